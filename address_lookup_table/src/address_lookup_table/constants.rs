@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey = Pubkey(b58!("AddressLookupTab1e1111111111111111111111111"));
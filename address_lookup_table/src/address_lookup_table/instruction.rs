@@ -0,0 +1,50 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// Address lookup table instructions, bincode encoded with a little-endian `u32` tag.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AddressLookupTableInstruction {
+    CreateLookupTable { recent_slot: u64, bump_seed: u8 },
+    FreezeLookupTable,
+    ExtendLookupTable { new_addresses: Vec<Pubkey> },
+    DeactivateLookupTable,
+    CloseLookupTable,
+}
+
+impl AddressLookupTableInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = Self::unpack_u32(data)?;
+        Ok(match tag {
+            0 => {
+                let (recent_slot, rest) = Self::unpack_u64(rest)?;
+                let bump_seed = *rest.first().ok_or("Address Lookup Table instruction is truncated.")?;
+                Self::CreateLookupTable { recent_slot, bump_seed }
+            },
+            1 => Self::FreezeLookupTable,
+            2 => Self::ExtendLookupTable { new_addresses: Self::unpack_pubkeys(rest)? },
+            3 => Self::DeactivateLookupTable,
+            4 => Self::CloseLookupTable,
+            _ => return Err("Invalid Address Lookup Table instruction."),
+        })
+    }
+
+    fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), &'static str> {
+        let value = input.get(..4).ok_or("Address Lookup Table instruction is truncated.")?;
+        Ok((u32::from_le_bytes(value.try_into().unwrap()), &input[4..]))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        let value = input.get(..8).ok_or("Address Lookup Table instruction is truncated.")?;
+        Ok((u64::from_le_bytes(value.try_into().unwrap()), &input[8..]))
+    }
+
+    /// A `u64` length followed by that many pubkeys. The length is checked against the
+    /// remaining data before anything is allocated.
+    fn unpack_pubkeys(input: &[u8]) -> Result<Vec<Pubkey>, &'static str> {
+        let (len, rest) = Self::unpack_u64(input)?;
+        let expected = usize::try_from(len).ok()
+            .and_then(|len| len.checked_mul(32))
+            .ok_or("Invalid address count.")?;
+        let bytes = rest.get(..expected).ok_or("Address Lookup Table instruction is truncated.")?;
+        Ok(bytes.chunks_exact(32).map(|key| Pubkey(key.try_into().unwrap())).collect())
+    }
+}
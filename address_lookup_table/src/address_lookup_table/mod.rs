@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod constants;
+pub use constants::ADDRESS_LOOKUP_TABLE_PROGRAM_ID;
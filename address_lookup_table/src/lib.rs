@@ -0,0 +1,200 @@
+use anyhow::{anyhow, Context, Error};
+
+use substreams::store::{StoreAppend, StoreDelete, StoreNew};
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::transaction::{get_context, TransactionContext};
+use utils::pubkey::Pubkey;
+
+pub mod address_lookup_table;
+use address_lookup_table::ADDRESS_LOOKUP_TABLE_PROGRAM_ID;
+use address_lookup_table::instruction::AddressLookupTableInstruction;
+
+pub mod pb;
+use pb::address_lookup_table::*;
+use pb::address_lookup_table::address_lookup_table_event::Event;
+
+#[substreams::handlers::map]
+fn address_lookup_table_events(block: Block) -> Result<AddressLookupTableBlockEvents, Error> {
+    Ok(AddressLookupTableBlockEvents { transactions: parse_block(&block)? })
+}
+
+/// Accumulates the addresses of each lookup table, keyed by table address. Closed tables are removed.
+#[substreams::handlers::store]
+fn store_lookup_table_addresses(events: AddressLookupTableBlockEvents, store: StoreAppend<String>) {
+    let mut ordinal: u64 = 0;
+    for event in events.transactions.iter().flat_map(|transaction| transaction.events.iter()) {
+        ordinal += 1;
+        match &event.event {
+            Some(Event::ExtendLookupTable(extend)) => {
+                store.append_all(ordinal, &extend.lookup_table, extend.new_addresses.clone());
+            },
+            Some(Event::CloseLookupTable(close)) => {
+                store.delete_prefix(ordinal as i64, &close.lookup_table);
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
+pub fn parse_block(block: &Block) -> Result<Vec<AddressLookupTableTransactionEvents>, Error> {
+    let mut transactions_events: Vec<AddressLookupTableTransactionEvents> = Vec::new();
+    for transaction in block.transactions() {
+        let events = match parse_transaction(transaction) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        if !events.is_empty() {
+            transactions_events.push(AddressLookupTableTransactionEvents {
+                signature: utils::transaction::get_signature(&transaction),
+                events
+            })
+        }
+    }
+    Ok(transactions_events)
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<AddressLookupTableEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new())
+    }
+
+    let mut events: Vec<AddressLookupTableEvent> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened().iter().enumerate() {
+        if instruction.program_id() != ADDRESS_LOOKUP_TABLE_PROGRAM_ID {
+            continue;
+        }
+        let event = parse_instruction(instruction, &context)
+            .with_context(|| format!("Failed to parse transaction {}", context.signature))?;
+        events.push(AddressLookupTableEvent { instruction_index: i as u32, event: Some(event) });
+    }
+
+    Ok(events)
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Event, Error> {
+    if instruction.program_id() != ADDRESS_LOOKUP_TABLE_PROGRAM_ID {
+        return Err(anyhow!("Not an Address Lookup Table program instruction"));
+    }
+
+    let unpacked = AddressLookupTableInstruction::unpack(&instruction.data()).map_err(|x| anyhow!(x))?;
+    match unpacked {
+        AddressLookupTableInstruction::CreateLookupTable { recent_slot, bump_seed } => {
+            _parse_create_lookup_table_instruction(instruction, context, recent_slot, bump_seed).map(Event::CreateLookupTable)
+        },
+        AddressLookupTableInstruction::FreezeLookupTable => {
+            _parse_freeze_lookup_table_instruction(instruction, context).map(Event::FreezeLookupTable)
+        },
+        AddressLookupTableInstruction::ExtendLookupTable { new_addresses } => {
+            _parse_extend_lookup_table_instruction(instruction, context, new_addresses).map(Event::ExtendLookupTable)
+        },
+        AddressLookupTableInstruction::DeactivateLookupTable => {
+            _parse_deactivate_lookup_table_instruction(instruction, context).map(Event::DeactivateLookupTable)
+        },
+        AddressLookupTableInstruction::CloseLookupTable => {
+            _parse_close_lookup_table_instruction(instruction, context).map(Event::CloseLookupTable)
+        },
+    }.map_err(|x| anyhow!(x)).context("Failed to parse Address Lookup Table instruction")
+}
+
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, &'static str> {
+    instruction.accounts().get(index).cloned().ok_or("Instruction is missing accounts.")
+}
+
+fn _parse_create_lookup_table_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    recent_slot: u64,
+    bump_seed: u8,
+) -> Result<CreateLookupTableEvent, &'static str> {
+    let lookup_table = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+    let payer = _get_account(instruction, 2)?.to_string();
+
+    Ok(CreateLookupTableEvent {
+        lookup_table,
+        authority,
+        payer,
+        recent_slot,
+        bump_seed: bump_seed.into(),
+    })
+}
+
+fn _parse_freeze_lookup_table_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<FreezeLookupTableEvent, &'static str> {
+    let lookup_table = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+
+    Ok(FreezeLookupTableEvent {
+        lookup_table,
+        authority,
+    })
+}
+
+fn _parse_extend_lookup_table_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    new_addresses: Vec<Pubkey>,
+) -> Result<ExtendLookupTableEvent, &'static str> {
+    let lookup_table = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+    let payer = instruction.accounts().get(2).map(|account| account.to_string());
+
+    Ok(ExtendLookupTableEvent {
+        lookup_table,
+        authority,
+        payer,
+        new_addresses: new_addresses.iter().map(|address| address.to_string()).collect(),
+    })
+}
+
+fn _parse_deactivate_lookup_table_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<DeactivateLookupTableEvent, &'static str> {
+    let lookup_table = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+
+    Ok(DeactivateLookupTableEvent {
+        lookup_table,
+        authority,
+    })
+}
+
+fn _parse_close_lookup_table_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<CloseLookupTableEvent, &'static str> {
+    let lookup_table = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+    let recipient = _get_account(instruction, 2)?.to_string();
+
+    Ok(CloseLookupTableEvent {
+        lookup_table,
+        authority,
+        recipient,
+    })
+}
@@ -0,0 +1,93 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressLookupTableBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<AddressLookupTableTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressLookupTableTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<AddressLookupTableEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressLookupTableEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(oneof="address_lookup_table_event::Event", tags="2, 3, 4, 5, 6")]
+    pub event: ::core::option::Option<address_lookup_table_event::Event>,
+}
+/// Nested message and enum types in `AddressLookupTableEvent`.
+pub mod address_lookup_table_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="2")]
+        CreateLookupTable(super::CreateLookupTableEvent),
+        #[prost(message, tag="3")]
+        FreezeLookupTable(super::FreezeLookupTableEvent),
+        #[prost(message, tag="4")]
+        ExtendLookupTable(super::ExtendLookupTableEvent),
+        #[prost(message, tag="5")]
+        DeactivateLookupTable(super::DeactivateLookupTableEvent),
+        #[prost(message, tag="6")]
+        CloseLookupTable(super::CloseLookupTableEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateLookupTableEvent {
+    #[prost(string, tag="1")]
+    pub lookup_table: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub recent_slot: u64,
+    #[prost(uint32, tag="5")]
+    pub bump_seed: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FreezeLookupTableEvent {
+    #[prost(string, tag="1")]
+    pub lookup_table: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendLookupTableEvent {
+    #[prost(string, tag="1")]
+    pub lookup_table: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub payer: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="4")]
+    pub new_addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeactivateLookupTableEvent {
+    #[prost(string, tag="1")]
+    pub lookup_table: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloseLookupTableEvent {
+    #[prost(string, tag="1")]
+    pub lookup_table: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub recipient: ::prost::alloc::string::String,
+}
+// @@protoc_insertion_point(module)
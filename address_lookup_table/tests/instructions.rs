@@ -0,0 +1,71 @@
+use test_support::{address, key, TransactionBuilder};
+use address_lookup_table_substream::parse_transaction;
+use address_lookup_table_substream::address_lookup_table::ADDRESS_LOOKUP_TABLE_PROGRAM_ID;
+use address_lookup_table_substream::address_lookup_table::instruction::AddressLookupTableInstruction;
+use address_lookup_table_substream::pb::address_lookup_table::ExtendLookupTableEvent;
+use address_lookup_table_substream::pb::address_lookup_table::address_lookup_table_event::Event;
+use substreams_solana_utils::pubkey::Pubkey;
+
+// Account indexes: the authority (fee payer), the lookup table, the System Program and the
+// Address Lookup Table program.
+const AUTHORITY: u8 = 0;
+const LOOKUP_TABLE: u8 = 1;
+const SYSTEM_PROGRAM: u8 = 2;
+const ADDRESS_LOOKUP_TABLE_PROGRAM: u32 = 3;
+
+fn builder() -> TransactionBuilder {
+    TransactionBuilder::new(vec![key(1), key(2), key(0), ADDRESS_LOOKUP_TABLE_PROGRAM_ID.0.to_vec()])
+}
+
+/// ExtendLookupTable data declaring `len` addresses, followed by `addresses`.
+fn extend_data(len: u64, addresses: &[u8]) -> Vec<u8> {
+    let mut data = [2u32.to_le_bytes().to_vec(), len.to_le_bytes().to_vec()].concat();
+    for n in addresses {
+        data.extend(key(*n));
+    }
+    data
+}
+
+#[test]
+fn extend_lookup_table() {
+    let accounts = [LOOKUP_TABLE, AUTHORITY, AUTHORITY, SYSTEM_PROGRAM];
+    let transaction = builder().instruction(ADDRESS_LOOKUP_TABLE_PROGRAM, &accounts, extend_data(2, &[8, 9])).build();
+    let events = parse_transaction(&transaction).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, Some(Event::ExtendLookupTable(ExtendLookupTableEvent {
+        lookup_table: address(2),
+        authority: address(1),
+        payer: Some(address(1)),
+        new_addresses: vec![address(8), address(9)],
+    })));
+}
+
+#[test]
+fn extend_lookup_table_unpack() {
+    assert_eq!(
+        AddressLookupTableInstruction::unpack(&extend_data(2, &[8, 9])),
+        Ok(AddressLookupTableInstruction::ExtendLookupTable { new_addresses: vec![Pubkey([8; 32]), Pubkey([9; 32])] }),
+    );
+    assert_eq!(
+        AddressLookupTableInstruction::unpack(&extend_data(0, &[])),
+        Ok(AddressLookupTableInstruction::ExtendLookupTable { new_addresses: Vec::new() }),
+    );
+}
+
+#[test]
+fn truncated_extend_lookup_table_fails() {
+    // Two addresses declared, one present.
+    assert_eq!(
+        AddressLookupTableInstruction::unpack(&extend_data(2, &[8])),
+        Err("Address Lookup Table instruction is truncated."),
+    );
+    // A count whose byte length overflows is rejected before anything is allocated.
+    assert_eq!(
+        AddressLookupTableInstruction::unpack(&extend_data(u64::MAX, &[8])),
+        Err("Invalid address count."),
+    );
+
+    let accounts = [LOOKUP_TABLE, AUTHORITY, AUTHORITY, SYSTEM_PROGRAM];
+    let transaction = builder().instruction(ADDRESS_LOOKUP_TABLE_PROGRAM, &accounts, extend_data(2, &[8])).build();
+    assert!(parse_transaction(&transaction).is_err());
+}
@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = Pubkey(b58!("ATokenGPvbdGVxr1b2hvZbsiqW1xWH25efTNsLJA8knL"));
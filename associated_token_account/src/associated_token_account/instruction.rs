@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssociatedTokenAccountInstruction {
+    Create,
+    CreateIdempotent,
+    RecoverNested,
+}
+
+impl AssociatedTokenAccountInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        // The original Create instruction carries no data at all.
+        match data.first() {
+            None | Some(0) => Ok(Self::Create),
+            Some(1) => Ok(Self::CreateIdempotent),
+            Some(2) => Ok(Self::RecoverNested),
+            Some(_) => Err("Invalid Associated Token Account instruction."),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod constants;
+pub use constants::ASSOCIATED_TOKEN_PROGRAM_ID;
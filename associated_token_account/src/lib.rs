@@ -0,0 +1,150 @@
+use anyhow::{anyhow, Context, Error};
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::transaction::{get_context, TransactionContext};
+use utils::system_program::SYSTEM_PROGRAM_ID;
+use utils::pubkey::Pubkey;
+
+pub mod associated_token_account;
+use associated_token_account::ASSOCIATED_TOKEN_PROGRAM_ID;
+use associated_token_account::instruction::AssociatedTokenAccountInstruction;
+
+pub mod pb;
+use pb::associated_token_account::*;
+use pb::associated_token_account::associated_token_account_event::Event;
+
+#[substreams::handlers::map]
+fn associated_token_account_events(block: Block) -> Result<AssociatedTokenAccountBlockEvents, Error> {
+    Ok(AssociatedTokenAccountBlockEvents { transactions: parse_block(&block)? })
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
+pub fn parse_block(block: &Block) -> Result<Vec<AssociatedTokenAccountTransactionEvents>, Error> {
+    let mut transactions_events: Vec<AssociatedTokenAccountTransactionEvents> = Vec::new();
+    for transaction in block.transactions() {
+        let events = match parse_transaction(transaction) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        if !events.is_empty() {
+            transactions_events.push(AssociatedTokenAccountTransactionEvents {
+                signature: utils::transaction::get_signature(&transaction),
+                events
+            })
+        }
+    }
+    Ok(transactions_events)
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<AssociatedTokenAccountEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new())
+    }
+
+    let mut events: Vec<AssociatedTokenAccountEvent> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened().iter().enumerate() {
+        if instruction.program_id() != ASSOCIATED_TOKEN_PROGRAM_ID {
+            continue;
+        }
+        let event = parse_instruction(instruction, &context)
+            .with_context(|| format!("Failed to parse transaction {}", context.signature))?;
+        events.push(AssociatedTokenAccountEvent { instruction_index: i as u32, event: Some(event) });
+    }
+
+    Ok(events)
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Event, Error> {
+    if instruction.program_id() != ASSOCIATED_TOKEN_PROGRAM_ID {
+        return Err(anyhow!("Not an Associated Token Account program instruction"));
+    }
+
+    let unpacked = AssociatedTokenAccountInstruction::unpack(&instruction.data()).map_err(|x| anyhow!(x))?;
+    match unpacked {
+        AssociatedTokenAccountInstruction::Create => {
+            _parse_create_instruction(instruction, context, false).map(Event::Create)
+        },
+        AssociatedTokenAccountInstruction::CreateIdempotent => {
+            _parse_create_instruction(instruction, context, true).map(Event::Create)
+        },
+        AssociatedTokenAccountInstruction::RecoverNested => {
+            _parse_recover_nested_instruction(instruction, context).map(Event::RecoverNested)
+        },
+    }.map_err(|x| anyhow!(x)).context("Failed to parse Associated Token Account instruction")
+}
+
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, &'static str> {
+    instruction.accounts().get(index).cloned().ok_or("Instruction is missing accounts.")
+}
+
+fn _parse_create_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    idempotent: bool,
+) -> Result<CreateEvent, &'static str> {
+    let funding_account = _get_account(instruction, 0)?.to_string();
+    let associated_account = _get_account(instruction, 1)?.to_string();
+    let wallet = _get_account(instruction, 2)?.to_string();
+    let mint = _get_account(instruction, 3)?.to_string();
+    let token_program = _get_account(instruction, 5)?.to_string();
+
+    // Creating the account always goes through the System Program (CreateAccount, or
+    // Transfer/Allocate/Assign for a prefunded address). CreateIdempotent returns early without
+    // any CPI when the account is already initialized.
+    let already_existed = idempotent && !instruction.inner_instructions().iter()
+        .any(|inner| inner.program_id() == SYSTEM_PROGRAM_ID);
+
+    Ok(CreateEvent {
+        funding_account,
+        associated_account,
+        wallet,
+        mint,
+        token_program,
+        idempotent,
+        already_existed,
+    })
+}
+
+fn _parse_recover_nested_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<RecoverNestedEvent, &'static str> {
+    let nested_account = _get_account(instruction, 0)?.to_string();
+    let nested_mint = _get_account(instruction, 1)?.to_string();
+    let destination_account = _get_account(instruction, 2)?.to_string();
+    let owner_account = _get_account(instruction, 3)?.to_string();
+    let owner_mint = _get_account(instruction, 4)?.to_string();
+    let wallet = _get_account(instruction, 5)?.to_string();
+    let token_program = _get_account(instruction, 6)?.to_string();
+
+    Ok(RecoverNestedEvent {
+        nested_account,
+        nested_mint,
+        destination_account,
+        owner_account,
+        owner_mint,
+        wallet,
+        token_program,
+    })
+}
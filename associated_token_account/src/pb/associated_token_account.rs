@@ -0,0 +1,72 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssociatedTokenAccountBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<AssociatedTokenAccountTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssociatedTokenAccountTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<AssociatedTokenAccountEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssociatedTokenAccountEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(oneof="associated_token_account_event::Event", tags="2, 3")]
+    pub event: ::core::option::Option<associated_token_account_event::Event>,
+}
+/// Nested message and enum types in `AssociatedTokenAccountEvent`.
+pub mod associated_token_account_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="2")]
+        Create(super::CreateEvent),
+        #[prost(message, tag="3")]
+        RecoverNested(super::RecoverNestedEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateEvent {
+    #[prost(string, tag="1")]
+    pub funding_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub associated_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub wallet: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token_program: ::prost::alloc::string::String,
+    #[prost(bool, tag="6")]
+    pub idempotent: bool,
+    /// Set when a CreateIdempotent instruction found the account already initialized and made no System Program call.
+    #[prost(bool, tag="7")]
+    pub already_existed: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecoverNestedEvent {
+    #[prost(string, tag="1")]
+    pub nested_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub nested_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub destination_account: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub owner_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub owner_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub wallet: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub token_program: ::prost::alloc::string::String,
+}
+// @@protoc_insertion_point(module)
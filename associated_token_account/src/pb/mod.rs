@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:associated_token_account)
+pub mod associated_token_account {
+    include!("associated_token_account.rs");
+    // @@protoc_insertion_point(associated_token_account)
+}
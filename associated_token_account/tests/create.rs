@@ -0,0 +1,82 @@
+use test_support::{address, key, TransactionBuilder};
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana_utils::spl_token::TOKEN_PROGRAM_ID;
+use associated_token_account_substream::associated_token_account::ASSOCIATED_TOKEN_PROGRAM_ID;
+use associated_token_account_substream::parse_transaction;
+use associated_token_account_substream::pb::associated_token_account::CreateEvent;
+use associated_token_account_substream::pb::associated_token_account::associated_token_account_event::Event;
+
+// Account indexes: the funding account, the associated token account, the wallet, the mint, then
+// the System, Token and Associated Token Account programs.
+const SYSTEM_PROGRAM: u32 = 4;
+const TOKEN_PROGRAM: u32 = 5;
+const ATA_PROGRAM: u32 = 6;
+const CREATE_ACCOUNTS: [u8; 6] = [0, 1, 2, 3, 4, 5];
+
+fn builder() -> TransactionBuilder {
+    TransactionBuilder::new(vec![
+        key(1), key(2), key(3), key(4), vec![0; 32], TOKEN_PROGRAM_ID.0.to_vec(), ASSOCIATED_TOKEN_PROGRAM_ID.0.to_vec(),
+    ])
+}
+
+/// The CPIs of an ATA creation: the System Program creates the account, then the Token program
+/// initializes it.
+fn with_account_creation(builder: TransactionBuilder) -> TransactionBuilder {
+    let create_account = [vec![0, 0, 0, 0], 2_039_280u64.to_le_bytes().to_vec(), 165u64.to_le_bytes().to_vec(), TOKEN_PROGRAM_ID.0.to_vec()].concat();
+    builder
+        .inner_instruction(SYSTEM_PROGRAM, &[0, 1], create_account, 2)
+        .inner_instruction(TOKEN_PROGRAM, &[1], vec![22], 2)
+        .inner_instruction(TOKEN_PROGRAM, &[1, 3], [vec![18], key(3)].concat(), 2)
+}
+
+fn create_event(transaction: &ConfirmedTransaction) -> CreateEvent {
+    let events = parse_transaction(transaction).unwrap();
+    assert_eq!(events.len(), 1);
+    match &events[0].event {
+        Some(Event::Create(event)) => event.clone(),
+        other => panic!("Expected Create, got {:?}", other),
+    }
+}
+
+fn assert_accounts(event: &CreateEvent) {
+    assert_eq!(event.funding_account, address(1));
+    assert_eq!(event.associated_account, address(2));
+    assert_eq!(event.wallet, address(3));
+    assert_eq!(event.mint, address(4));
+    assert_eq!(event.token_program, "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+}
+
+#[test]
+fn create() {
+    let transaction = with_account_creation(builder().instruction(ATA_PROGRAM, &CREATE_ACCOUNTS, vec![])).build();
+    let event = create_event(&transaction);
+    assert_accounts(&event);
+    assert!(!event.idempotent);
+    assert!(!event.already_existed);
+}
+
+#[test]
+fn create_idempotent() {
+    let transaction = with_account_creation(builder().instruction(ATA_PROGRAM, &CREATE_ACCOUNTS, vec![1])).build();
+    let event = create_event(&transaction);
+    assert_accounts(&event);
+    assert!(event.idempotent);
+    assert!(!event.already_existed);
+}
+
+#[test]
+fn create_idempotent_noop() {
+    // The account already exists, so the program returns without any CPI.
+    let transaction = builder().instruction(ATA_PROGRAM, &CREATE_ACCOUNTS, vec![1]).build();
+    let event = create_event(&transaction);
+    assert_accounts(&event);
+    assert!(event.idempotent);
+    assert!(event.already_existed);
+}
+
+#[test]
+fn transaction_without_meta_is_skipped() {
+    let mut transaction = with_account_creation(builder().instruction(ATA_PROGRAM, &CREATE_ACCOUNTS, vec![])).build();
+    transaction.meta = None;
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const BPF_LOADER_UPGRADEABLE_PROGRAM_ID: Pubkey = Pubkey(b58!("BPFLoaderUpgradeab1e11111111111111111111111"));
@@ -0,0 +1,44 @@
+/// Upgradeable loader instructions, bincode encoded with a little-endian `u32` tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpgradeableLoaderInstruction {
+    InitializeBuffer,
+    /// Only the length of the written bytes is kept.
+    Write { offset: u32, length: u64 },
+    DeployWithMaxDataLen { max_data_len: u64 },
+    Upgrade,
+    SetAuthority,
+    Close,
+    ExtendProgram { additional_bytes: u32 },
+    SetAuthorityChecked,
+}
+
+impl UpgradeableLoaderInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = Self::unpack_u32(data)?;
+        Ok(match tag {
+            0 => Self::InitializeBuffer,
+            1 => {
+                let (offset, rest) = Self::unpack_u32(rest)?;
+                let (length, _rest) = Self::unpack_u64(rest)?;
+                Self::Write { offset, length }
+            },
+            2 => Self::DeployWithMaxDataLen { max_data_len: Self::unpack_u64(rest)?.0 },
+            3 => Self::Upgrade,
+            4 => Self::SetAuthority,
+            5 => Self::Close,
+            6 => Self::ExtendProgram { additional_bytes: Self::unpack_u32(rest)?.0 },
+            7 => Self::SetAuthorityChecked,
+            _ => return Err("Invalid BPF Loader Upgradeable instruction."),
+        })
+    }
+
+    fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), &'static str> {
+        let value = input.get(..4).ok_or("BPF Loader Upgradeable instruction is truncated.")?;
+        Ok((u32::from_le_bytes(value.try_into().unwrap()), &input[4..]))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        let value = input.get(..8).ok_or("BPF Loader Upgradeable instruction is truncated.")?;
+        Ok((u64::from_le_bytes(value.try_into().unwrap()), &input[8..]))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod constants;
+pub use constants::BPF_LOADER_UPGRADEABLE_PROGRAM_ID;
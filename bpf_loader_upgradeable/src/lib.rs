@@ -0,0 +1,257 @@
+use anyhow::{anyhow, Context, Error};
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::transaction::{get_context, TransactionContext};
+use utils::pubkey::Pubkey;
+
+pub mod bpf_loader_upgradeable;
+use bpf_loader_upgradeable::BPF_LOADER_UPGRADEABLE_PROGRAM_ID;
+use bpf_loader_upgradeable::instruction::UpgradeableLoaderInstruction;
+
+pub mod options;
+pub use options::ParseOptions;
+
+pub mod pb;
+use pb::bpf_loader_upgradeable::*;
+use pb::bpf_loader_upgradeable::bpf_loader_upgradeable_event::Event;
+
+#[substreams::handlers::map]
+fn bpf_loader_events(params: String, block: Block) -> Result<BpfLoaderUpgradeableBlockEvents, Error> {
+    let options = ParseOptions::from_params(&params)?;
+    Ok(BpfLoaderUpgradeableBlockEvents { transactions: parse_block_with_options(&block, &options)? })
+}
+
+pub fn parse_block(block: &Block) -> Result<Vec<BpfLoaderUpgradeableTransactionEvents>, Error> {
+    parse_block_with_options(block, &ParseOptions::default())
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
+pub fn parse_block_with_options(block: &Block, options: &ParseOptions) -> Result<Vec<BpfLoaderUpgradeableTransactionEvents>, Error> {
+    let mut transactions_events: Vec<BpfLoaderUpgradeableTransactionEvents> = Vec::new();
+    for transaction in block.transactions() {
+        let events = match parse_transaction_with_options(transaction, options) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        if !events.is_empty() {
+            transactions_events.push(BpfLoaderUpgradeableTransactionEvents {
+                signature: utils::transaction::get_signature(&transaction),
+                events
+            })
+        }
+    }
+    Ok(transactions_events)
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<BpfLoaderUpgradeableEvent>, Error> {
+    parse_transaction_with_options(transaction, &ParseOptions::default())
+}
+
+pub fn parse_transaction_with_options(transaction: &ConfirmedTransaction, options: &ParseOptions) -> Result<Vec<BpfLoaderUpgradeableEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new())
+    }
+
+    let mut events: Vec<BpfLoaderUpgradeableEvent> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened().iter().enumerate() {
+        if instruction.program_id() != BPF_LOADER_UPGRADEABLE_PROGRAM_ID {
+            continue;
+        }
+        let event = parse_instruction(instruction, &context)
+            .with_context(|| format!("Failed to parse transaction {}", context.signature))?;
+        if options.skip_writes && matches!(event, Event::Write(_)) {
+            continue;
+        }
+        events.push(BpfLoaderUpgradeableEvent { instruction_index: i as u32, event: Some(event) });
+    }
+
+    Ok(events)
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Event, Error> {
+    if instruction.program_id() != BPF_LOADER_UPGRADEABLE_PROGRAM_ID {
+        return Err(anyhow!("Not a BPF Loader Upgradeable program instruction"));
+    }
+
+    let unpacked = UpgradeableLoaderInstruction::unpack(&instruction.data()).map_err(|x| anyhow!(x))?;
+    match unpacked {
+        UpgradeableLoaderInstruction::InitializeBuffer => {
+            _parse_initialize_buffer_instruction(instruction, context).map(Event::InitializeBuffer)
+        },
+        UpgradeableLoaderInstruction::Write { offset, length } => {
+            _parse_write_instruction(instruction, context, offset, length).map(Event::Write)
+        },
+        UpgradeableLoaderInstruction::DeployWithMaxDataLen { max_data_len } => {
+            _parse_deploy_with_max_data_len_instruction(instruction, context, max_data_len).map(Event::DeployWithMaxDataLen)
+        },
+        UpgradeableLoaderInstruction::Upgrade => {
+            _parse_upgrade_instruction(instruction, context).map(Event::Upgrade)
+        },
+        UpgradeableLoaderInstruction::SetAuthority => {
+            _parse_set_authority_instruction(instruction, context, false).map(Event::SetAuthority)
+        },
+        UpgradeableLoaderInstruction::SetAuthorityChecked => {
+            _parse_set_authority_instruction(instruction, context, true).map(Event::SetAuthority)
+        },
+        UpgradeableLoaderInstruction::Close => {
+            _parse_close_instruction(instruction, context).map(Event::Close)
+        },
+        UpgradeableLoaderInstruction::ExtendProgram { additional_bytes } => {
+            _parse_extend_program_instruction(instruction, context, additional_bytes).map(Event::ExtendProgram)
+        },
+    }.map_err(|x| anyhow!(x)).context("Failed to parse BPF Loader Upgradeable instruction")
+}
+
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, &'static str> {
+    instruction.accounts().get(index).cloned().ok_or("Instruction is missing accounts.")
+}
+
+fn _get_optional_account(instruction: &StructuredInstruction, index: usize) -> Option<String> {
+    instruction.accounts().get(index).map(|account| account.to_string())
+}
+
+fn _parse_initialize_buffer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<InitializeBufferEvent, &'static str> {
+    let buffer = _get_account(instruction, 0)?.to_string();
+    let authority = _get_optional_account(instruction, 1);
+
+    Ok(InitializeBufferEvent {
+        buffer,
+        authority,
+    })
+}
+
+fn _parse_write_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    offset: u32,
+    length: u64,
+) -> Result<WriteEvent, &'static str> {
+    let buffer = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+
+    Ok(WriteEvent {
+        buffer,
+        authority,
+        offset,
+        length,
+    })
+}
+
+fn _parse_deploy_with_max_data_len_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    max_data_len: u64,
+) -> Result<DeployWithMaxDataLenEvent, &'static str> {
+    let payer = _get_account(instruction, 0)?.to_string();
+    let programdata = _get_account(instruction, 1)?.to_string();
+    let program = _get_account(instruction, 2)?.to_string();
+    let buffer = _get_account(instruction, 3)?.to_string();
+    let authority = _get_account(instruction, 7)?.to_string();
+
+    Ok(DeployWithMaxDataLenEvent {
+        payer,
+        programdata,
+        program,
+        buffer,
+        authority,
+        max_data_len,
+    })
+}
+
+fn _parse_upgrade_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<UpgradeEvent, &'static str> {
+    let programdata = _get_account(instruction, 0)?.to_string();
+    let program = _get_account(instruction, 1)?.to_string();
+    let buffer = _get_account(instruction, 2)?.to_string();
+    let spill = _get_account(instruction, 3)?.to_string();
+    let authority = _get_account(instruction, 6)?.to_string();
+
+    Ok(UpgradeEvent {
+        programdata,
+        program,
+        buffer,
+        spill,
+        authority,
+    })
+}
+
+fn _parse_set_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    checked: bool,
+) -> Result<SetAuthorityEvent, &'static str> {
+    let account = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+    let new_authority = if checked {
+        Some(_get_account(instruction, 2)?.to_string())
+    } else {
+        _get_optional_account(instruction, 2)
+    };
+
+    Ok(SetAuthorityEvent {
+        account,
+        authority,
+        new_authority,
+        checked,
+    })
+}
+
+fn _parse_close_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<CloseEvent, &'static str> {
+    let account = _get_account(instruction, 0)?.to_string();
+    let recipient = _get_account(instruction, 1)?.to_string();
+    let authority = _get_optional_account(instruction, 2);
+    let program = _get_optional_account(instruction, 3);
+
+    Ok(CloseEvent {
+        account,
+        recipient,
+        authority,
+        program,
+    })
+}
+
+fn _parse_extend_program_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    additional_bytes: u32,
+) -> Result<ExtendProgramEvent, &'static str> {
+    let programdata = _get_account(instruction, 0)?.to_string();
+    let program = _get_account(instruction, 1)?.to_string();
+    let payer = _get_optional_account(instruction, 3);
+
+    Ok(ExtendProgramEvent {
+        programdata,
+        program,
+        payer,
+        additional_bytes,
+    })
+}
@@ -0,0 +1,135 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BpfLoaderUpgradeableBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<BpfLoaderUpgradeableTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BpfLoaderUpgradeableTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<BpfLoaderUpgradeableEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BpfLoaderUpgradeableEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(oneof="bpf_loader_upgradeable_event::Event", tags="2, 3, 4, 5, 6, 7, 8")]
+    pub event: ::core::option::Option<bpf_loader_upgradeable_event::Event>,
+}
+/// Nested message and enum types in `BpfLoaderUpgradeableEvent`.
+pub mod bpf_loader_upgradeable_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="2")]
+        InitializeBuffer(super::InitializeBufferEvent),
+        #[prost(message, tag="3")]
+        Write(super::WriteEvent),
+        #[prost(message, tag="4")]
+        DeployWithMaxDataLen(super::DeployWithMaxDataLenEvent),
+        #[prost(message, tag="5")]
+        Upgrade(super::UpgradeEvent),
+        #[prost(message, tag="6")]
+        SetAuthority(super::SetAuthorityEvent),
+        #[prost(message, tag="7")]
+        Close(super::CloseEvent),
+        #[prost(message, tag="8")]
+        ExtendProgram(super::ExtendProgramEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeBufferEvent {
+    #[prost(string, tag="1")]
+    pub buffer: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WriteEvent {
+    #[prost(string, tag="1")]
+    pub buffer: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub offset: u32,
+    /// Length of the written chunk; the payload itself is not emitted.
+    #[prost(uint64, tag="4")]
+    pub length: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeployWithMaxDataLenEvent {
+    #[prost(string, tag="1")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub programdata: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub program: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub buffer: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub max_data_len: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradeEvent {
+    #[prost(string, tag="1")]
+    pub programdata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub program: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub buffer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub spill: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAuthorityEvent {
+    /// Buffer or programdata account.
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    /// Unset when the account is made immutable.
+    #[prost(string, optional, tag="3")]
+    pub new_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub checked: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloseEvent {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Set when closing a programdata account.
+    #[prost(string, optional, tag="4")]
+    pub program: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendProgramEvent {
+    #[prost(string, tag="1")]
+    pub programdata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub program: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub payer: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="4")]
+    pub additional_bytes: u32,
+}
+// @@protoc_insertion_point(module)
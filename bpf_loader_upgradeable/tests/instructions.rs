@@ -0,0 +1,101 @@
+use test_support::{address, key, TransactionBuilder};
+use bpf_loader_upgradeable_substream::bpf_loader_upgradeable::BPF_LOADER_UPGRADEABLE_PROGRAM_ID;
+use bpf_loader_upgradeable_substream::pb::bpf_loader_upgradeable::{SetAuthorityEvent, UpgradeEvent};
+use bpf_loader_upgradeable_substream::pb::bpf_loader_upgradeable::bpf_loader_upgradeable_event::Event;
+use bpf_loader_upgradeable_substream::{parse_transaction, parse_transaction_with_options, ParseOptions};
+
+// Account indexes: the upgrade authority (fee payer), the programdata, program and buffer
+// accounts, the Rent and Clock sysvars, the new authority and the loader.
+const AUTHORITY: u8 = 0;
+const PROGRAMDATA: u8 = 1;
+const PROGRAM: u8 = 2;
+const BUFFER: u8 = 3;
+const RENT: u8 = 4;
+const CLOCK: u8 = 5;
+const NEW_AUTHORITY: u8 = 6;
+const LOADER: u32 = 7;
+
+fn builder() -> TransactionBuilder {
+    TransactionBuilder::new(vec![
+        key(1), key(2), key(3), key(4), key(5), key(6), key(7), BPF_LOADER_UPGRADEABLE_PROGRAM_ID.0.to_vec(),
+    ])
+}
+
+fn tag(tag: u32) -> Vec<u8> {
+    tag.to_le_bytes().to_vec()
+}
+
+fn single_event(transaction: TransactionBuilder) -> Event {
+    let events = parse_transaction(&transaction.build()).unwrap();
+    assert_eq!(events.len(), 1);
+    events[0].event.clone().unwrap()
+}
+
+#[test]
+fn upgrade() {
+    // The authority also receives the buffer lamports as the spill account.
+    let transaction = builder().instruction(LOADER, &[PROGRAMDATA, PROGRAM, BUFFER, AUTHORITY, RENT, CLOCK, AUTHORITY], tag(3));
+    assert_eq!(single_event(transaction), Event::Upgrade(UpgradeEvent {
+        programdata: address(2),
+        program: address(3),
+        buffer: address(4),
+        spill: address(1),
+        authority: address(1),
+    }));
+}
+
+#[test]
+fn upgrade_missing_authority_fails() {
+    let transaction = builder().instruction(LOADER, &[PROGRAMDATA, PROGRAM, BUFFER, AUTHORITY, RENT, CLOCK], tag(3));
+    assert!(parse_transaction(&transaction.build()).is_err());
+}
+
+#[test]
+fn set_authority() {
+    let transaction = builder().instruction(LOADER, &[PROGRAMDATA, AUTHORITY, NEW_AUTHORITY], tag(4));
+    assert_eq!(single_event(transaction), Event::SetAuthority(SetAuthorityEvent {
+        account: address(2),
+        authority: address(1),
+        new_authority: Some(address(7)),
+        checked: false,
+    }));
+}
+
+#[test]
+fn set_authority_immutable() {
+    let transaction = builder().instruction(LOADER, &[PROGRAMDATA, AUTHORITY], tag(4));
+    assert_eq!(single_event(transaction), Event::SetAuthority(SetAuthorityEvent {
+        account: address(2),
+        authority: address(1),
+        new_authority: None,
+        checked: false,
+    }));
+}
+
+#[test]
+fn set_authority_checked() {
+    let transaction = builder().instruction(LOADER, &[BUFFER, AUTHORITY, NEW_AUTHORITY], tag(7));
+    assert_eq!(single_event(transaction), Event::SetAuthority(SetAuthorityEvent {
+        account: address(4),
+        authority: address(1),
+        new_authority: Some(address(7)),
+        checked: true,
+    }));
+}
+
+#[test]
+fn skip_writes() {
+    let write = [tag(1), 64u32.to_le_bytes().to_vec(), 3u64.to_le_bytes().to_vec(), vec![1, 2, 3]].concat();
+    let transaction = builder().instruction(LOADER, &[BUFFER, AUTHORITY], write).build();
+    let Some(Event::Write(event)) = parse_transaction(&transaction).unwrap()[0].event.clone() else { panic!("Expected Write") };
+    assert_eq!((event.offset, event.length), (64, 3));
+    let options = ParseOptions { skip_writes: true };
+    assert!(parse_transaction_with_options(&transaction, &options).unwrap().is_empty());
+}
+
+#[test]
+fn transaction_without_meta_is_skipped() {
+    let mut transaction = builder().instruction(LOADER, &[PROGRAMDATA, AUTHORITY, NEW_AUTHORITY], tag(4)).build();
+    transaction.meta = None;
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
@@ -0,0 +1,329 @@
+use anyhow::Error;
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::StructuredInstruction;
+use utils::transaction::TransactionContext;
+use utils::system_program::SYSTEM_PROGRAM_ID;
+use utils::spl_token::TOKEN_PROGRAM_ID;
+use utils::pubkey::Pubkey;
+
+use system_program_substream::{account_metas, nonce, wsol, annotate_invoking_programs};
+use system_program_substream::{InstructionParser, ParserRegistry, ParseError, PubkeyEncoding, SystemProgramParser};
+use system_program_substream::pb::system_program::SystemProgramEvent;
+use system_program_substream::pb::system_program::system_program_event::Event as SystemEvent;
+use system_program_substream::token::{SplTokenParser, TokenEvent};
+use spl_token_substream::pb::spl_token::SplTokenEvent;
+use mpl_token_metadata_substream::mpl_token_metadata::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
+use mpl_token_metadata_substream::pb::mpl_token_metadata::MplTokenMetadataEvent;
+use raydium_amm_substream::raydium_amm::constants::RAYDIUM_AMM_PROGRAM_ID;
+use raydium_amm_substream::raydium_clmm::constants::RAYDIUM_CLMM_PROGRAM_ID;
+use raydium_amm_substream::raydium_cpmm::constants::RAYDIUM_CPMM_PROGRAM_ID;
+use raydium_amm_substream::raydium_stable::constants::RAYDIUM_STABLE_PROGRAM_ID;
+use raydium_amm_substream::pb::raydium_amm::RaydiumAmmEvent;
+use pumpfun_substream::pumpfun::PUMPFUN_PROGRAM_ID;
+use pumpfun_substream::pb::pumpfun::PumpfunEvent;
+use token_2022_substream::token_2022::TOKEN_2022_PROGRAM_ID;
+use token_2022_substream::pb::token_2022::Token2022Event;
+use associated_token_account_substream::associated_token_account::ASSOCIATED_TOKEN_PROGRAM_ID;
+use associated_token_account_substream::pb::associated_token_account::AssociatedTokenAccountEvent;
+use bpf_loader_upgradeable_substream::bpf_loader_upgradeable::BPF_LOADER_UPGRADEABLE_PROGRAM_ID;
+use bpf_loader_upgradeable_substream::pb::bpf_loader_upgradeable::BpfLoaderUpgradeableEvent;
+use stake_program_substream::stake_program::STAKE_PROGRAM_ID;
+use stake_program_substream::pb::stake_program::StakeProgramEvent;
+use address_lookup_table_substream::address_lookup_table::ADDRESS_LOOKUP_TABLE_PROGRAM_ID;
+use address_lookup_table_substream::pb::address_lookup_table::AddressLookupTableEvent;
+use vote_program_substream::vote_program::VOTE_PROGRAM_ID;
+use vote_program_substream::pb::vote_program::VoteProgramEvent;
+
+pub mod options;
+pub use options::{ParseOptions, Program};
+
+pub mod pb;
+use pb::combined::*;
+use pb::combined::combined_event::Event;
+
+#[substreams::handlers::map]
+fn combined_events(params: String, block: Block) -> Result<CombinedBlockEvents, Error> {
+    let options = ParseOptions::from_params(&params)?;
+    Ok(CombinedBlockEvents { transactions: parse_block(&block, &options)? })
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
+pub fn parse_block(block: &Block, options: &ParseOptions) -> Result<Vec<CombinedTransactionEvents>, Error> {
+    let registry = registry(options);
+    let mut transactions_events: Vec<CombinedTransactionEvents> = Vec::new();
+    for transaction in block.transactions() {
+        let events = match parse_transaction_with_registry(transaction, &registry) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        if !events.is_empty() {
+            transactions_events.push(CombinedTransactionEvents {
+                signature: utils::transaction::get_signature(&transaction),
+                events
+            })
+        }
+    }
+    Ok(transactions_events)
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction, options: &ParseOptions) -> Result<Vec<CombinedEvent>, Error> {
+    parse_transaction_with_registry(transaction, &registry(options))
+}
+
+/// Walks the instructions of `transaction` once, handing each one to the parser registered for
+/// its program. Instructions that fail to parse are logged and skipped.
+///
+/// The events are then enriched by the same passes as in the individual modules: System Program
+/// events get their invoking program, WSOL wraps, nonce rent payers and account metas (as with
+/// `include_account_metas=true`), SPL Token transfers their mint, owners and decimals, and
+/// Raydium swaps their decimals, price and balance check.
+pub fn parse_transaction_with_registry(
+    transaction: &ConfirmedTransaction,
+    registry: &ParserRegistry<CombinedEvent>,
+) -> Result<Vec<CombinedEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new())
+    }
+
+    let (events, _failed_instruction_indices) = registry.parse_transaction_isolated(transaction)?;
+    let mut events: Vec<CombinedEvent> = events.into_iter()
+        .map(|(instruction_index, mut event)| {
+            set_instruction_index(&mut event, instruction_index);
+            event
+        })
+        .collect();
+
+    enrich_events(&mut events, system_program_event, |events| {
+        annotate_invoking_programs(transaction, events, PubkeyEncoding::Base58);
+        wsol::annotate_wsol_wraps(transaction, events, PubkeyEncoding::Base58)?;
+        nonce::annotate_nonce_rent_payers(events);
+        account_metas::annotate_account_metas(transaction, events, PubkeyEncoding::Base58);
+        Ok(())
+    })?;
+    enrich_events(&mut events, spl_token_event, |events| {
+        spl_token_substream::enrich_transfer_events(events, transaction);
+        Ok(())
+    })?;
+    enrich_events(&mut events, raydium_event, |events| {
+        raydium_amm_substream::enrich_swap_events(events, transaction);
+        Ok(())
+    })?;
+
+    Ok(events)
+}
+
+/// The parsers of the programs included by `options`.
+pub fn registry(options: &ParseOptions) -> ParserRegistry<CombinedEvent> {
+    let mut registry = ParserRegistry::new();
+    for program in options.programs.iter() {
+        match program {
+            Program::SystemProgram => {
+                registry.register(SystemProgramParser::default());
+            },
+            Program::SplToken => {
+                registry.register(SplTokenParser);
+            },
+            Program::MplTokenMetadata => {
+                registry.register(ProgramParser {
+                    program_id: MPL_TOKEN_METADATA_PROGRAM_ID,
+                    parse: |instruction, context| Ok(mpl_token_metadata_substream::parse_instruction(instruction, context)
+                        .map_err(ParseError::InvalidInstruction)?
+                        .map(|event| Event::MplTokenMetadata(MplTokenMetadataEvent { event: Some(event) }))),
+                });
+            },
+            Program::Raydium => {
+                registry.register(ProgramParser {
+                    program_id: RAYDIUM_AMM_PROGRAM_ID,
+                    parse: |instruction, context| Ok(raydium_amm_substream::parse_instruction(instruction, context)
+                        .map_err(ParseError::InvalidInstruction)?
+                        .map(|event| Event::Raydium(RaydiumAmmEvent { event: Some(event) }))),
+                });
+                registry.register(ProgramParser {
+                    program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                    parse: |instruction, context| Ok(raydium_amm_substream::parse_clmm_instruction(instruction, context)
+                        .map_err(ParseError::InvalidInstruction)?
+                        .map(|event| Event::Raydium(RaydiumAmmEvent { event: Some(event) }))),
+                });
+                registry.register(ProgramParser {
+                    program_id: RAYDIUM_CPMM_PROGRAM_ID,
+                    parse: |instruction, context| Ok(raydium_amm_substream::parse_cpmm_instruction(instruction, context)
+                        .map_err(ParseError::InvalidInstruction)?
+                        .map(|event| Event::Raydium(RaydiumAmmEvent { event: Some(event) }))),
+                });
+                registry.register(ProgramParser {
+                    program_id: RAYDIUM_STABLE_PROGRAM_ID,
+                    parse: |instruction, context| Ok(raydium_amm_substream::parse_stable_instruction(instruction, context)
+                        .map_err(ParseError::InvalidInstruction)?
+                        .map(|event| Event::Raydium(RaydiumAmmEvent { event: Some(event) }))),
+                });
+            },
+            Program::Pumpfun => {
+                registry.register(ProgramParser {
+                    program_id: PUMPFUN_PROGRAM_ID,
+                    parse: |instruction, context| Ok(pumpfun_substream::parse_instruction(instruction, context)?
+                        .map(|event| Event::Pumpfun(PumpfunEvent { event: Some(event) }))),
+                });
+            },
+            Program::Token2022 => {
+                registry.register(ProgramParser {
+                    program_id: TOKEN_2022_PROGRAM_ID,
+                    parse: |instruction, context| Ok(token_2022_substream::parse_instruction(instruction, context)?
+                        .map(|event| Event::Token2022(Token2022Event { event: Some(event), ..Default::default() }))),
+                });
+            },
+            Program::AssociatedTokenAccount => {
+                registry.register(ProgramParser {
+                    program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    parse: |instruction, context| Ok(associated_token_account_substream::parse_instruction(instruction, context)
+                        .map(|event| Some(Event::AssociatedTokenAccount(AssociatedTokenAccountEvent { event: Some(event), ..Default::default() })))?),
+                });
+            },
+            Program::BpfLoaderUpgradeable => {
+                registry.register(ProgramParser {
+                    program_id: BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+                    parse: |instruction, context| Ok(bpf_loader_upgradeable_substream::parse_instruction(instruction, context)
+                        .map(|event| Some(Event::BpfLoaderUpgradeable(BpfLoaderUpgradeableEvent { event: Some(event), ..Default::default() })))?),
+                });
+            },
+            Program::StakeProgram => {
+                registry.register(ProgramParser {
+                    program_id: STAKE_PROGRAM_ID,
+                    parse: |instruction, context| Ok(stake_program_substream::parse_instruction(instruction, context)?
+                        .map(|event| Event::StakeProgram(StakeProgramEvent { event: Some(event), ..Default::default() }))),
+                });
+            },
+            Program::AddressLookupTable => {
+                registry.register(ProgramParser {
+                    program_id: ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+                    parse: |instruction, context| Ok(address_lookup_table_substream::parse_instruction(instruction, context)
+                        .map(|event| Some(Event::AddressLookupTable(AddressLookupTableEvent { event: Some(event), ..Default::default() })))?),
+                });
+            },
+            Program::VoteProgram => {
+                registry.register(ProgramParser {
+                    program_id: VOTE_PROGRAM_ID,
+                    parse: |instruction, context| Ok(vote_program_substream::parse_instruction(instruction, context)?
+                        .map(|event| Event::VoteProgram(VoteProgramEvent { event: Some(event), ..Default::default() }))),
+                });
+            },
+        }
+    }
+    registry
+}
+
+/// A program crate's instruction parser, its events wrapped into the combined `Event`.
+struct ProgramParser {
+    program_id: Pubkey,
+    parse: fn(&StructuredInstruction, &TransactionContext) -> Result<Option<Event>, ParseError>,
+}
+
+impl InstructionParser<CombinedEvent> for ProgramParser {
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    fn parse(
+        &self,
+        instruction: &StructuredInstruction,
+        context: &TransactionContext,
+    ) -> Result<Option<CombinedEvent>, ParseError> {
+        Ok((self.parse)(instruction, context)?.map(|event| CombinedEvent {
+            program_id: self.program_id.to_string(),
+            event: Some(event),
+            ..Default::default()
+        }))
+    }
+}
+
+/// For `SystemProgramParser`, which yields any event type its events convert into.
+impl From<SystemEvent> for CombinedEvent {
+    fn from(event: SystemEvent) -> Self {
+        CombinedEvent {
+            program_id: SYSTEM_PROGRAM_ID.to_string(),
+            event: Some(Event::SystemProgram(SystemProgramEvent { event: Some(event), ..Default::default() })),
+            ..Default::default()
+        }
+    }
+}
+
+/// For `SplTokenParser`, which yields any event type its events convert into.
+impl From<TokenEvent> for CombinedEvent {
+    fn from(event: TokenEvent) -> Self {
+        CombinedEvent {
+            program_id: TOKEN_PROGRAM_ID.to_string(),
+            event: Some(Event::SplToken(SplTokenEvent { event: Some(event) })),
+            ..Default::default()
+        }
+    }
+}
+
+/// Sets the instruction index returned by the registry on the event, and on the program event
+/// when it has one.
+fn set_instruction_index(event: &mut CombinedEvent, instruction_index: u32) {
+    event.instruction_index = instruction_index;
+    match event.event.as_mut() {
+        Some(Event::SystemProgram(event)) => event.instruction_index = instruction_index,
+        Some(Event::Token2022(event)) => event.instruction_index = instruction_index,
+        Some(Event::AssociatedTokenAccount(event)) => event.instruction_index = instruction_index,
+        Some(Event::BpfLoaderUpgradeable(event)) => event.instruction_index = instruction_index,
+        Some(Event::StakeProgram(event)) => event.instruction_index = instruction_index,
+        Some(Event::AddressLookupTable(event)) => event.instruction_index = instruction_index,
+        Some(Event::VoteProgram(event)) => event.instruction_index = instruction_index,
+        _ => (),
+    }
+}
+
+fn system_program_event(event: &mut CombinedEvent) -> Option<&mut SystemProgramEvent> {
+    match event.event.as_mut() {
+        Some(Event::SystemProgram(event)) => Some(event),
+        _ => None,
+    }
+}
+
+fn spl_token_event(event: &mut CombinedEvent) -> Option<&mut SplTokenEvent> {
+    match event.event.as_mut() {
+        Some(Event::SplToken(event)) => Some(event),
+        _ => None,
+    }
+}
+
+fn raydium_event(event: &mut CombinedEvent) -> Option<&mut RaydiumAmmEvent> {
+    match event.event.as_mut() {
+        Some(Event::Raydium(event)) => Some(event),
+        _ => None,
+    }
+}
+
+/// Runs `enrich` over the program events that `select` picks out of `events`, moved into a
+/// vector in instruction order and back, so a program crate's enrichment pass over its own
+/// events applies unchanged.
+fn enrich_events<E: Default>(
+    events: &mut [CombinedEvent],
+    select: fn(&mut CombinedEvent) -> Option<&mut E>,
+    enrich: impl FnOnce(&mut [E]) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut slots: Vec<&mut E> = events.iter_mut().filter_map(select).collect();
+    if slots.is_empty() {
+        return Ok(())
+    }
+    let mut selected: Vec<E> = slots.iter_mut().map(|slot| std::mem::take(&mut **slot)).collect();
+    let result = enrich(&mut selected);
+    for (slot, event) in slots.into_iter().zip(selected) {
+        *slot = event;
+    }
+    result
+}
@@ -0,0 +1,91 @@
+use anyhow::{anyhow, Error};
+
+/// The programs whose events `combined_events` can emit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Program {
+    SystemProgram,
+    SplToken,
+    MplTokenMetadata,
+    /// Raydium AMM v4, CLMM, CPMM and stable swap.
+    Raydium,
+    Pumpfun,
+    Token2022,
+    AssociatedTokenAccount,
+    BpfLoaderUpgradeable,
+    StakeProgram,
+    AddressLookupTable,
+    /// Administrative instructions only; votes themselves produce no event.
+    VoteProgram,
+}
+
+impl Program {
+    pub const ALL: [Program; 11] = [
+        Program::SystemProgram,
+        Program::SplToken,
+        Program::MplTokenMetadata,
+        Program::Raydium,
+        Program::Pumpfun,
+        Program::Token2022,
+        Program::AssociatedTokenAccount,
+        Program::BpfLoaderUpgradeable,
+        Program::StakeProgram,
+        Program::AddressLookupTable,
+        Program::VoteProgram,
+    ];
+
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        match name {
+            "system_program" => Ok(Program::SystemProgram),
+            "spl_token" => Ok(Program::SplToken),
+            "mpl_token_metadata" => Ok(Program::MplTokenMetadata),
+            "raydium" => Ok(Program::Raydium),
+            "pumpfun" => Ok(Program::Pumpfun),
+            "token_2022" => Ok(Program::Token2022),
+            "associated_token_account" => Ok(Program::AssociatedTokenAccount),
+            "bpf_loader_upgradeable" => Ok(Program::BpfLoaderUpgradeable),
+            "stake_program" => Ok(Program::StakeProgram),
+            "address_lookup_table" => Ok(Program::AddressLookupTable),
+            "vote_program" => Ok(Program::VoteProgram),
+            _ => Err(anyhow!("Unknown program {}.", name)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ParseOptions {
+    /// Programs to emit events for. Instructions of any other program are skipped.
+    pub programs: Vec<Program>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            programs: Program::ALL.to_vec(),
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Parses module params of the form `key=value&key=value`. Empty params yield the defaults.
+    pub fn from_params(params: &str) -> Result<Self, Error> {
+        let mut options = ParseOptions::default();
+        for param in params.split('&').map(str::trim).filter(|param| !param.is_empty()) {
+            let (key, value) = param.split_once('=').ok_or_else(|| anyhow!("Invalid param {}.", param))?;
+            match key.trim() {
+                "programs" => {
+                    options.programs = value.split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(Program::from_name)
+                        .collect::<Result<_, _>>()?;
+                },
+                _ => return Err(anyhow!("Unknown param {}.", key)),
+            }
+        }
+        Ok(options)
+    }
+
+    pub fn includes(&self, program: Program) -> bool {
+        self.programs.contains(&program)
+    }
+}
@@ -0,0 +1,57 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CombinedBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<CombinedTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CombinedTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    /// In instruction execution order, inner instructions included.
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<CombinedEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CombinedEvent {
+    #[prost(string, tag="1")]
+    pub program_id: ::prost::alloc::string::String,
+    /// Index of the instruction in the flattened instruction list of the transaction.
+    #[prost(uint32, tag="2")]
+    pub instruction_index: u32,
+    #[prost(oneof="combined_event::Event", tags="3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13")]
+    pub event: ::core::option::Option<combined_event::Event>,
+}
+/// Nested message and enum types in `CombinedEvent`.
+pub mod combined_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="3")]
+        SystemProgram(::system_program_substream::pb::system_program::SystemProgramEvent),
+        #[prost(message, tag="4")]
+        SplToken(::spl_token_substream::pb::spl_token::SplTokenEvent),
+        #[prost(message, tag="5")]
+        MplTokenMetadata(::mpl_token_metadata_substream::pb::mpl_token_metadata::MplTokenMetadataEvent),
+        #[prost(message, tag="6")]
+        Raydium(::raydium_amm_substream::pb::raydium_amm::RaydiumAmmEvent),
+        #[prost(message, tag="7")]
+        Pumpfun(::pumpfun_substream::pb::pumpfun::PumpfunEvent),
+        #[prost(message, tag="8")]
+        Token2022(::token_2022_substream::pb::token_2022::Token2022Event),
+        #[prost(message, tag="9")]
+        AssociatedTokenAccount(::associated_token_account_substream::pb::associated_token_account::AssociatedTokenAccountEvent),
+        #[prost(message, tag="10")]
+        BpfLoaderUpgradeable(::bpf_loader_upgradeable_substream::pb::bpf_loader_upgradeable::BpfLoaderUpgradeableEvent),
+        #[prost(message, tag="11")]
+        StakeProgram(::stake_program_substream::pb::stake_program::StakeProgramEvent),
+        #[prost(message, tag="12")]
+        AddressLookupTable(::address_lookup_table_substream::pb::address_lookup_table::AddressLookupTableEvent),
+        #[prost(message, tag="13")]
+        VoteProgram(::vote_program_substream::pb::vote_program::VoteProgramEvent),
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:combined)
+pub mod combined {
+    include!("combined.rs");
+    // @@protoc_insertion_point(combined)
+}
@@ -8,12 +8,30 @@ use substreams_solana::pb::sf::solana::r#type::v1::Block;
 use substreams_solana_utils as utils;
 use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
 use utils::transaction::{get_context, TransactionContext};
+use utils::pubkey::Pubkey;
 
 pub mod mpl_token_metadata;
 use mpl_token_metadata::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
-use mpl_token_metadata::instruction::MetadataInstruction;
+use mpl_token_metadata::instruction::{
+    MetadataInstruction,
+    CreateArgs,
+    MintArgs,
+    UpdateArgs,
+    TransferArgs,
+    BurnArgs,
+    DelegateArgs,
+    CollectionToggle,
+    UsesToggle,
+    CreateMasterEditionArgs,
+    UpdateMetadataAccountArgsV2,
+    MigrateArgs,
+    MIGRATE,
+};
+use mpl_token_metadata::state::{MasterEdition, MasterEditionAccount, Metadata, MigrationType};
+use mpl_token_metadata::error::ProgramError;
 
 pub mod pb;
+pub mod metadata_registry;
 use pb::mpl_token_metadata::*;
 use pb::mpl_token_metadata::mpl_token_metadata_event::Event;
 
@@ -23,6 +41,52 @@ fn mpl_token_metadata_events(block: Block) -> Result<MplTokenMetadataBlockEvents
     Ok(MplTokenMetadataBlockEvents { transactions })
 }
 
+/// Metadata records of the creations and updates in `events`, normalized for indexing.
+#[substreams::handlers::map]
+fn token_metadata_registry(events: MplTokenMetadataBlockEvents) -> Result<MetadataRecords, Error> {
+    Ok(MetadataRecords { records: metadata_registry::metadata_records(&events) })
+}
+
+/// Decodes the master edition accounts among `updates`. Other accounts are skipped.
+#[substreams::handlers::map]
+fn master_edition_events(updates: AccountUpdates) -> Result<MasterEditionEvents, Error> {
+    let master_editions = updates.accounts.iter()
+        .filter(|account| account.owner == MPL_TOKEN_METADATA_PROGRAM_ID.to_string())
+        .filter_map(|account| parse_master_edition_account(&account.address, &account.data).ok())
+        .collect();
+    Ok(MasterEditionEvents { master_editions })
+}
+
+pub fn parse_master_edition_account(address: &str, data: &[u8]) -> Result<MasterEditionEvent, String> {
+    let master_edition = MasterEditionAccount::from_bytes(data).map_err(|e| format!("Failed to decode master edition: {}", e))?;
+
+    Ok(MasterEditionEvent {
+        address: address.to_string(),
+        key: Key::from(master_edition.key()).into(),
+        supply: master_edition.supply(),
+        max_supply: master_edition.max_supply(),
+    })
+}
+
+pub fn parse_metadata_account(data: &[u8]) -> Result<MetadataEvent, ProgramError> {
+    let metadata = Metadata::from_bytes(data)?.normalized();
+
+    Ok(MetadataEvent {
+        mint: metadata.mint.to_string(),
+        update_authority: metadata.update_authority.to_string(),
+        name: metadata.data.name,
+        symbol: metadata.data.symbol,
+        uri: metadata.data.uri,
+        seller_fee_basis_points: metadata.data.seller_fee_basis_points.into(),
+        creators: metadata.data.creators.unwrap_or_default().iter().map(|x| x.into()).collect(),
+        collection: metadata.collection.map(|x| x.into()),
+        token_standard: metadata.token_standard.map(|x| TokenStandard::from(x).into()),
+        primary_sale_happened: metadata.primary_sale_happened,
+        is_mutable: metadata.is_mutable,
+        collection_details: metadata.collection_details.map(|x| x.into()),
+    })
+}
+
 pub fn parse_block(block: &Block) -> Vec<MplTokenMetadataTransactionEvents> {
     let mut block_events: Vec<MplTokenMetadataTransactionEvents> = Vec::new();
 
@@ -40,10 +104,15 @@ pub fn parse_block(block: &Block) -> Vec<MplTokenMetadataTransactionEvents> {
 }
 
 pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<MplTokenMetadataEvent>, String> {
+    if transaction.meta.is_none() {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    }
+
     let mut events: Vec<MplTokenMetadataEvent> = Vec::new();
 
-    let context = get_context(transaction).unwrap();
-    let instructions = get_structured_instructions(transaction).unwrap();
+    let context = get_context(transaction).map_err(|e| e.to_string())?;
+    let instructions = get_structured_instructions(transaction).map_err(|e| e.to_string())?;
 
     for instruction in instructions.flattened().iter() {
         if instruction.program_id() != MPL_TOKEN_METADATA_PROGRAM_ID {
@@ -64,6 +133,10 @@ pub fn parse_instruction(
     if instruction.program_id() != MPL_TOKEN_METADATA_PROGRAM_ID {
         return Err("Not a Metaplex Token Metadata instruction.".into());
     }
+    // Migrate args predate the argument-less variant of `MetadataInstruction`, which rejects them.
+    if let Some((&MIGRATE, args)) = instruction.data().split_first() {
+        return _parse_migrate_instruction(instruction, context, args).map(|x| Some(Event::Migrate(x)));
+    }
     let unpacked = MetadataInstruction::try_from_slice(instruction.data()).map_err(|_| "Failed to parse MetadataInstruction.")?;
     match unpacked {
         MetadataInstruction::ApproveCollectionAuthority => {
@@ -75,14 +148,14 @@ pub fn parse_instruction(
         MetadataInstruction::BubblegumSetCollectionSize(_) => {
             Ok(Some(Event::BubblegumSetCollectionSize(BubblegumSetCollectionSizeEvent {})))
         },
-        MetadataInstruction::Burn(_) => {
-            Ok(Some(Event::Burn(BurnEvent {})))
+        MetadataInstruction::Burn(burn_args) => {
+            _parse_burn_instruction(instruction, context, burn_args).map(|x| Some(Event::Burn(x)))
         },
         MetadataInstruction::BurnEditionNft => {
             Ok(Some(Event::BurnEditionNft(BurnEditionNftEvent {})))
         },
         MetadataInstruction::BurnNft => {
-            Ok(Some(Event::BurnNft(BurnNftEvent {})))
+            _parse_burn_nft_instruction(instruction, context).map(|x| Some(Event::BurnNft(x)))
         },
         MetadataInstruction::CloseEscrowAccount => {
             Ok(Some(Event::CloseEscrowAccount(CloseEscrowAccountEvent {})))
@@ -90,8 +163,8 @@ pub fn parse_instruction(
         MetadataInstruction::ConvertMasterEditionV1ToV2 => {
             Ok(Some(Event::ConvertMasterEditionV1ToV2(ConvertMasterEditionV1ToV2Event {})))
         },
-        MetadataInstruction::Create(_) => {
-            Ok(Some(Event::Create(CreateEvent {})))
+        MetadataInstruction::Create(create_args) => {
+            _parse_create_instruction(instruction, context, create_args).map(|x| Some(Event::Create(x)))
         },
         MetadataInstruction::CreateEscrowAccount => {
             Ok(Some(Event::CreateEscrowAccount(CreateEscrowAccountEvent {})))
@@ -99,8 +172,8 @@ pub fn parse_instruction(
         MetadataInstruction::CreateMasterEdition => {
             Ok(Some(Event::CreateMasterEdition(CreateMasterEditionEvent {})))
         },
-        MetadataInstruction::CreateMasterEditionV3(_) => {
-            Ok(Some(Event::CreateMasterEditionV3(CreateMasterEditionV3Event {})))
+        MetadataInstruction::CreateMasterEditionV3(create_master_edition_args) => {
+            _parse_create_master_edition_v3_instruction(instruction, context, create_master_edition_args).map(|x| Some(Event::CreateMasterEditionV3(x)))
         },
         MetadataInstruction::CreateMetadataAccount => {
             Ok(Some(Event::CreateMetadataAccount(CreateMetadataAccountEvent {})))
@@ -111,8 +184,8 @@ pub fn parse_instruction(
         MetadataInstruction::CreateMetadataAccountV3(create_metadata_account_v3) => {
             _parse_create_metadata_account_v3_instruction(instruction, context, create_metadata_account_v3).map(|x| Some(Event::CreateMetadataAccountV3(x)))
         },
-        MetadataInstruction::Delegate(_) => {
-            Ok(Some(Event::Delegate(DelegateEvent {})))
+        MetadataInstruction::Delegate(delegate_args) => {
+            _parse_delegate_instruction(instruction, context, delegate_args).map(|x| Some(Event::Delegate(x)))
         },
         MetadataInstruction::DeprecatedCreateMasterEdition => {
             Ok(Some(Event::DeprecatedCreateMasterEdition(DeprecatedCreateMasterEditionEvent {})))
@@ -139,7 +212,7 @@ pub fn parse_instruction(
             Ok(Some(Event::Lock(LockEvent {})))
         },
         MetadataInstruction::Migrate => {
-            Ok(Some(Event::Migrate(MigrateEvent {})))
+            _parse_migrate_instruction(instruction, context, &[]).map(|x| Some(Event::Migrate(x)))
         },
         MetadataInstruction::MintNewEditionFromMasterEditionViaToken(_) => {
             Ok(Some(Event::MintNewEditionFromMasterEditionViaToken(MintNewEditionFromMasterEditionViaTokenEvent {})))
@@ -163,7 +236,7 @@ pub fn parse_instruction(
             Ok(Some(Event::RevokeUseAuthority(RevokeUseAuthorityEvent {})))
         },
         MetadataInstruction::SetAndVerifyCollection => {
-            Ok(Some(Event::SetAndVerifyCollection(SetAndVerifyCollectionEvent {})))
+            _parse_set_and_verify_collection_instruction(instruction, context).map(|x| Some(Event::SetAndVerifyCollection(x)))
         },
         MetadataInstruction::SetAndVerifySizedCollectionItem => {
             Ok(Some(Event::SetAndVerifySizedCollectionItem(SetAndVerifySizedCollectionItemEvent {})))
@@ -172,13 +245,13 @@ pub fn parse_instruction(
             Ok(Some(Event::SetTokenStandard(SetTokenStandardEvent {})))
         },
         MetadataInstruction::SignMetadata => {
-            Ok(Some(Event::SignMetadata(SignMetadataEvent {})))
+            _parse_sign_metadata_instruction(instruction, context).map(|x| Some(Event::SignMetadata(x)))
         },
         MetadataInstruction::ThawDelegatedAccount => {
             Ok(Some(Event::ThawDelegatedAccount(ThawDelegatedAccountEvent {})))
         },
-        MetadataInstruction::Transfer(_) => {
-            Ok(Some(Event::Transfer(TransferEvent {})))
+        MetadataInstruction::Transfer(transfer_args) => {
+            _parse_transfer_instruction(instruction, context, transfer_args).map(|x| Some(Event::Transfer(x)))
         },
         MetadataInstruction::TransferOutOfEscrow(_) => {
             Ok(Some(Event::TransferOutOfEscrow(TransferOutOfEscrowEvent {})))
@@ -190,19 +263,19 @@ pub fn parse_instruction(
             Ok(Some(Event::Unverify(UnverifyEvent {})))
         },
         MetadataInstruction::UnverifyCollection => {
-            Ok(Some(Event::UnverifyCollection(UnverifyCollectionEvent {})))
+            _parse_unverify_collection_instruction(instruction, context).map(|x| Some(Event::UnverifyCollection(x)))
         },
         MetadataInstruction::UnverifySizedCollectionItem => {
             Ok(Some(Event::UnverifySizedCollectionItem(UnverifySizedCollectionItemEvent {})))
         },
-        MetadataInstruction::Update(_) => {
-            Ok(Some(Event::Update(UpdateEvent {})))
+        MetadataInstruction::Update(update_args) => {
+            _parse_update_instruction(instruction, context, update_args).map(|x| Some(Event::Update(x)))
         },
         MetadataInstruction::UpdateMetadataAccount => {
             Ok(Some(Event::UpdateMetadataAccount(UpdateMetadataAccountEvent {})))
         },
-        MetadataInstruction::UpdateMetadataAccountV2(_) => {
-            Ok(Some(Event::UpdateMetadataAccountV2(UpdateMetadataAccountV2Event {})))
+        MetadataInstruction::UpdateMetadataAccountV2(update_metadata_account_args) => {
+            _parse_update_metadata_account_v2_instruction(instruction, context, update_metadata_account_args).map(|x| Some(Event::Update(x)))
         },
         MetadataInstruction::UpdatePrimarySaleHappenedViaToken => {
             Ok(Some(Event::UpdatePrimarySaleHappenedViaToken(UpdatePrimarySaleHappenedViaTokenEvent {})))
@@ -216,8 +289,8 @@ pub fn parse_instruction(
         MetadataInstruction::Verify(_) => {
             Ok(Some(Event::Verify(VerifyEvent {})))
         },
-        MetadataInstruction::Mint(_) => {
-            Ok(Some(Event::Mint(MintEvent {})))
+        MetadataInstruction::Mint(mint_args) => {
+            _parse_mint_instruction(instruction, context, mint_args).map(|x| Some(Event::Mint(x)))
         },
         MetadataInstruction::SetCollectionSize(_) => {
             Ok(Some(Event::SetCollectionSize(SetCollectionSizeEvent {})))
@@ -232,7 +305,7 @@ pub fn parse_instruction(
             Ok(Some(Event::VerifySizedCollectionItem(VerifySizedCollectionItemEvent {})))
         },
         MetadataInstruction::VerifyCollection => {
-            Ok(Some(Event::VerifyCollection(VerifyCollectionEvent {})))
+            _parse_verify_collection_instruction(instruction, context).map(|x| Some(Event::VerifyCollection(x)))
         },
     }
 }
@@ -259,6 +332,432 @@ fn _parse_create_metadata_account_v3_instruction<'a>(
     })
 }
 
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, String> {
+    instruction.accounts().get(index).cloned().ok_or_else(|| "Instruction is missing accounts.".to_string())
+}
+
+/// Optional accounts left out by the caller are passed as the program id itself.
+fn _get_optional_account(instruction: &StructuredInstruction, index: usize) -> Option<String> {
+    instruction.accounts().get(index)
+        .filter(|account| **account != MPL_TOKEN_METADATA_PROGRAM_ID)
+        .map(|account| account.to_string())
+}
+
+fn _parse_create_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    create_args: CreateArgs,
+) -> Result<CreateEvent, String> {
+    let CreateArgs::V1 { asset_data, decimals, print_supply } = create_args;
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let master_edition = _get_optional_account(instruction, 1);
+    let mint = _get_account(instruction, 2)?.to_string();
+    let authority = _get_account(instruction, 3)?.to_string();
+    let payer = _get_account(instruction, 4)?.to_string();
+    let update_authority = _get_account(instruction, 5)?.to_string();
+    let validation_error = asset_data.validate().err().map(|e| e.to_string());
+
+    Ok(CreateEvent {
+        metadata,
+        master_edition,
+        mint,
+        authority,
+        payer,
+        update_authority,
+        asset_data: Some(asset_data.into()),
+        decimals: decimals.map(|x| x.into()),
+        print_supply: print_supply.map(|x| PrintSupply { max_supply: x.to_option() }),
+        valid: validation_error.is_none(),
+        validation_error,
+    })
+}
+
+fn _parse_mint_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    mint_args: MintArgs,
+) -> Result<MintEvent, String> {
+    let MintArgs::V1 { amount, .. } = mint_args;
+    let token = _get_account(instruction, 0)?.to_string();
+    let token_owner = _get_optional_account(instruction, 1);
+    let metadata = _get_account(instruction, 2)?.to_string();
+    let master_edition = _get_optional_account(instruction, 3);
+    let token_record = _get_optional_account(instruction, 4);
+    let mint = _get_account(instruction, 5)?.to_string();
+    let authority = _get_account(instruction, 6)?.to_string();
+    let delegate_record = _get_optional_account(instruction, 7);
+    let payer = _get_account(instruction, 8)?.to_string();
+
+    Ok(MintEvent {
+        token,
+        token_owner,
+        metadata,
+        master_edition,
+        token_record,
+        mint,
+        authority,
+        delegate_record,
+        payer,
+        amount,
+    })
+}
+
+fn _parse_update_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    update_args: UpdateArgs,
+) -> Result<UpdateEvent, String> {
+    let authority = _get_account(instruction, 0)?.to_string();
+    let delegate_record = _get_optional_account(instruction, 1);
+    let token = _get_optional_account(instruction, 2);
+    let mint = _get_account(instruction, 3)?.to_string();
+    let metadata = _get_account(instruction, 4)?.to_string();
+    let payer = _get_account(instruction, 6)?.to_string();
+
+    let (update_type, new_update_authority, data, primary_sale_happened, is_mutable, collection, uses) = match update_args {
+        UpdateArgs::V1 { new_update_authority, data, primary_sale_happened, is_mutable, collection, uses, .. } => {
+            ("V1", new_update_authority, data, primary_sale_happened, is_mutable, collection, uses)
+        },
+        UpdateArgs::AsUpdateAuthorityV2 { new_update_authority, data, primary_sale_happened, is_mutable, collection, uses, .. } => {
+            ("AsUpdateAuthorityV2", new_update_authority, data, primary_sale_happened, is_mutable, collection, uses)
+        },
+        UpdateArgs::AsAuthorityItemDelegateV2 { new_update_authority, primary_sale_happened, is_mutable, .. } => {
+            ("AsAuthorityItemDelegateV2", new_update_authority, None, primary_sale_happened, is_mutable, CollectionToggle::None, UsesToggle::None)
+        },
+        UpdateArgs::AsCollectionDelegateV2 { collection, .. } => {
+            ("AsCollectionDelegateV2", None, None, None, None, collection, UsesToggle::None)
+        },
+        UpdateArgs::AsDataDelegateV2 { data, .. } => {
+            ("AsDataDelegateV2", None, data, None, None, CollectionToggle::None, UsesToggle::None)
+        },
+        UpdateArgs::AsProgrammableConfigDelegateV2 { .. } => {
+            ("AsProgrammableConfigDelegateV2", None, None, None, None, CollectionToggle::None, UsesToggle::None)
+        },
+        UpdateArgs::AsDataItemDelegateV2 { data, .. } => {
+            ("AsDataItemDelegateV2", None, data, None, None, CollectionToggle::None, UsesToggle::None)
+        },
+        UpdateArgs::AsCollectionItemDelegateV2 { collection, .. } => {
+            ("AsCollectionItemDelegateV2", None, None, None, None, collection, UsesToggle::None)
+        },
+        UpdateArgs::AsProgrammableConfigItemDelegateV2 { .. } => {
+            ("AsProgrammableConfigItemDelegateV2", None, None, None, None, CollectionToggle::None, UsesToggle::None)
+        },
+    };
+    let collection = match collection {
+        CollectionToggle::Set(collection) => Some(collection.into()),
+        CollectionToggle::None | CollectionToggle::Clear => None,
+    };
+    let uses = match uses {
+        UsesToggle::Set(uses) => Some(uses.into()),
+        UsesToggle::None | UsesToggle::Clear => None,
+    };
+
+    Ok(UpdateEvent {
+        authority,
+        delegate_record,
+        token,
+        mint,
+        metadata,
+        payer,
+        update_type: update_type.to_string(),
+        new_update_authority: new_update_authority.map(|x| x.to_string()),
+        data: data.map(|x| x.into()),
+        primary_sale_happened,
+        is_mutable,
+        legacy: false,
+        collection,
+        uses,
+    })
+}
+
+fn _parse_update_metadata_account_v2_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    update_metadata_account_args: UpdateMetadataAccountArgsV2,
+) -> Result<UpdateEvent, String> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+    let (data, collection, uses) = match update_metadata_account_args.data {
+        Some(data) => (Some(data.to_v1().into()), data.collection.map(|x| x.into()), data.uses.map(|x| x.into())),
+        None => (None, None, None),
+    };
+
+    Ok(UpdateEvent {
+        authority,
+        delegate_record: None,
+        token: None,
+        mint: String::new(),
+        metadata,
+        payer: String::new(),
+        update_type: "UpdateMetadataAccountV2".to_string(),
+        new_update_authority: update_metadata_account_args.update_authority.map(|x| x.to_string()),
+        data,
+        primary_sale_happened: update_metadata_account_args.primary_sale_happened,
+        is_mutable: update_metadata_account_args.is_mutable,
+        legacy: true,
+        collection,
+        uses,
+    })
+}
+
+fn _parse_create_master_edition_v3_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    create_master_edition_args: CreateMasterEditionArgs,
+) -> Result<CreateMasterEditionV3Event, String> {
+    let edition = _get_account(instruction, 0)?.to_string();
+    let mint = _get_account(instruction, 1)?.to_string();
+    let update_authority = _get_account(instruction, 2)?.to_string();
+    let mint_authority = _get_account(instruction, 3)?.to_string();
+    let payer = _get_account(instruction, 4)?.to_string();
+    let metadata = _get_account(instruction, 5)?.to_string();
+
+    Ok(CreateMasterEditionV3Event {
+        edition,
+        mint,
+        update_authority,
+        mint_authority,
+        payer,
+        metadata,
+        max_supply: create_master_edition_args.max_supply,
+    })
+}
+
+fn _parse_verify_collection_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+) -> Result<VerifyCollectionEvent, String> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let collection_authority = _get_account(instruction, 1)?.to_string();
+    let payer = _get_account(instruction, 2)?.to_string();
+    let collection_mint = _get_account(instruction, 3)?.to_string();
+    let collection = _get_account(instruction, 4)?.to_string();
+    let collection_master_edition = _get_account(instruction, 5)?.to_string();
+    let collection_authority_record = _get_optional_account(instruction, 6);
+
+    Ok(VerifyCollectionEvent {
+        metadata,
+        collection_authority,
+        payer,
+        collection_mint,
+        collection,
+        collection_master_edition,
+        collection_authority_record,
+    })
+}
+
+fn _parse_unverify_collection_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+) -> Result<UnverifyCollectionEvent, String> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let collection_authority = _get_account(instruction, 1)?.to_string();
+    let collection_mint = _get_account(instruction, 2)?.to_string();
+    let collection = _get_account(instruction, 3)?.to_string();
+    let collection_master_edition = _get_account(instruction, 4)?.to_string();
+    let collection_authority_record = _get_optional_account(instruction, 5);
+
+    Ok(UnverifyCollectionEvent {
+        metadata,
+        collection_authority,
+        collection_mint,
+        collection,
+        collection_master_edition,
+        collection_authority_record,
+    })
+}
+
+fn _parse_set_and_verify_collection_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+) -> Result<SetAndVerifyCollectionEvent, String> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let collection_authority = _get_account(instruction, 1)?.to_string();
+    let payer = _get_account(instruction, 2)?.to_string();
+    let update_authority = _get_account(instruction, 3)?.to_string();
+    let collection_mint = _get_account(instruction, 4)?.to_string();
+    let collection = _get_account(instruction, 5)?.to_string();
+    let collection_master_edition = _get_account(instruction, 6)?.to_string();
+    let collection_authority_record = _get_optional_account(instruction, 7);
+
+    Ok(SetAndVerifyCollectionEvent {
+        metadata,
+        collection_authority,
+        payer,
+        update_authority,
+        collection_mint,
+        collection,
+        collection_master_edition,
+        collection_authority_record,
+    })
+}
+
+fn _parse_sign_metadata_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+) -> Result<SignMetadataEvent, String> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let creator = _get_account(instruction, 1)?.to_string();
+
+    Ok(SignMetadataEvent {
+        metadata,
+        creator,
+    })
+}
+
+fn _parse_transfer_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    transfer_args: TransferArgs,
+) -> Result<TransferEvent, String> {
+    let TransferArgs::V1 { amount, .. } = transfer_args;
+    let token = _get_account(instruction, 0)?.to_string();
+    let token_owner = _get_account(instruction, 1)?.to_string();
+    let destination = _get_account(instruction, 2)?.to_string();
+    let destination_owner = _get_account(instruction, 3)?.to_string();
+    let mint = _get_account(instruction, 4)?.to_string();
+    let metadata = _get_account(instruction, 5)?.to_string();
+    let owner_token_record = _get_optional_account(instruction, 7);
+    let destination_token_record = _get_optional_account(instruction, 8);
+    let authority = _get_account(instruction, 9)?.to_string();
+    let payer = _get_account(instruction, 10)?.to_string();
+
+    Ok(TransferEvent {
+        token,
+        token_owner,
+        destination,
+        destination_owner,
+        mint,
+        metadata,
+        owner_token_record,
+        destination_token_record,
+        authority,
+        payer,
+        amount,
+    })
+}
+
+fn _parse_burn_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    burn_args: BurnArgs,
+) -> Result<BurnEvent, String> {
+    let BurnArgs::V1 { amount } = burn_args;
+    let authority = _get_account(instruction, 0)?.to_string();
+    let metadata = _get_account(instruction, 2)?.to_string();
+    let edition = _get_optional_account(instruction, 3);
+    let mint = _get_account(instruction, 4)?.to_string();
+    let token = _get_account(instruction, 5)?.to_string();
+    let token_record = _get_optional_account(instruction, 10);
+
+    Ok(BurnEvent {
+        authority,
+        metadata,
+        edition,
+        mint,
+        token,
+        token_record,
+        amount,
+    })
+}
+
+fn _parse_burn_nft_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+) -> Result<BurnNftEvent, String> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let owner = _get_account(instruction, 1)?.to_string();
+    let mint = _get_account(instruction, 2)?.to_string();
+    let token = _get_account(instruction, 3)?.to_string();
+    let master_edition = _get_account(instruction, 4)?.to_string();
+    let collection_metadata = _get_optional_account(instruction, 6);
+
+    Ok(BurnNftEvent {
+        metadata,
+        owner,
+        mint,
+        token,
+        master_edition,
+        collection_metadata,
+    })
+}
+
+fn _parse_migrate_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    args: &[u8],
+) -> Result<MigrateEvent, String> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let mint = _get_account(instruction, 4)?.to_string();
+    let authority = _get_account(instruction, 6)?.to_string();
+    let collection_metadata = _get_account(instruction, 7)?.to_string();
+
+    let (migration_type, rule_set) = match MigrateArgs::from_bytes(args).map_err(|e| e.to_string())? {
+        Some(MigrateArgs::V1 { migration_type, rule_set }) => {
+            let migration_type = match migration_type {
+                MigrationType::CollectionV1 => "CollectionV1",
+                MigrationType::ProgrammableV1 => "ProgrammableV1",
+            };
+            (Some(migration_type.to_string()), rule_set.map(|x| x.to_string()))
+        },
+        None => (None, None),
+    };
+
+    Ok(MigrateEvent {
+        metadata,
+        mint,
+        authority,
+        collection_metadata,
+        migration_type,
+        rule_set,
+    })
+}
+
+fn _parse_delegate_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    delegate_args: DelegateArgs,
+) -> Result<DelegateEvent, String> {
+    let delegate_record = _get_optional_account(instruction, 0);
+    let delegate = _get_account(instruction, 1)?.to_string();
+    let metadata = _get_account(instruction, 2)?.to_string();
+    let token_record = _get_optional_account(instruction, 4);
+    let mint = _get_account(instruction, 5)?.to_string();
+    let token = _get_optional_account(instruction, 6);
+    let authority = _get_account(instruction, 7)?.to_string();
+    let payer = _get_account(instruction, 8)?.to_string();
+
+    let (role, amount) = match delegate_args {
+        DelegateArgs::CollectionV1 { .. } => ("CollectionV1", None),
+        DelegateArgs::SaleV1 { amount, .. } => ("SaleV1", Some(amount)),
+        DelegateArgs::TransferV1 { amount, .. } => ("TransferV1", Some(amount)),
+        DelegateArgs::DataV1 { .. } => ("DataV1", None),
+        DelegateArgs::UtilityV1 { amount, .. } => ("UtilityV1", Some(amount)),
+        DelegateArgs::StakingV1 { amount, .. } => ("StakingV1", Some(amount)),
+        DelegateArgs::StandardV1 { amount } => ("StandardV1", Some(amount)),
+        DelegateArgs::LockedTransferV1 { amount, .. } => ("LockedTransferV1", Some(amount)),
+        DelegateArgs::ProgrammableConfigV1 { .. } => ("ProgrammableConfigV1", None),
+        DelegateArgs::AuthorityItemV1 { .. } => ("AuthorityItemV1", None),
+        DelegateArgs::DataItemV1 { .. } => ("DataItemV1", None),
+        DelegateArgs::CollectionItemV1 { .. } => ("CollectionItemV1", None),
+        DelegateArgs::ProgrammableConfigItemV1 { .. } => ("ProgrammableConfigItemV1", None),
+        DelegateArgs::PrintDelegateV1 { .. } => ("PrintDelegateV1", None),
+    };
+
+    Ok(DelegateEvent {
+        delegate_record,
+        delegate,
+        metadata,
+        mint,
+        token,
+        token_record,
+        authority,
+        payer,
+        role: role.to_string(),
+        amount,
+    })
+}
+
 impl From<mpl_token_metadata::state::DataV2> for DataV2 {
     fn from(value: mpl_token_metadata::state::DataV2) -> Self {
         DataV2 {
@@ -273,6 +772,37 @@ impl From<mpl_token_metadata::state::DataV2> for DataV2 {
     }
 }
 
+impl From<mpl_token_metadata::state::Data> for Data {
+    fn from(value: mpl_token_metadata::state::Data) -> Self {
+        Data {
+            creators: value.creators.unwrap_or_else(Vec::new).iter().map(|x| x.into()).collect(),
+            name: value.name,
+            seller_fee_basis_points: value.seller_fee_basis_points.into(),
+            symbol: value.symbol,
+            uri: value.uri,
+        }
+    }
+}
+
+impl From<mpl_token_metadata::state::AssetData> for AssetData {
+    fn from(value: mpl_token_metadata::state::AssetData) -> Self {
+        AssetData {
+            name: value.name,
+            symbol: value.symbol,
+            uri: value.uri,
+            seller_fee_basis_points: value.seller_fee_basis_points.into(),
+            creators: value.creators.unwrap_or_else(Vec::new).iter().map(|x| x.into()).collect(),
+            primary_sale_happened: value.primary_sale_happened,
+            is_mutable: value.is_mutable,
+            token_standard: TokenStandard::from(value.token_standard).into(),
+            collection: value.collection.map(|x| x.into()),
+            uses: value.uses.map(|x| x.into()),
+            collection_details: value.collection_details.map(|x| x.into()),
+            rule_set: value.rule_set.map(|x| x.to_string()),
+        }
+    }
+}
+
 impl From<mpl_token_metadata::state::Collection> for Collection {
     fn from(value: mpl_token_metadata::state::Collection) -> Self {
         Collection {
@@ -326,3 +856,40 @@ impl From<mpl_token_metadata::state::CollectionDetails> for CollectionDetails {
         }
     }
 }
+
+impl From<mpl_token_metadata::state::TokenStandard> for TokenStandard {
+    fn from(value: mpl_token_metadata::state::TokenStandard) -> Self {
+        use mpl_token_metadata::state::TokenStandard as StateTokenStandard;
+        match value {
+            StateTokenStandard::NonFungible => Self::NonFungible,
+            StateTokenStandard::FungibleAsset => Self::FungibleAsset,
+            StateTokenStandard::Fungible => Self::Fungible,
+            StateTokenStandard::NonFungibleEdition => Self::NonFungibleEdition,
+            StateTokenStandard::ProgrammableNonFungible => Self::ProgrammableNonFungible,
+            StateTokenStandard::ProgrammableNonFungibleEdition => Self::ProgrammableNonFungibleEdition,
+        }
+    }
+}
+
+impl From<mpl_token_metadata::state::Key> for Key {
+    fn from(value: mpl_token_metadata::state::Key) -> Self {
+        use mpl_token_metadata::state::Key as StateKey;
+        match value {
+            StateKey::Uninitialized => Self::Uninitialized,
+            StateKey::EditionV1 => Self::EditionV1,
+            StateKey::MasterEditionV1 => Self::MasterEditionV1,
+            StateKey::ReservationListV1 => Self::ReservationListV1,
+            StateKey::MetadataV1 => Self::MetadataV1,
+            StateKey::ReservationListV2 => Self::ReservationListV2,
+            StateKey::MasterEditionV2 => Self::MasterEditionV2,
+            StateKey::EditionMarker => Self::EditionMarker,
+            StateKey::UseAuthorityRecord => Self::UseAuthorityRecord,
+            StateKey::CollectionAuthorityRecord => Self::CollectionAuthorityRecord,
+            StateKey::TokenOwnedEscrow => Self::TokenOwnedEscrow,
+            StateKey::TokenRecord => Self::TokenRecord,
+            StateKey::MetadataDelegate => Self::MetadataDelegate,
+            StateKey::EditionMarkerV2 => Self::EditionMarkerV2,
+            StateKey::HolderDelegate => Self::HolderDelegate,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Metadata records built from the creation and update instructions, for indexing the current
+//! metadata of each asset without reading account state.
+
+use crate::mpl_token_metadata::state::data::strip_padding;
+use crate::pb::mpl_token_metadata::*;
+use crate::pb::mpl_token_metadata::mpl_token_metadata_event::Event;
+
+pub fn metadata_records(block_events: &MplTokenMetadataBlockEvents) -> Vec<MetadataRecord> {
+    block_events.transactions.iter()
+        .flat_map(|transaction| transaction.events.iter().map(move |event| (&transaction.signature, event)))
+        .filter_map(|(signature, event)| metadata_record(signature, event.event.as_ref()?))
+        .collect()
+}
+
+/// The record of a create or update event, `None` for other events and for updates that
+/// change none of the record fields.
+pub fn metadata_record(signature: &str, event: &Event) -> Option<MetadataRecord> {
+    let record = match event {
+        Event::Create(create) => {
+            let asset_data = create.asset_data.as_ref()?;
+            MetadataRecord {
+                action: MetadataAction::Created.into(),
+                metadata_pda: create.metadata.clone(),
+                mint: create.mint.clone(),
+                update_authority: Some(create.update_authority.clone()),
+                name: Some(strip_padding(&asset_data.name)),
+                symbol: Some(strip_padding(&asset_data.symbol)),
+                uri: Some(strip_padding(&asset_data.uri)),
+                seller_fee_bps: Some(asset_data.seller_fee_basis_points),
+                creators: dedup_creators(&asset_data.creators),
+                collection: asset_data.collection.clone(),
+                token_standard: Some(asset_data.token_standard),
+                is_mutable: Some(asset_data.is_mutable),
+                ..Default::default()
+            }
+        },
+        Event::CreateMetadataAccountV3(create) => {
+            let data = create.data.as_ref()?;
+            MetadataRecord {
+                action: MetadataAction::Created.into(),
+                metadata_pda: create.metadata.clone(),
+                mint: create.mint.clone(),
+                update_authority: Some(create.update_authority.clone()),
+                name: Some(strip_padding(&data.name)),
+                symbol: Some(strip_padding(&data.symbol)),
+                uri: Some(strip_padding(&data.uri)),
+                seller_fee_bps: Some(data.seller_fee_basis_points),
+                creators: dedup_creators(&data.creators),
+                collection: data.collection.clone(),
+                is_mutable: Some(create.is_mutable),
+                ..Default::default()
+            }
+        },
+        Event::Update(update) => {
+            let data = update.data.as_ref();
+            if data.is_none() && update.new_update_authority.is_none() && update.collection.is_none() && update.is_mutable.is_none() {
+                return None;
+            }
+            MetadataRecord {
+                action: MetadataAction::Updated.into(),
+                metadata_pda: update.metadata.clone(),
+                mint: update.mint.clone(),
+                update_authority: update.new_update_authority.clone(),
+                name: data.map(|data| strip_padding(&data.name)),
+                symbol: data.map(|data| strip_padding(&data.symbol)),
+                uri: data.map(|data| strip_padding(&data.uri)),
+                seller_fee_bps: data.map(|data| data.seller_fee_basis_points),
+                creators: data.map(|data| dedup_creators(&data.creators)).unwrap_or_default(),
+                collection: update.collection.clone(),
+                is_mutable: update.is_mutable,
+                ..Default::default()
+            }
+        },
+        _ => return None,
+    };
+    Some(MetadataRecord { signature: signature.to_string(), ..record })
+}
+
+/// Keeps the first entry of each creator address, in order.
+fn dedup_creators(creators: &[Creator]) -> Vec<Creator> {
+    let mut deduped: Vec<Creator> = Vec::with_capacity(creators.len());
+    for creator in creators {
+        if !deduped.iter().any(|other| other.address == creator.address) {
+            deduped.push(creator.clone());
+        }
+    }
+    deduped
+}
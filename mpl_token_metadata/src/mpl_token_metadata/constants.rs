@@ -2,3 +2,6 @@ use substreams_solana_utils::pubkey::Pubkey;
 use substreams_solana::b58;
 
 pub const MPL_TOKEN_METADATA_PROGRAM_ID: Pubkey = Pubkey(b58!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"));
+
+/// The program id under the name used by the upstream program crate.
+pub const ID: Pubkey = MPL_TOKEN_METADATA_PROGRAM_ID;
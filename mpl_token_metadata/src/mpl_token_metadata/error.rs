@@ -1,6 +1,5 @@
 //! Error types
 
-use num_derive::FromPrimitive;
 // use solana_program::{
 //     decode_error::DecodeError,
 //     msg,
@@ -8,6 +7,8 @@ use num_derive::FromPrimitive;
 // };
 use thiserror::Error;
 
+use super::state::Key;
+
 /// Reasons the program may fail
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum ProgramError {
@@ -58,10 +59,16 @@ pub enum ProgramError {
     MaxInstructionTraceLengthExceeded,
     #[error("Builtin programs must consume compute units")]
     BuiltinProgramsMustConsumeComputeUnits,
+    /// A token-metadata error, see `MetadataError`.
+    #[error(transparent)]
+    Metadata(MetadataError),
+    /// A `MigrationType` index past the known variants.
+    #[error("Unknown migration type {0}")]
+    UnknownMigrationType(u8),
 }
 
 /// Errors that may be returned by the Metadata program.
-#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum MetadataError {
     /// 0 Failed to unpack instruction data
     #[error("")]
@@ -315,9 +322,9 @@ pub enum MetadataError {
     #[error("Invalid token program")]
     InvalidTokenProgram,
 
-    /// Data type mismatch
-    #[error("Data type mismatch")]
-    DataTypeMismatch,
+    /// Data type mismatch: the account holds another account type than the one being decoded.
+    #[error("Data type mismatch: expected {expected_key:?}, found {found_key:?}")]
+    DataTypeMismatch { expected_key: Key, found_key: Key },
 
     /// Beyond alotted address size in reservation!
     #[error("")]
@@ -835,4 +842,19 @@ pub enum MetadataError {
     /// 198
     #[error("Missing immutable owner extension")]
     MissingImmutableOwnerExtension,
+
+    /// The account starts with a key byte this copy of the program doesn't know, typically an
+    /// account type added after it was vendored.
+    #[error("Unknown account key {0}")]
+    UnknownKey(u8),
+
+    /// The account has the expected key but not a size this account type can have.
+    #[error("Size mismatch: expected {expected} bytes, found {found}")]
+    SizeMismatch { expected: usize, found: usize },
+}
+
+impl From<MetadataError> for ProgramError {
+    fn from(e: MetadataError) -> Self {
+        ProgramError::Metadata(e)
+    }
 }
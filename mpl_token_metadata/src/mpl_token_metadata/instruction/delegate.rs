@@ -106,6 +106,19 @@ pub enum MetadataDelegateRole {
     ProgrammableConfigItem,
 }
 
+impl MetadataDelegateRole {
+    pub const ALL: [MetadataDelegateRole; 8] = [
+        Self::AuthorityItem,
+        Self::Collection,
+        Self::Use,
+        Self::Data,
+        Self::ProgrammableConfig,
+        Self::DataItem,
+        Self::CollectionItem,
+        Self::ProgrammableConfigItem,
+    ];
+}
+
 impl fmt::Display for MetadataDelegateRole {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let message = match self {
@@ -128,6 +141,10 @@ pub enum HolderDelegateRole {
     PrintDelegate,
 }
 
+impl HolderDelegateRole {
+    pub const ALL: [HolderDelegateRole; 1] = [Self::PrintDelegate];
+}
+
 impl fmt::Display for HolderDelegateRole {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let message = match self {
@@ -1,9 +1,12 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde-feature")]
+use serde::{Deserialize, Serialize};
 use substreams_solana_utils::pubkey::Pubkey;
 use super::super::state::{
-    AssetData, Collection, CollectionDetails, Data, DataV2, PrintSupply,
+    AssetData, Collection, CollectionDetails, Data, DataV2, MigrationType, PrintSupply,
     TokenStandard, Uses,
 };
+use super::super::error::ProgramError;
 use super::super::processor::AuthorizationData;
 
 #[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
@@ -395,3 +398,31 @@ pub enum PrintArgs {
     V1 { edition: u64 },
     V2 { edition: u64 },
 }
+
+/// Args of `Migrate` as sent while migrations were enabled. The instruction has since been
+/// reduced to its discriminator, so current transactions carry none.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MigrateArgs {
+    V1 {
+        migration_type: MigrationType,
+        rule_set: Option<Pubkey>,
+    },
+}
+
+impl MigrateArgs {
+    /// Decodes the data following the `Migrate` discriminator, `None` when it is empty. An
+    /// unknown migration type fails with `UnknownMigrationType`.
+    pub fn from_bytes(data: &[u8]) -> Result<Option<MigrateArgs>, ProgramError> {
+        let Some((version, rest)) = data.split_first() else {
+            return Ok(None);
+        };
+        if *version != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let (migration_type, mut rest) = rest.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+        let migration_type = MigrationType::try_from_u8(*migration_type)?;
+        let rule_set: Option<Pubkey> = BorshDeserialize::deserialize(&mut rest)
+            .map_err(|e| ProgramError::BorshIoError(e.to_string()))?;
+        Ok(Some(MigrateArgs::V1 { migration_type, rule_set }))
+    }
+}
@@ -2,5 +2,6 @@ pub mod constants;
 pub mod instruction;
 pub mod processor;
 pub mod error;
+pub mod pda;
 pub mod state;
 pub mod utils;
@@ -0,0 +1,142 @@
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+use substreams_solana_utils::pubkey::Pubkey;
+
+use super::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
+use super::instruction::MetadataDelegateRole;
+
+pub const PREFIX: &str = "metadata";
+pub const EDITION: &str = "edition";
+pub const RESERVATION: &str = "reservation";
+pub const USER: &str = "user";
+pub const BURN: &str = "burn";
+pub const COLLECTION_AUTHORITY: &str = "collection_authority";
+pub const TOKEN_RECORD_SEED: &str = "token_record";
+pub const MARKER: &str = "marker";
+
+const MAX_SEEDS: usize = 16;
+const MAX_SEED_LEN: usize = 32;
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+/// Derives the address of `seeds` for `program_id`, `None` when the seeds are invalid or the
+/// resulting hash is a valid ed25519 point, i.e. could have a private key.
+pub fn create_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Option<Pubkey> {
+    if seeds.len() > MAX_SEEDS || seeds.iter().any(|seed| seed.len() > MAX_SEED_LEN) {
+        return None;
+    }
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed);
+    }
+    hasher.update(program_id.0);
+    hasher.update(PDA_MARKER);
+    let hash: [u8; 32] = hasher.finalize().into();
+
+    if CompressedEdwardsY(hash).decompress().is_some() {
+        return None;
+    }
+    Some(Pubkey(hash))
+}
+
+/// Finds the canonical address of `seeds` for `program_id`, trying bump seeds from 255 down.
+/// Pure Rust, so unlike `solana_program` it doesn't rely on the `sol_try_find_program_address`
+/// syscall and runs in the substreams wasm runtime.
+pub fn try_find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Option<(Pubkey, u8)> {
+    if seeds.len() >= MAX_SEEDS {
+        return None;
+    }
+    let mut seeds_with_bump = seeds.to_vec();
+    (0..=u8::MAX).rev().find_map(|bump| {
+        let bump_seed = [bump];
+        seeds_with_bump.push(&bump_seed);
+        let address = create_program_address(&seeds_with_bump, program_id);
+        seeds_with_bump.pop();
+        address.map(|address| (address, bump))
+    })
+}
+
+/// Like `try_find_program_address`, panicking when no bump seed gives an off-curve address,
+/// which for valid seeds doesn't happen in practice.
+pub fn find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> (Pubkey, u8) {
+    try_find_program_address(seeds, program_id).expect("Unable to find a viable program address bump seed")
+}
+
+fn find_token_metadata_address(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    find_program_address(seeds, &MPL_TOKEN_METADATA_PROGRAM_ID)
+}
+
+pub fn find_metadata_account(mint: &Pubkey) -> (Pubkey, u8) {
+    find_token_metadata_address(&[PREFIX.as_bytes(), &MPL_TOKEN_METADATA_PROGRAM_ID.0, &mint.0])
+}
+
+pub fn find_master_edition_account(mint: &Pubkey) -> (Pubkey, u8) {
+    find_token_metadata_address(&[
+        PREFIX.as_bytes(),
+        &MPL_TOKEN_METADATA_PROGRAM_ID.0,
+        &mint.0,
+        EDITION.as_bytes(),
+    ])
+}
+
+/// The edition marker holding the bit of `edition` for prints of `mint`.
+pub fn find_edition_marker_account(mint: &Pubkey, edition: u64) -> (Pubkey, u8) {
+    let marker_number = (edition / super::state::EDITION_MARKER_BIT_SIZE).to_string();
+    find_token_metadata_address(&[
+        PREFIX.as_bytes(),
+        &MPL_TOKEN_METADATA_PROGRAM_ID.0,
+        &mint.0,
+        EDITION.as_bytes(),
+        marker_number.as_bytes(),
+    ])
+}
+
+pub fn find_token_record_account(mint: &Pubkey, token: &Pubkey) -> (Pubkey, u8) {
+    find_token_metadata_address(&[
+        PREFIX.as_bytes(),
+        &MPL_TOKEN_METADATA_PROGRAM_ID.0,
+        &mint.0,
+        TOKEN_RECORD_SEED.as_bytes(),
+        &token.0,
+    ])
+}
+
+pub fn find_metadata_delegate_record_account(
+    mint: &Pubkey,
+    role: MetadataDelegateRole,
+    update_authority: &Pubkey,
+    delegate: &Pubkey,
+) -> (Pubkey, u8) {
+    let role = role.to_string();
+    find_token_metadata_address(&[
+        PREFIX.as_bytes(),
+        &MPL_TOKEN_METADATA_PROGRAM_ID.0,
+        &mint.0,
+        role.as_bytes(),
+        &update_authority.0,
+        &delegate.0,
+    ])
+}
+
+pub fn find_collection_authority_account(mint: &Pubkey, collection_authority: &Pubkey) -> (Pubkey, u8) {
+    find_token_metadata_address(&[
+        PREFIX.as_bytes(),
+        &MPL_TOKEN_METADATA_PROGRAM_ID.0,
+        &mint.0,
+        COLLECTION_AUTHORITY.as_bytes(),
+        &collection_authority.0,
+    ])
+}
+
+pub fn find_use_authority_account(mint: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    find_token_metadata_address(&[
+        PREFIX.as_bytes(),
+        &MPL_TOKEN_METADATA_PROGRAM_ID.0,
+        &mint.0,
+        USER.as_bytes(),
+        &authority.0,
+    ])
+}
+
+pub fn find_program_as_burner_account() -> (Pubkey, u8) {
+    find_token_metadata_address(&[PREFIX.as_bytes(), &MPL_TOKEN_METADATA_PROGRAM_ID.0, BURN.as_bytes()])
+}
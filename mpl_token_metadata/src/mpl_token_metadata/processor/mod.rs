@@ -25,8 +25,15 @@ use borsh::BorshDeserialize;
 
 use substreams_solana_utils::pubkey::Pubkey;
 use std::collections::HashMap;
+#[cfg(feature = "serde-feature")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-feature")]
+use serde_with::As;
+#[cfg(feature = "serde-feature")]
+use super::state::Base58;
 
 #[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// A seed path type used by the `DerivedKeyMatch` rule.
 pub struct SeedsVec {
@@ -42,6 +49,7 @@ impl SeedsVec {
 }
 
 #[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// A proof type used by the `PubkeyTreeMatch` rule.
 pub struct ProofInfo {
@@ -57,11 +65,12 @@ impl ProofInfo {
 }
 
 #[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// Variants representing the different types represented in a payload.
 pub enum PayloadType {
     /// A plain `Pubkey`.
-    Pubkey(Pubkey),
+    Pubkey(#[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))] Pubkey),
     /// PDA derivation seeds.
     Seeds(SeedsVec),
     /// A merkle proof.
@@ -70,6 +79,7 @@ pub enum PayloadType {
     Number(u64),
 }
 
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(
     BorshDeserialize, PartialEq, Eq, Debug, Clone, Default,
 )]
@@ -80,6 +90,7 @@ pub struct Payload {
 
 
 
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct AuthorizationData {
     pub payload: Payload,
@@ -103,4 +114,14 @@ impl Payload {
             map: HashMap::new(),
         }
     }
+
+    /// Get a reference to the value corresponding to the key.
+    pub fn get(&self, key: &str) -> Option<&PayloadType> {
+        self.map.get(key)
+    }
+
+    /// Get a reference to the hashmap of the payload.
+    pub fn inner(&self) -> &HashMap<String, PayloadType> {
+        &self.map
+    }
 }
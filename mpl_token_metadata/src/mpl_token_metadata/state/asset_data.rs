@@ -6,7 +6,7 @@ use super::*;
 /// Data representation of an asset.
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct AssetData {
     /// The name of the asset.
     pub name: String,
@@ -80,4 +80,91 @@ impl AssetData {
             creators: self.creators.clone(),
         }
     }
+
+    /// Checks the limits the program enforces on creation: field lengths, royalty basis points,
+    /// and a creators list of 1 to `MAX_CREATOR_LIMIT` distinct addresses whose shares sum to 100.
+    /// Signing and verification of creators aren't checked, they depend on the accounts.
+    pub fn validate(&self) -> Result<(), MetadataError> {
+        if self.name.len() > MAX_NAME_LENGTH {
+            return Err(MetadataError::NameTooLong);
+        }
+        if self.symbol.len() > MAX_SYMBOL_LENGTH {
+            return Err(MetadataError::SymbolTooLong);
+        }
+        if self.uri.len() > MAX_URI_LENGTH {
+            return Err(MetadataError::UriTooLong);
+        }
+        if self.seller_fee_basis_points > 10000 {
+            return Err(MetadataError::InvalidBasisPoints);
+        }
+        if let Some(creators) = &self.creators {
+            validate_creators(creators)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn creator(n: u8, share: u8) -> Creator {
+        Creator { address: Pubkey([n; 32]), verified: false, share }
+    }
+
+    /// Data at every limit, which passes validation.
+    fn asset_data() -> AssetData {
+        AssetData {
+            seller_fee_basis_points: 10000,
+            creators: Some(vec![creator(1, 60), creator(2, 40)]),
+            ..AssetData::new(
+                TokenStandard::NonFungible,
+                "n".repeat(MAX_NAME_LENGTH),
+                "s".repeat(MAX_SYMBOL_LENGTH),
+                "u".repeat(MAX_URI_LENGTH),
+            )
+        }
+    }
+
+    #[test]
+    fn valid_at_the_limits() {
+        assert_eq!(asset_data().validate(), Ok(()));
+    }
+
+    #[test]
+    fn name_too_long() {
+        let data = AssetData { name: "n".repeat(MAX_NAME_LENGTH + 1), ..asset_data() };
+        assert_eq!(data.validate(), Err(MetadataError::NameTooLong));
+    }
+
+    #[test]
+    fn symbol_too_long() {
+        let data = AssetData { symbol: "s".repeat(MAX_SYMBOL_LENGTH + 1), ..asset_data() };
+        assert_eq!(data.validate(), Err(MetadataError::SymbolTooLong));
+    }
+
+    #[test]
+    fn uri_too_long() {
+        let data = AssetData { uri: "u".repeat(MAX_URI_LENGTH + 1), ..asset_data() };
+        assert_eq!(data.validate(), Err(MetadataError::UriTooLong));
+    }
+
+    #[test]
+    fn basis_points_over_10000() {
+        let data = AssetData { seller_fee_basis_points: 10001, ..asset_data() };
+        assert_eq!(data.validate(), Err(MetadataError::InvalidBasisPoints));
+    }
+
+    #[test]
+    fn more_than_5_creators() {
+        let creators = (1..=MAX_CREATOR_LIMIT as u8 + 1).map(|n| creator(n, if n == 1 { 100 } else { 0 })).collect();
+        let data = AssetData { creators: Some(creators), ..asset_data() };
+        assert_eq!(data.validate(), Err(MetadataError::CreatorsTooLong));
+    }
+
+    #[test]
+    fn shares_not_summing_to_100() {
+        let data = AssetData { creators: Some(vec![creator(1, 60), creator(2, 30)]), ..asset_data() };
+        assert_eq!(data.validate(), Err(MetadataError::ShareTotalMustBe100));
+    }
 }
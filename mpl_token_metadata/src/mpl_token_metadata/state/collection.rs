@@ -1,24 +1,32 @@
 use super::*;
-use super::super::utils::try_from_slice_checked;
 use substreams_solana_utils::pubkey::Pubkey;
 
 pub const COLLECTION_AUTHORITY_RECORD_SIZE: usize = 35;
+/// Size of records created before `update_authority` was added: key, bump and 9 bytes of padding.
+pub const COLLECTION_AUTHORITY_RECORD_LEGACY_SIZE: usize = 11;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct Collection {
     pub verified: bool,
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub key: Pubkey,
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct CollectionAuthorityRecord {
     pub key: Key,                         //1
     pub bump: u8,                         //1
+    #[cfg_attr(
+        feature = "serde-feature",
+        serde(
+            deserialize_with = "deser_option_pubkey",
+            serialize_with = "ser_option_pubkey"
+        )
+    )]
     pub update_authority: Option<Pubkey>, //33 (1 + 32)
 }
 
@@ -40,22 +48,38 @@ impl TokenMetadataAccount for CollectionAuthorityRecord {
     fn size() -> usize {
         COLLECTION_AUTHORITY_RECORD_SIZE
     }
+
+    /// Records exist with both the legacy and the current size, so `data_size` is ignored.
+    fn is_correct_account_type(data: &[u8], data_type: Key, _data_size: usize) -> bool {
+        let key = match data.first().and_then(|key| Key::from_u8(*key)) {
+            Some(key) => key,
+            None => return false,
+        };
+        (key == data_type || key == Key::Uninitialized)
+            && (data.len() == COLLECTION_AUTHORITY_RECORD_SIZE
+                || data.len() == COLLECTION_AUTHORITY_RECORD_LEGACY_SIZE)
+    }
 }
 
 impl CollectionAuthorityRecord {
+    /// Decodes either layout. Legacy records have no `update_authority`; their padding is not
+    /// read, since it was never guaranteed to be zeroed.
     pub fn from_bytes(b: &[u8]) -> Result<CollectionAuthorityRecord, ProgramError> {
-        let ca: CollectionAuthorityRecord = try_from_slice_checked(
-            b,
-            Key::CollectionAuthorityRecord,
-            COLLECTION_AUTHORITY_RECORD_SIZE,
-        )?;
-        Ok(ca)
+        if b.len() == COLLECTION_AUTHORITY_RECORD_LEGACY_SIZE {
+            Self::check_account_type(b, Self::key(), Self::size())?;
+            return Ok(CollectionAuthorityRecord {
+                key: Key::try_from_u8(b[0])?,
+                bump: b[1],
+                update_authority: None,
+            });
+        }
+        CollectionAuthorityRecord::safe_deserialize(b)
     }
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum CollectionDetails {
     #[deprecated(
         since = "1.13.1",
@@ -68,3 +92,15 @@ pub enum CollectionDetails {
         padding: [u8; 8],
     },
 }
+
+impl CollectionDetails {
+    /// The tracked collection size. `None` for `V2`, which replaced size tracking and keeps the
+    /// 8 bytes as padding.
+    #[allow(deprecated)]
+    pub fn size(&self) -> Option<u64> {
+        match self {
+            CollectionDetails::V1 { size } => Some(*size),
+            CollectionDetails::V2 { .. } => None,
+        }
+    }
+}
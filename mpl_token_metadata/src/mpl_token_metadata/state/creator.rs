@@ -8,11 +8,29 @@ pub const MAX_CREATOR_LEN: usize = 32 + 1 + 1;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Debug, Clone, Eq, Hash)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Eq, Hash)]
 pub struct Creator {
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub address: Pubkey,
     pub verified: bool,
     // In percentages, NOT basis points ;) Watch out!
     pub share: u8,
 }
+
+/// Checks the creators rules enforced on-chain: between one and `MAX_CREATOR_LIMIT` creators,
+/// no address listed twice, and shares adding up to 100.
+pub fn validate_creators(creators: &[Creator]) -> Result<(), MetadataError> {
+    if creators.len() > MAX_CREATOR_LIMIT {
+        return Err(MetadataError::CreatorsTooLong);
+    }
+    if creators.is_empty() {
+        return Err(MetadataError::CreatorsMustBeAtleastOne);
+    }
+    if creators.iter().enumerate().any(|(i, creator)| creators[..i].iter().any(|other| other.address == creator.address)) {
+        return Err(MetadataError::DuplicateCreatorAddress);
+    }
+    if creators.iter().map(|creator| creator.share as u16).sum::<u16>() != 100 {
+        return Err(MetadataError::ShareTotalMustBe100);
+    }
+    Ok(())
+}
@@ -2,7 +2,7 @@ use super::*;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, Default, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Default, PartialEq, Eq, Debug, Clone)]
 pub struct Data {
     /// The name of the asset
     pub name: String,
@@ -16,9 +16,22 @@ pub struct Data {
     pub creators: Option<Vec<Creator>>,
 }
 
+impl Data {
+    /// A copy with the NUL padding that puffs name, symbol and uri to their maximum length
+    /// on-chain removed.
+    pub fn sanitized(&self) -> Data {
+        Data {
+            name: strip_padding(&self.name),
+            symbol: strip_padding(&self.symbol),
+            uri: strip_padding(&self.uri),
+            ..self.clone()
+        }
+    }
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct DataV2 {
     /// The name of the asset
     pub name: String,
@@ -47,4 +60,20 @@ impl DataV2 {
             creators: ns.creators,
         }
     }
+
+    /// Same as `Data::sanitized`.
+    pub fn sanitized(&self) -> DataV2 {
+        DataV2 {
+            name: strip_padding(&self.name),
+            symbol: strip_padding(&self.symbol),
+            uri: strip_padding(&self.uri),
+            ..self.clone()
+        }
+    }
+}
+
+/// Strips trailing `\0` characters only. NUL never occurs inside a multi-byte UTF-8 sequence, so
+/// a character ending exactly at the field capacity is kept whole.
+pub(crate) fn strip_padding(value: &str) -> String {
+    value.trim_end_matches('\0').to_string()
 }
@@ -1,10 +1,13 @@
 use super::*;
 use substreams_solana_utils::pubkey::Pubkey;
-use super::super::utils::try_from_slice_checked;
+use super::super::instruction::{HolderDelegateRole, MetadataDelegateRole};
+use super::super::pda::create_program_address;
+use super::super::utils::try_from_slice_checked_resizable;
 
 const SIZE: usize = 98;
 
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// SEEDS = [
 ///     "metadata",
 ///     program id,
@@ -16,11 +19,11 @@ const SIZE: usize = 98;
 pub struct MetadataDelegateRecord {
     pub key: Key, // 1
     pub bump: u8, // 1
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub mint: Pubkey, // 32
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub delegate: Pubkey, // 32
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub update_authority: Pubkey, // 32
 }
 
@@ -47,16 +50,34 @@ impl TokenMetadataAccount for MetadataDelegateRecord {
 }
 
 impl MetadataDelegateRecord {
+    /// Records reallocated larger than `SIZE` are accepted, their trailing bytes ignored.
     pub fn from_bytes(data: &[u8]) -> Result<MetadataDelegateRecord, ProgramError> {
-        let delegate: MetadataDelegateRecord =
-            try_from_slice_checked(data, Key::MetadataDelegate, MetadataDelegateRecord::size())?;
-        Ok(delegate)
+        try_from_slice_checked_resizable(data, Key::MetadataDelegate, SIZE)
+    }
+
+    /// The role of the delegate, which only appears in the record's seeds: the role whose
+    /// address, derived with the stored bump, is `delegate_record_address`. `None` when no role
+    /// matches, e.g. for an account that isn't the record it claims to be.
+    pub fn resolve_role(&self, program_id: &Pubkey, delegate_record_address: &Pubkey) -> Option<MetadataDelegateRole> {
+        MetadataDelegateRole::ALL.into_iter().find(|role| {
+            let role_seed = role.to_string();
+            let seeds: [&[u8]; 7] = [
+                PREFIX.as_bytes(),
+                &program_id.0,
+                &self.mint.0,
+                role_seed.as_bytes(),
+                &self.update_authority.0,
+                &self.delegate.0,
+                &[self.bump],
+            ];
+            create_program_address(&seeds, program_id).as_ref() == Some(delegate_record_address)
+        })
     }
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// SEEDS = [
 ///     "metadata",
 ///     program id,
@@ -68,11 +89,11 @@ impl MetadataDelegateRecord {
 pub struct HolderDelegateRecord {
     pub key: Key, // 1
     pub bump: u8, // 1
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub mint: Pubkey, // 32
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub delegate: Pubkey, // 32
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub update_authority: Pubkey, // 32
 }
 
@@ -100,8 +121,23 @@ impl TokenMetadataAccount for HolderDelegateRecord {
 
 impl HolderDelegateRecord {
     pub fn from_bytes(data: &[u8]) -> Result<HolderDelegateRecord, ProgramError> {
-        let delegate: HolderDelegateRecord =
-            try_from_slice_checked(data, Key::HolderDelegate, HolderDelegateRecord::size())?;
-        Ok(delegate)
+        try_from_slice_checked_resizable(data, Key::HolderDelegate, SIZE)
+    }
+
+    /// Same as `MetadataDelegateRecord::resolve_role`. The holder is stored in `update_authority`.
+    pub fn resolve_role(&self, program_id: &Pubkey, delegate_record_address: &Pubkey) -> Option<HolderDelegateRole> {
+        HolderDelegateRole::ALL.into_iter().find(|role| {
+            let role_seed = role.to_string();
+            let seeds: [&[u8]; 7] = [
+                PREFIX.as_bytes(),
+                &program_id.0,
+                &self.mint.0,
+                role_seed.as_bytes(),
+                &self.update_authority.0,
+                &self.delegate.0,
+                &[self.bump],
+            ];
+            create_program_address(&seeds, program_id).as_ref() == Some(delegate_record_address)
+        })
     }
 }
@@ -1,5 +1,6 @@
 use super::*;
 use substreams_solana_utils::pubkey::Pubkey;
+use super::super::utils::try_from_slice_checked;
 
 pub const MAX_EDITION_LEN: usize = 1 + 32 + 8 + 200;
 
@@ -10,7 +11,7 @@ pub const TOKEN_STANDARD_INDEX_EDITION: usize = MAX_EDITION_LEN - 1;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 /// All Editions should never have a supply greater than 1.
 /// To enforce this, a transfer mint authority instruction will happen when
 /// a normal token is turned into an Edition, and in order for a Metadata update authority
@@ -19,7 +20,7 @@ pub struct Edition {
     pub key: Key,
 
     /// Points at MasterEdition struct
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub parent: Pubkey,
 
     /// Starting at 0 for master record, this is incremented for each edition minted.
@@ -45,3 +46,9 @@ impl TokenMetadataAccount for Edition {
         MAX_EDITION_LEN
     }
 }
+
+impl Edition {
+    pub fn from_bytes(data: &[u8]) -> Result<Edition, ProgramError> {
+        try_from_slice_checked(data, Key::EditionV1, MAX_EDITION_LEN)
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use super::super::utils::try_from_slice_checked;
 
 pub const MAX_EDITION_MARKER_SIZE: usize = 32;
 
@@ -32,56 +33,33 @@ impl TokenMetadataAccount for EditionMarker {
 }
 
 impl EditionMarker {
-    // fn get_edition_offset_from_starting_index(edition: u64) -> Result<usize, ProgramError> {
-    //     Ok(edition
-    //         .checked_rem(EDITION_MARKER_BIT_SIZE)
-    //         .ok_or(MetadataError::NumericalOverflowError)? as usize)
-    // }
-
-    // fn get_index(offset_from_start: usize) -> Result<usize, ProgramError> {
-    //     let index = offset_from_start
-    //         .checked_div(8)
-    //         .ok_or(MetadataError::NumericalOverflowError)?;
-
-    //     // With only EDITION_MARKER_BIT_SIZE bits, or 31 bytes, we have a max constraint here.
-    //     if index > 30 {
-    //         return Err(MetadataError::InvalidEditionIndex.into());
-    //     }
-
-    //     Ok(index)
-    // }
-
-    // fn get_offset_from_right(offset_from_start: usize) -> Result<u32, ProgramError> {
-    //     // We're saying the left hand side of a u8 is the 0th index so to get a 1 in that 0th index
-    //     // you need to shift a 1 over 8 spots from the right hand side. To do that you actually
-    //     // need not 00000001 but 10000000 which you can get by simply multiplying 1 by 2^7, 128 and then ORing
-    //     // it with the current value.
-    //     Ok(7 - offset_from_start
-    //         .checked_rem(8)
-    //         .ok_or(MetadataError::NumericalOverflowError)? as u32)
-    // }
-
-    // pub fn get_index_and_mask(edition: u64) -> Result<(usize, u8), ProgramError> {
-    //     // How many editions off we are from edition at 0th index
-    //     let offset_from_start = EditionMarker::get_edition_offset_from_starting_index(edition)?;
-
-    //     // How many whole u8s we are from the u8 at the 0th index, which basically dividing by 8
-    //     let index = EditionMarker::get_index(offset_from_start)?;
-
-    //     // what position in the given u8 bitset are we (remainder math)
-    //     let my_position_in_index_starting_from_right =
-    //         EditionMarker::get_offset_from_right(offset_from_start)?;
+    pub fn from_bytes(data: &[u8]) -> Result<EditionMarker, ProgramError> {
+        try_from_slice_checked(data, Key::EditionMarker, MAX_EDITION_MARKER_SIZE)
+    }
 
-    //     Ok((index, u8::pow(2, my_position_in_index_starting_from_right)))
-    // }
+    /// Number of the marker recording `edition`, the last seed of the marker PDA:
+    /// `["metadata", program id, master mint, "edition", marker_number.to_string()]`.
+    pub fn marker_number(edition: u64) -> u64 {
+        edition / EDITION_MARKER_BIT_SIZE
+    }
 
-    // pub fn edition_taken(&self, edition: u64) -> Result<bool, ProgramError> {
-    //     let (index, mask) = EditionMarker::get_index_and_mask(edition)?;
+    /// Byte of the ledger and bit mask recording `edition`. The ledger is read left to right, so
+    /// the first edition of a marker is the most significant bit of the first byte.
+    pub fn get_index_and_mask(edition: u64) -> (usize, u8) {
+        // Below EDITION_MARKER_BIT_SIZE, so the index is at most 30.
+        let offset_from_start = (edition % EDITION_MARKER_BIT_SIZE) as usize;
+        (offset_from_start / 8, 1u8 << (7 - offset_from_start % 8))
+    }
 
-    //     // apply mask with bitwise and with a 1 to determine if it is set or not
-    //     let applied_mask = self.ledger[index] & mask;
+    /// Whether `edition` has been printed, assuming it belongs to this marker.
+    pub fn edition_taken(&self, edition: u64) -> bool {
+        let (index, mask) = EditionMarker::get_index_and_mask(edition);
+        self.ledger[index] & mask != 0
+    }
 
-    //     // What remains should not equal 0.
-    //     Ok(applied_mask != 0)
-    // }
+    /// The printed editions recorded by this marker, in increasing order.
+    pub fn taken_editions(&self, marker_number: u64) -> impl Iterator<Item = u64> + '_ {
+        let first = marker_number.saturating_mul(EDITION_MARKER_BIT_SIZE);
+        (first..first.saturating_add(EDITION_MARKER_BIT_SIZE)).filter(move |edition| self.edition_taken(*edition))
+    }
 }
@@ -1,5 +1,6 @@
 use super::*;
 
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct EditionMarkerV2 {
     pub key: Key,
@@ -26,50 +27,67 @@ impl TokenMetadataAccount for EditionMarkerV2 {
 }
 
 impl EditionMarkerV2 {
-    // fn get_index(offset_from_start: usize) -> Result<usize, ProgramError> {
-    //     let index = offset_from_start
-    //         .checked_div(8)
-    //         .ok_or(MetadataError::NumericalOverflowError)?;
+    pub fn from_bytes(data: &[u8]) -> Result<EditionMarkerV2, ProgramError> {
+        EditionMarkerV2::safe_deserialize(data)
+    }
 
-    //     Ok(index)
-    // }
+    /// Borrows the ledger of this marker. The bitmap helpers live on the borrowed form, so they
+    /// work the same on accounts decoded with `EditionMarkerV2Ref::from_bytes`.
+    pub fn as_borrowed(&self) -> EditionMarkerV2Ref<'_> {
+        EditionMarkerV2Ref { ledger: &self.ledger }
+    }
 
-    // fn get_offset_from_right(offset_from_start: usize) -> Result<u32, ProgramError> {
-    //     // We're saying the left hand side of a u8 is the 0th index so to get a 1 in that 0th index
-    //     // you need to shift a 1 over 8 spots from the right hand side. To do that you actually
-    //     // need not 00000001 but 10000000 which you can get by simply multiplying 1 by 2^7, 128 and then ORing
-    //     // it with the current value.
-    //     Ok(7 - offset_from_start
-    //         .checked_rem(8)
-    //         .ok_or(MetadataError::NumericalOverflowError)? as u32)
-    // }
+    pub fn edition_taken(&self, edition: u64) -> Result<bool, MetadataError> {
+        self.as_borrowed().edition_taken(edition)
+    }
 
-    // pub fn get_index_and_mask(edition: u64) -> Result<(usize, u8), ProgramError> {
-    //     let edition = edition
-    //         .try_into()
-    //         .map_err(|_| MetadataError::NumericalOverflowError)?;
-    //     // How many whole u8s we are from the u8 at the 0th index, which basically dividing by 8
-    //     let index = EditionMarkerV2::get_index(edition)?;
+    pub fn count_taken(&self) -> u64 {
+        self.as_borrowed().count_taken()
+    }
+}
 
-    //     // what position in the given u8 bitset are we (remainder math)
-    //     let my_position_in_index_starting_from_right =
-    //         EditionMarkerV2::get_offset_from_right(edition)?;
+/// An `EditionMarkerV2` account whose ledger is borrowed from the account data.
+///
+/// Unlike `EditionMarker`, whose ledger is 31 bytes, a single V2 marker records every edition of
+/// a master edition, so the ledger of a popular collection can be large and isn't worth copying
+/// just to read it.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct EditionMarkerV2Ref<'a> {
+    pub ledger: &'a [u8],
+}
 
-    //     Ok((index, 1u8 << my_position_in_index_starting_from_right))
-    // }
+impl<'a> EditionMarkerV2Ref<'a> {
+    /// Checks the key and reads the Borsh length prefix of the ledger, without copying it.
+    /// Bytes past the ledger are ignored, since the account is resizable.
+    pub fn from_bytes(data: &'a [u8]) -> Result<EditionMarkerV2Ref<'a>, ProgramError> {
+        EditionMarkerV2::check_account_type(data, Key::EditionMarkerV2, 0)?;
+        let length = data.get(1..5)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+        let ledger = data.get(5..)
+            .and_then(|ledger| ledger.get(..length))
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+        Ok(EditionMarkerV2Ref { ledger })
+    }
 
-    // pub fn edition_taken(&self, edition: u64) -> Result<bool, ProgramError> {
-    //     let (index, mask) = EditionMarkerV2::get_index_and_mask(edition)?;
+    /// Byte of the ledger and bit mask recording `edition`. Bits are numbered from the most
+    /// significant bit of the first byte, edition 0 included.
+    pub fn get_index_and_mask(edition: u64) -> Result<(usize, u8), MetadataError> {
+        let edition: usize = edition
+            .try_into()
+            .map_err(|_| MetadataError::NumericalOverflowError)?;
+        Ok((edition / 8, 1u8 << (7 - edition % 8)))
+    }
 
-    //     // If the ledger is smaller than the index, then it's not taken.
-    //     if self.ledger.len() <= index {
-    //         Ok(false)
-    //     } else {
-    //         // apply mask with bitwise and with a 1 to determine if it is set or not
-    //         let applied_mask = self.ledger[index] & mask;
+    /// Whether `edition` has been printed. The ledger only grows as editions are printed, so
+    /// editions past its end are not taken.
+    pub fn edition_taken(&self, edition: u64) -> Result<bool, MetadataError> {
+        let (index, mask) = EditionMarkerV2Ref::get_index_and_mask(edition)?;
+        Ok(self.ledger.get(index).map_or(false, |byte| byte & mask != 0))
+    }
 
-    //         // What remains should not equal 0.
-    //         Ok(applied_mask != 0)
-    //     }
-    // }
+    /// Number of printed editions recorded in the ledger.
+    pub fn count_taken(&self) -> u64 {
+        self.ledger.iter().map(|byte| byte.count_ones() as u64).sum()
+    }
 }
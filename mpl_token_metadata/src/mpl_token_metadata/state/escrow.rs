@@ -5,26 +5,27 @@ pub const ESCROW_POSTFIX: &str = "escrow";
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
 pub enum EscrowAuthority {
     TokenOwner,
-    Creator(Pubkey),
+    Creator(#[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))] Pubkey),
 }
 
 impl EscrowAuthority {
-    // pub fn to_seeds(&self) -> Vec<&[u8]> {
-    //     match self {
-    //         EscrowAuthority::TokenOwner => vec![&[0]],
-    //         EscrowAuthority::Creator(creator) => vec![&[1], creator.as_ref()],
-    //     }
-    // }
+    pub fn to_seeds(&self) -> Vec<&[u8]> {
+        match self {
+            EscrowAuthority::TokenOwner => vec![&[0]],
+            EscrowAuthority::Creator(creator) => vec![&[1], &creator.0],
+        }
+    }
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct TokenOwnedEscrow {
     pub key: Key,
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub base_token: Pubkey,
     pub authority: EscrowAuthority,
     pub bump: u8,
@@ -39,11 +40,34 @@ impl TokenMetadataAccount for TokenOwnedEscrow {
         0
     }
 
+    /// The account size depends on the authority (1 byte for `TokenOwner`, 33 for `Creator`), so
+    /// only the key is checked.
     fn is_correct_account_type(data: &[u8], data_type: Key, _data_size: usize) -> bool {
-        let key: Option<Key> = Key::from_u8(data[0]);
+        let key: Option<Key> = data.first().and_then(|key| Key::from_u8(*key));
         match key {
             Some(key) => key == data_type || key == Key::Uninitialized,
             None => false,
         }
     }
 }
+
+impl TokenOwnedEscrow {
+    /// Decodes an escrow account. Data with another account key fails with `DataTypeMismatch`,
+    /// data too short for the authority it declares with `SizeMismatch`.
+    pub fn from_bytes(b: &[u8]) -> Result<TokenOwnedEscrow, ProgramError> {
+        let expected = TokenOwnedEscrow::declared_size(b);
+        TokenOwnedEscrow::safe_deserialize(b).map_err(|e| match e {
+            ProgramError::BorshIoError(_) if b.len() < expected => MetadataError::SizeMismatch { expected, found: b.len() }.into(),
+            e => e,
+        })
+    }
+
+    /// Size of the account for the authority variant tagged after `base_token`: key, base
+    /// token, authority (1 byte, or 33 for `Creator`) and bump.
+    fn declared_size(data: &[u8]) -> usize {
+        match data.get(33) {
+            Some(1) => 1 + 32 + 33 + 1,
+            _ => 1 + 32 + 1 + 1,
+        }
+    }
+}
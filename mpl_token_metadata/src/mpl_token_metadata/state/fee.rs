@@ -1,5 +1,20 @@
+use super::MAX_METADATA_LEN;
+
 // create_metadata_accounts_v3, create, print edition commands
 pub const CREATE_FEE: u64 = 10_000_000;
 
 pub const FEE_FLAG_SET: u8 = 1;
 pub const FEE_FLAG_CLEARED: u8 = 0;
+
+/// Offset of the fee flag in a metadata account: its last byte once padded to `MAX_METADATA_LEN`.
+pub const METADATA_FEE_FLAG_OFFSET: usize = MAX_METADATA_LEN - 1;
+
+/// Whether the metadata account still holds fees the protocol hasn't collected. The flag is the
+/// last byte of the account, so resized accounts are read at their end. Accounts shorter than
+/// `MAX_METADATA_LEN` predate the flag and never have it set.
+pub fn has_fee_flag(account_data: &[u8]) -> bool {
+    if account_data.len() <= METADATA_FEE_FLAG_OFFSET {
+        return false;
+    }
+    account_data.last() == Some(&FEE_FLAG_SET)
+}
@@ -1,5 +1,6 @@
 use super::*;
 use substreams_solana_utils::pubkey::Pubkey;
+use super::super::utils::try_from_slice_checked_resizable;
 
 // Large buffer because the older master editions have two pubkeys in them,
 // need to keep two versions same size because the conversion process actually
@@ -22,7 +23,8 @@ pub trait MasterEdition {
     fn max_supply(&self) -> Option<u64>;
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct MasterEditionV2 {
     pub key: Key,
 
@@ -51,6 +53,12 @@ impl TokenMetadataAccount for MasterEditionV2 {
     }
 }
 
+impl MasterEditionV2 {
+    pub fn from_bytes(data: &[u8]) -> Result<MasterEditionV2, ProgramError> {
+        try_from_slice_checked_resizable(data, Key::MasterEditionV2, MAX_MASTER_EDITION_LEN)
+    }
+}
+
 impl MasterEdition for MasterEditionV2 {
     fn key(&self) -> Key {
         self.key
@@ -69,7 +77,8 @@ impl MasterEdition for MasterEditionV2 {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct MasterEditionV1 {
     pub key: Key,
 
@@ -78,6 +87,7 @@ pub struct MasterEditionV1 {
     pub max_supply: Option<u64>,
 
     /// Can be used to mint tokens that give one-time permission to mint a single limited edition.
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub printing_mint: Pubkey,
 
     /// If you don't know how many printing tokens you are going to need, but you do know
@@ -90,6 +100,7 @@ pub struct MasterEditionV1 {
     /// but at the end we will. At the end it then burns this token with token-metadata to
     /// get the printing tokens it needs to give to bidders. Each bidder then redeems a printing token
     /// to get their limited editions.
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub one_time_printing_authorization_mint: Pubkey,
 }
 
@@ -103,6 +114,12 @@ impl TokenMetadataAccount for MasterEditionV1 {
     }
 }
 
+impl MasterEditionV1 {
+    pub fn from_bytes(data: &[u8]) -> Result<MasterEditionV1, ProgramError> {
+        try_from_slice_checked_resizable(data, Key::MasterEditionV1, MAX_MASTER_EDITION_LEN)
+    }
+}
+
 impl MasterEdition for MasterEditionV1 {
     fn key(&self) -> Key {
         self.key
@@ -120,3 +137,53 @@ impl MasterEdition for MasterEditionV1 {
         self.supply = supply;
     }
 }
+
+/// A master edition account of either version, as found on-chain.
+///
+/// Named apart from the `MasterEdition` trait, which it implements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MasterEditionAccount {
+    V1(MasterEditionV1),
+    V2(MasterEditionV2),
+}
+
+impl MasterEditionAccount {
+    /// Decodes `data` as whichever version its key byte says it is.
+    pub fn from_bytes(data: &[u8]) -> Result<MasterEditionAccount, ProgramError> {
+        match data.first().map(|key| Key::try_from_u8(*key)).transpose()? {
+            Some(Key::MasterEditionV1) => MasterEditionV1::from_bytes(data).map(MasterEditionAccount::V1),
+            Some(Key::MasterEditionV2) => MasterEditionV2::from_bytes(data).map(MasterEditionAccount::V2),
+            _ => Err(account_type_error(data, Key::MasterEditionV2, MAX_MASTER_EDITION_LEN)),
+        }
+    }
+}
+
+impl MasterEdition for MasterEditionAccount {
+    fn key(&self) -> Key {
+        match self {
+            MasterEditionAccount::V1(master_edition) => master_edition.key,
+            MasterEditionAccount::V2(master_edition) => master_edition.key,
+        }
+    }
+
+    fn supply(&self) -> u64 {
+        match self {
+            MasterEditionAccount::V1(master_edition) => master_edition.supply,
+            MasterEditionAccount::V2(master_edition) => master_edition.supply,
+        }
+    }
+
+    fn set_supply(&mut self, supply: u64) {
+        match self {
+            MasterEditionAccount::V1(master_edition) => master_edition.supply = supply,
+            MasterEditionAccount::V2(master_edition) => master_edition.supply = supply,
+        }
+    }
+
+    fn max_supply(&self) -> Option<u64> {
+        match self {
+            MasterEditionAccount::V1(master_edition) => master_edition.max_supply,
+            MasterEditionAccount::V2(master_edition) => master_edition.max_supply,
+        }
+    }
+}
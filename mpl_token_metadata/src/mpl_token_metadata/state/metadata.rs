@@ -1,4 +1,6 @@
 use super::*;
+use super::super::utils::meta_deser_unchecked;
+use borsh::io::Error as BorshError;
 
 pub const MAX_NAME_LENGTH: usize = 32;
 
@@ -49,15 +51,16 @@ macro_rules! metadata_seeds {
     }};
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, BorshSerialize, Debug, PartialEq, Eq)]
 pub struct Metadata {
     /// Account discriminator.
     pub key: Key,
     /// Address of the update authority.
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub update_authority: Pubkey,
     /// Address of the mint.
-    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
     pub mint: Pubkey,
     /// Asset data.
     pub data: Data,
@@ -80,6 +83,53 @@ pub struct Metadata {
 }
 
 impl Metadata {
+    /// Decodes a metadata account as stored on-chain.
+    ///
+    /// Metadata accounts have been resized over time and often end with zero padding or stale
+    /// bytes, so the account size isn't checked. Fields added after `edition_nonce` are `None`
+    /// when the account predates them or they don't decode, and an undecodable creators vector
+    /// is `None` as well, see `meta_deser_unchecked`.
+    pub fn from_bytes(data: &[u8]) -> Result<Metadata, ProgramError> {
+        if let Some(key) = data.first() {
+            Key::try_from_u8(*key)?;
+        }
+        if !Metadata::is_correct_account_type(data, Key::MetadataV1, 0) {
+            return Err(account_type_error(data, Key::MetadataV1, MAX_METADATA_LEN));
+        }
+        meta_deser_unchecked(&mut &data[..]).map_err(|e| ProgramError::BorshIoError(e.to_string()))
+    }
+
+    /// `data.name` without the NUL padding it is stored with.
+    pub fn name_trimmed(&self) -> &str {
+        self.data.name.trim_end_matches('\0')
+    }
+
+    pub fn symbol_trimmed(&self) -> &str {
+        self.data.symbol.trim_end_matches('\0')
+    }
+
+    pub fn uri_trimmed(&self) -> &str {
+        self.data.uri.trim_end_matches('\0')
+    }
+
+    /// A copy fit for indexing: name, symbol and uri trimmed as by `Data::sanitized`, and
+    /// creators listed more than once kept at their first position only. Every other field,
+    /// flags included, is unchanged.
+    pub fn normalized(&self) -> Metadata {
+        let mut data = self.data.sanitized();
+        if let Some(creators) = data.creators.as_mut() {
+            let mut seen: Vec<Pubkey> = Vec::with_capacity(creators.len());
+            creators.retain(|creator| {
+                if seen.contains(&creator.address) {
+                    return false;
+                }
+                seen.push(creator.address);
+                true
+            });
+        }
+        Metadata { data, ..self.clone() }
+    }
+
     pub fn into_asset_data(self) -> AssetData {
         let mut asset_data = AssetData::new(
             self.token_standard.unwrap_or(TokenStandard::NonFungible),
@@ -124,31 +174,33 @@ impl Default for Metadata {
     }
 }
 
-// impl TokenMetadataAccount for Metadata {
-//     fn key() -> Key {
-//         Key::MetadataV1
-//     }
-
-//     fn size() -> usize {
-//         MAX_METADATA_LEN
-//     }
-// }
-
-// // We have a custom implementation of BorshDeserialize for Metadata because of corrupted metadata issues
-// // caused by resizing of the Creators array. We use a custom `meta_deser_unchecked` function
-// // that has fallback values for corrupted fields.
-// impl borsh::de::BorshDeserialize for Metadata {
-//     fn deserialize(buf: &mut &[u8]) -> ::core::result::Result<Self, BorshError> {
-//         let md = meta_deser_unchecked(buf)?;
-//         Ok(md)
-//     }
-//     fn deserialize_reader<R: std::io::prelude::Read>(reader: &mut R) -> std::io::Result<Self> {
-//         let md = meta_deser_unchecked_reader(reader)?;
-//         Ok(md)
-//     }
-// }
-
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+impl TokenMetadataAccount for Metadata {
+    fn key() -> Key {
+        Key::MetadataV1
+    }
+
+    fn size() -> usize {
+        MAX_METADATA_LEN
+    }
+}
+
+// We have a custom implementation of BorshDeserialize for Metadata because of corrupted metadata issues
+// caused by resizing of the Creators array. We use a custom `meta_deser_unchecked` function
+// that has fallback values for corrupted fields.
+impl BorshDeserialize for Metadata {
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, BorshError> {
+        meta_deser_unchecked(buf)
+    }
+
+    fn deserialize_reader<R: std::io::prelude::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        meta_deser_unchecked(&mut data.as_slice())
+    }
+}
+
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// Represents the print supply of a non-fungible asset.
 pub enum PrintSupply {
     /// The asset does not have any prints.
@@ -171,7 +223,8 @@ impl PrintSupply {
 }
 
 /// Configuration for programmable assets.
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum ProgrammableConfig {
     V1 {
         /// Programmable authorization rules.
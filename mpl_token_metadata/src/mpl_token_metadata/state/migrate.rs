@@ -1,5 +1,10 @@
 use super::*;
 
+/// The migration requested by a `Migrate` instruction.
+///
+/// The program keeps no migration account: a collection or asset mid-migration is only visible
+/// through the accounts the instruction rewrites (token standard, token record, delegate
+/// records), so this enum is the whole of the migration state.
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
@@ -7,3 +12,21 @@ pub enum MigrationType {
     CollectionV1,
     ProgrammableV1,
 }
+
+impl MigrationType {
+    /// Decodes the serialized variant index, keeping an unknown index in the error.
+    pub fn try_from_u8(value: u8) -> Result<MigrationType, ProgramError> {
+        match value {
+            0 => Ok(MigrationType::CollectionV1),
+            1 => Ok(MigrationType::ProgrammableV1),
+            _ => Err(ProgramError::UnknownMigrationType(value)),
+        }
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<MigrationType, ProgramError> {
+        match data {
+            [value] => MigrationType::try_from_u8(*value),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
@@ -11,9 +11,10 @@ pub mod fee;
 pub(crate) mod master_edition;
 pub(crate) mod metadata;
 pub(crate) mod migrate;
-// pub(crate) mod programmable;
-// pub(crate) mod reservation;
+pub(crate) mod programmable;
+pub(crate) mod reservation;
 pub(crate) mod token_auth_payload;
+pub(crate) mod token_record;
 pub(crate) mod uses;
 
 pub use asset_data::*;
@@ -32,15 +33,23 @@ pub use metadata::*;
 pub use migrate::*;
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
-// pub use programmable::*;
-// pub use reservation::*;
+pub use programmable::*;
+pub use reservation::*;
+pub use token_record::*;
 pub use uses::*;
 use super::error::{ProgramError, MetadataError};
+use super::constants::ID;
+#[cfg(feature = "serde-feature")]
+use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(feature = "serde-feature")]
+use serde_with::As;
+#[cfg(feature = "serde-feature")]
+use spl_token_substream::FromBase58;
 
 pub(crate) use substreams_solana_utils::pubkey::Pubkey;
 
 // Re-export constants to maintain compatibility.
-// pub use crate::pda::{BURN, COLLECTION_AUTHORITY, EDITION, PREFIX, USER};
+pub use super::pda::{BURN, COLLECTION_AUTHORITY, EDITION, PREFIX, USER};
 // use crate::{
 //     assertions::assert_owned_by,
 //     error::MetadataError,
@@ -83,6 +92,18 @@ pub trait TokenMetadataAccount: BorshDeserialize {
         }
     }
 
+    /// Like `is_correct_account_type`, as an error: `UnknownKey` when the first byte isn't a
+    /// known key at all, then `DataTypeMismatch` or `SizeMismatch`, see `account_type_error`.
+    fn check_account_type(data: &[u8], data_type: Key, data_size: usize) -> Result<(), ProgramError> {
+        if let Some(key) = data.first() {
+            Key::try_from_u8(*key)?;
+        }
+        if !Self::is_correct_account_type(data, data_type, data_size) {
+            return Err(account_type_error(data, data_type, data_size));
+        }
+        Ok(())
+    }
+
     fn pad_length(buf: &mut Vec<u8>) -> Result<(), MetadataError> {
         let padding_length = Self::size()
             .checked_sub(buf.len())
@@ -91,29 +112,28 @@ pub trait TokenMetadataAccount: BorshDeserialize {
         Ok(())
     }
 
-    // fn safe_deserialize(mut data: &[u8]) -> Result<Self, BorshError> {
-    //     if !Self::is_correct_account_type(data, Self::key(), Self::size()) {
-    //         return Err(BorshError::new(ErrorKind::Other, "DataTypeMismatch"));
-    //     }
-
-    //     let result = Self::deserialize(&mut data)?;
-
-    //     Ok(result)
-    // }
-
-//     fn from_account_info(a: &AccountInfo) -> Result<Self, ProgramError>
-// where {
-//         let data = &a.data.borrow_mut();
-
-//         let ua = Self::safe_deserialize(data).map_err(|_| MetadataError::DataTypeMismatch)?;
+    /// Deserializes the account after checking its key and size.
+    ///
+    /// Types whose size varies (resized accounts) declare a `size()` of 0, which skips the size
+    /// check. Trailing bytes past the decoded struct are ignored.
+    fn safe_deserialize(mut data: &[u8]) -> Result<Self, ProgramError> {
+        Self::check_account_type(data, Self::key(), Self::size())?;
 
-//         // Check that this is a `token-metadata` owned account.
-//         assert_owned_by(a, &ID)?;
+        Self::deserialize(&mut data).map_err(|e| ProgramError::BorshIoError(e.to_string()))
+    }
 
-//         Ok(ua)
-//     }
+    /// `safe_deserialize` for account data read with its owner, e.g. from an account snapshot.
+    /// Stands in for the on-chain `from_account_info`: data owned by any program other than
+    /// token-metadata fails with `IncorrectOwner`, whatever its bytes.
+    fn from_bytes_with_owner(data: &[u8], owner: &Pubkey) -> Result<Self, ProgramError> {
+        if *owner != ID {
+            return Err(MetadataError::IncorrectOwner.into());
+        }
+        Self::safe_deserialize(data)
+    }
 }
 
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy, FromPrimitive)]
 pub enum Key {
     Uninitialized,
@@ -133,13 +153,53 @@ pub enum Key {
     HolderDelegate,
 }
 
+impl Key {
+    /// Like `Key::from_u8`, but keeps the unknown discriminant in the error so callers can log
+    /// it and skip the account.
+    pub fn try_from_u8(value: u8) -> Result<Key, MetadataError> {
+        Key::from_u8(value).ok_or(MetadataError::UnknownKey(value))
+    }
+}
+
+/// `serde_with` adapter (de)serializing a `Pubkey` as its base58 string, used as
+/// `#[serde(with = "As::<Base58>")]`. `Pubkey` comes from substreams-solana-utils and has
+/// no `FromStr`, so `DisplayFromStr` can't be used.
+#[cfg(feature = "serde-feature")]
+pub struct Base58;
+
+#[cfg(feature = "serde-feature")]
+impl serde_with::SerializeAs<Pubkey> for Base58 {
+    fn serialize_as<S>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(pubkey)
+    }
+}
+
+#[cfg(feature = "serde-feature")]
+impl<'de> serde_with::DeserializeAs<'de, Pubkey> for Base58 {
+    fn deserialize_as<D>(deserializer: D) -> Result<Pubkey, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = <String as serde::de::Deserialize>::deserialize(deserializer)?;
+        pubkey_from_base58(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde-feature")]
+fn pubkey_from_base58(s: &str) -> Result<Pubkey, String> {
+    Pubkey::from_base58(s).map_err(|e| format!("Invalid pubkey {}: {}", s, e))
+}
+
 #[cfg(feature = "serde-feature")]
 fn deser_option_pubkey<'de, D>(deserializer: D) -> Result<Option<Pubkey>, D::Error>
 where
     D: Deserializer<'de>,
 {
     <Option<String> as serde::de::Deserialize>::deserialize(deserializer)?
-        .map(|s| Pubkey::from_str(&s))
+        .map(|s| pubkey_from_base58(&s))
         .transpose()
         .map_err(serde::de::Error::custom)
 }
@@ -153,6 +213,17 @@ where
     serde::ser::Serialize::serialize(&pubkey_string, serializer)
 }
 
+/// The error for `data` rejected as a `data_type` account of `data_size` bytes: `DataTypeMismatch`
+/// when its key is another account type, `SizeMismatch` otherwise, empty data included.
+pub fn account_type_error(data: &[u8], data_type: Key, data_size: usize) -> ProgramError {
+    match data.first().and_then(|key| Key::from_u8(*key)) {
+        Some(found_key) if found_key != data_type && found_key != Key::Uninitialized => {
+            MetadataError::DataTypeMismatch { expected_key: data_type, found_key }.into()
+        },
+        _ => MetadataError::SizeMismatch { expected: data_size, found: data.len() }.into(),
+    }
+}
+
 /// Trait for resizable accounts.
 ///
 /// Implementing this trait for a type will automatically allow the use of the `save` method,
@@ -0,0 +1,157 @@
+use super::*;
+use substreams_solana_utils::pubkey::Pubkey;
+use super::super::utils::try_from_slice_checked;
+
+pub const MAX_RESERVATIONS: usize = 200;
+
+// can hold up to 200 keys per reservation, note: the extra 8 is for number of elements in the vec
+pub const MAX_RESERVATION_LIST_V1_SIZE: usize = 1 + 32 + 8 + 8 + MAX_RESERVATIONS * 34 + 100;
+
+// can hold up to 200 keys per reservation, note: the extra 8 is for number of elements in the vec
+pub const MAX_RESERVATION_LIST_SIZE: usize = 1 + 32 + 8 + 8 + MAX_RESERVATIONS * 48 + 8 + 8 + 84;
+
+/// Read access common to both reservation list versions. Reservation lists belong to the
+/// deprecated printing token flow and can no longer be created or modified on-chain.
+pub trait ReservationList {
+    fn master_edition(&self) -> Pubkey;
+    fn supply_snapshot(&self) -> Option<u64>;
+    fn reservations(&self) -> Vec<Reservation>;
+    fn total_reservation_spots(&self) -> u64;
+    fn current_reservation_spots(&self) -> u64;
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Reservation {
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
+    pub address: Pubkey,
+    pub spots_remaining: u64,
+    pub total_spots: u64,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReservationListV2 {
+    pub key: Key,
+    /// Present for reverse lookups
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
+    pub master_edition: Pubkey,
+
+    /// What supply counter was on master_edition when this reservation was created.
+    pub supply_snapshot: Option<u64>,
+    pub reservations: Vec<Reservation>,
+    /// How many reservations there are going to be, given on first set_reservation call
+    pub total_reservation_spots: u64,
+    /// Cached count of reservation spots in the reservation vec to save on CPU.
+    pub current_reservation_spots: u64,
+}
+
+impl TokenMetadataAccount for ReservationListV2 {
+    fn key() -> Key {
+        Key::ReservationListV2
+    }
+
+    fn size() -> usize {
+        MAX_RESERVATION_LIST_SIZE
+    }
+}
+
+impl ReservationListV2 {
+    pub fn from_bytes(data: &[u8]) -> Result<ReservationListV2, ProgramError> {
+        try_from_slice_checked(data, Key::ReservationListV2, MAX_RESERVATION_LIST_SIZE)
+    }
+}
+
+impl ReservationList for ReservationListV2 {
+    fn master_edition(&self) -> Pubkey {
+        self.master_edition.clone()
+    }
+
+    fn supply_snapshot(&self) -> Option<u64> {
+        self.supply_snapshot
+    }
+
+    fn reservations(&self) -> Vec<Reservation> {
+        self.reservations.clone()
+    }
+
+    fn total_reservation_spots(&self) -> u64 {
+        self.total_reservation_spots
+    }
+
+    fn current_reservation_spots(&self) -> u64 {
+        self.current_reservation_spots
+    }
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReservationV1 {
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
+    pub address: Pubkey,
+    pub spots_remaining: u8,
+    pub total_spots: u8,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReservationListV1 {
+    pub key: Key,
+    /// Present for reverse lookups
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))]
+    pub master_edition: Pubkey,
+
+    /// What supply counter was on master_edition when this reservation was created.
+    pub supply_snapshot: Option<u64>,
+    pub reservations: Vec<ReservationV1>,
+}
+
+impl TokenMetadataAccount for ReservationListV1 {
+    fn key() -> Key {
+        Key::ReservationListV1
+    }
+
+    fn size() -> usize {
+        MAX_RESERVATION_LIST_V1_SIZE
+    }
+}
+
+impl ReservationListV1 {
+    pub fn from_bytes(data: &[u8]) -> Result<ReservationListV1, ProgramError> {
+        try_from_slice_checked(data, Key::ReservationListV1, MAX_RESERVATION_LIST_V1_SIZE)
+    }
+}
+
+/// V1 lists have no spot counters, so both counts are the sum of the reservations' total spots.
+impl ReservationList for ReservationListV1 {
+    fn master_edition(&self) -> Pubkey {
+        self.master_edition.clone()
+    }
+
+    fn supply_snapshot(&self) -> Option<u64> {
+        self.supply_snapshot
+    }
+
+    fn reservations(&self) -> Vec<Reservation> {
+        self.reservations
+            .iter()
+            .map(|reservation| Reservation {
+                address: reservation.address.clone(),
+                spots_remaining: reservation.spots_remaining as u64,
+                total_spots: reservation.total_spots as u64,
+            })
+            .collect()
+    }
+
+    fn total_reservation_spots(&self) -> u64 {
+        self.reservations.iter().map(|reservation| reservation.total_spots as u64).sum()
+    }
+
+    fn current_reservation_spots(&self) -> u64 {
+        self.reservations.iter().map(|reservation| reservation.total_spots as u64).sum()
+    }
+}
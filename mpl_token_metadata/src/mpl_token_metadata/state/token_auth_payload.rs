@@ -5,14 +5,18 @@
 
 use std::collections::HashMap;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-feature")]
+use serde_with::As;
+#[cfg(feature = "serde-feature")]
+use super::Base58;
 use substreams_solana_utils::pubkey::Pubkey;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// A seed path type used by the `DerivedKeyMatch` rule.
 struct SeedsVec {
     /// The vector of derivation seeds.
@@ -21,7 +25,7 @@ struct SeedsVec {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// A proof type used by the `PubkeyTreeMatch` rule.
 struct ProofInfo {
     /// The merkle proof.
@@ -30,11 +34,11 @@ struct ProofInfo {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// Variants representing the different types represented in a payload.
 enum PayloadType {
     /// A plain `Pubkey`.
-    Pubkey(Pubkey),
+    Pubkey(#[cfg_attr(feature = "serde-feature", serde(with = "As::<Base58>"))] Pubkey),
     /// PDA derivation seeds.
     Seeds(SeedsVec),
     /// A merkle proof.
@@ -45,7 +49,7 @@ enum PayloadType {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
 /// A wrapper type for the payload hashmap.
 struct Payload {
     /// The payload hashmap.
@@ -0,0 +1,188 @@
+use super::*;
+
+pub const TOKEN_RECORD_SEED: &str = "token_record";
+
+pub const TOKEN_STATE_INDEX: usize = 2;
+
+pub const LOCKED_TRANSFER_SIZE: usize = 33; // Optional Pubkey
+
+pub const TOKEN_RECORD_SIZE: usize = 1 // Key
++ 1   // bump
++ 1   // state
++ 9   // rule set revision
++ 33  // delegate
++ 2   // delegate role
++ 33; // locked transfer
+
+/// The `TokenRecord` struct represents the state of the token account holding a `pNFT`. Given
+/// that the token account is always frozen, it includes a `state` that provides an abstraction
+/// of frozen (locked) and thaw (unlocked).
+///
+/// It also stores state regarding token delegates that are set on the token account: the pubkey
+/// of the delegate set (this would match the spl-token account delegate) and the role.
+///
+/// Every token account holding a `pNFT` has a token record associated. The seeds for the token
+/// record PDA are:
+/// 1. `"metadata"`
+/// 2. program id
+/// 3. mint id
+/// 4. `"token_record"`
+/// 5. token account id
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TokenRecord {
+    /// Account key.
+    pub key: Key,
+    /// Derivation bump.
+    pub bump: u8,
+    /// Represented the token state.
+    pub state: TokenState,
+    /// Stores the rule set revision (if any). The revision is updated every time
+    /// a new token delegate is approved.
+    pub rule_set_revision: Option<u64>,
+    /// Pubkey of the current token delegate. This delegate key will match the spl-token
+    /// delegate pubkey.
+    #[cfg_attr(
+        feature = "serde-feature",
+        serde(
+            deserialize_with = "deser_option_pubkey",
+            serialize_with = "ser_option_pubkey"
+        )
+    )]
+    pub delegate: Option<Pubkey>,
+    /// The role of the current token delegate.
+    pub delegate_role: Option<TokenDelegateRole>,
+
+    #[deprecated(
+        since = "1.13.2",
+        note = "The locked address is deprecated and will soon be removed."
+    )]
+    /// Stores the destination pubkey when a transfer is lock to an allowed address. This
+    /// pubkey gets set when a 'LockTransfer' delegate is approved.
+    #[cfg_attr(
+        feature = "serde-feature",
+        serde(
+            deserialize_with = "deser_option_pubkey",
+            serialize_with = "ser_option_pubkey"
+        )
+    )]
+    pub locked_transfer: Option<Pubkey>,
+}
+
+impl Default for TokenRecord {
+    fn default() -> Self {
+        Self {
+            key: Key::TokenRecord,
+            bump: 255,
+            state: TokenState::Unlocked,
+            rule_set_revision: None,
+            delegate: None,
+            delegate_role: None,
+            locked_transfer: None,
+        }
+    }
+}
+
+impl TokenMetadataAccount for TokenRecord {
+    fn key() -> Key {
+        Key::TokenRecord
+    }
+
+    fn size() -> usize {
+        TOKEN_RECORD_SIZE
+    }
+
+    fn safe_deserialize(data: &[u8]) -> Result<Self, ProgramError> {
+        <TokenRecord as Resizable>::from_bytes(data)
+    }
+}
+
+impl TokenRecord {
+    pub fn is_locked(&self) -> bool {
+        matches!(self.state, TokenState::Locked)
+    }
+
+    /// Resets the token state by clearing any state stored.
+    pub fn reset(&mut self) {
+        self.state = TokenState::Unlocked;
+        self.rule_set_revision = None;
+        self.delegate = None;
+        self.delegate_role = None;
+        self.locked_transfer = None;
+    }
+}
+
+impl Resizable for TokenRecord {
+    /// Decodes both the current 80-byte layout and the original one without `locked_transfer`,
+    /// 33 bytes shorter.
+    fn from_bytes(account_data: &[u8]) -> Result<TokenRecord, ProgramError> {
+        // we perform a manual deserialization since we are potentially dealing
+        // with accounts of different sizes
+        let length = TokenRecord::size() as i64 - account_data.len() as i64;
+
+        // we use the account length in the 'check_account_type' since we are
+        // manually checking that the account length is valid
+        TokenRecord::check_account_type(account_data, Key::TokenRecord, account_data.len())?;
+        if !(length == 0 || length == LOCKED_TRANSFER_SIZE as i64) {
+            return Err(MetadataError::SizeMismatch { expected: TOKEN_RECORD_SIZE, found: account_data.len() }.into());
+        }
+        // mutable "pointer" to the account data
+        let mut data = account_data;
+
+        let key: Key = deserialize(&mut data)?;
+        let bump: u8 = deserialize(&mut data)?;
+        let state: TokenState = deserialize(&mut data)?;
+        let rule_set_revision: Option<u64> = deserialize(&mut data)?;
+        let delegate: Option<Pubkey> = deserialize(&mut data)?;
+        let delegate_role: Option<TokenDelegateRole> = deserialize(&mut data)?;
+
+        let locked_transfer: Option<Pubkey> = if length == 0 {
+            deserialize(&mut data)?
+        } else {
+            None
+        };
+
+        Ok(TokenRecord {
+            key,
+            bump,
+            state,
+            rule_set_revision,
+            delegate,
+            delegate_role,
+            locked_transfer,
+        })
+    }
+}
+
+/// Programmable account state.
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TokenState {
+    /// Token account is unlocked; operations are allowed on this account.
+    Unlocked,
+    /// Token account has been locked; no operations are allowed on this account.
+    Locked,
+    /// Token account has a `Sale` delegate set; operations are restricted.
+    Listed,
+}
+
+/// Role of the token delegate of a `TokenRecord`.
+///
+/// `Migration` is declared as 255 on-chain but, like every variant, is serialized by its index.
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+#[borsh(use_discriminant = false)]
+pub enum TokenDelegateRole {
+    Sale,
+    Transfer,
+    Utility,
+    Staking,
+    Standard,
+    LockedTransfer,
+    Migration = 255,
+}
+
+fn deserialize<T: BorshDeserialize>(data: &mut &[u8]) -> Result<T, ProgramError> {
+    T::deserialize(data).map_err(|e| ProgramError::BorshIoError(e.to_string()))
+}
@@ -1,5 +1,4 @@
 use super::*;
-use super::super::utils::try_from_slice_checked;
 
 pub const USE_AUTHORITY_RECORD_SIZE: usize = 18; //8 byte padding
 
@@ -12,6 +11,13 @@ pub enum UseMethod {
     Single,
 }
 
+impl UseMethod {
+    /// Whether the token is burned once its last use is utilized.
+    pub fn burns_on_zero(&self) -> bool {
+        *self == UseMethod::Burn
+    }
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
@@ -22,6 +28,18 @@ pub struct Uses {
     pub total: u64,            //8
 }
 
+impl Uses {
+    /// Uses left after utilizing `number_of_uses`, `NotEnoughUses` when fewer remain.
+    pub fn remaining_after(&self, number_of_uses: u64) -> Result<u64, MetadataError> {
+        self.remaining.checked_sub(number_of_uses).ok_or(MetadataError::NotEnoughUses)
+    }
+
+    /// Uses already utilized, `NumericalOverflowError` when `remaining` exceeds `total`.
+    pub fn used(&self) -> Result<u64, MetadataError> {
+        self.total.checked_sub(self.remaining).ok_or(MetadataError::NumericalOverflowError)
+    }
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
@@ -53,9 +71,13 @@ impl TokenMetadataAccount for UseAuthorityRecord {
 
 impl UseAuthorityRecord {
     pub fn from_bytes(b: &[u8]) -> Result<UseAuthorityRecord, ProgramError> {
-        let ua: UseAuthorityRecord =
-            try_from_slice_checked(b, Key::UseAuthorityRecord, USE_AUTHORITY_RECORD_SIZE)?;
-        Ok(ua)
+        UseAuthorityRecord::safe_deserialize(b)
+    }
+
+    /// Uses the authority may still utilize after `number_of_uses`, `NotEnoughUses` when fewer
+    /// are allowed.
+    pub fn allowed_uses_after(&self, number_of_uses: u64) -> Result<u64, MetadataError> {
+        self.allowed_uses.checked_sub(number_of_uses).ok_or(MetadataError::NotEnoughUses)
     }
 
     pub fn bump_empty(&self) -> bool {
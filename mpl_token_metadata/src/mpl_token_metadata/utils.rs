@@ -1,17 +1,117 @@
-use super::state::{Key, TokenMetadataAccount};
-use super::error::ProgramError;
+use borsh::BorshDeserialize;
+use borsh::io::Error as BorshError;
+use substreams_solana_utils::pubkey::Pubkey;
+
+use super::state::{
+    Collection, CollectionDetails, Creator, Data, Key, Metadata, ProgrammableConfig, TokenMetadataAccount, TokenStandard, Uses,
+    MAX_CREATOR_LIMIT,
+};
+use super::error::{MetadataError, ProgramError};
 
 pub fn try_from_slice_checked<T: TokenMetadataAccount>(
     data: &[u8],
     data_type: Key,
     data_size: usize,
 ) -> Result<T, ProgramError> {
-    if !T::is_correct_account_type(data, data_type, data_size) {
-        panic!();
+    T::check_account_type(data, data_type, data_size)?;
+
+    let mut data_mut = data;
+    T::deserialize(&mut data_mut).map_err(|e| ProgramError::BorshIoError(e.to_string()))
+}
+
+/// Like `try_from_slice_checked`, for accounts the program may have reallocated larger than
+/// `data_size`: any length from `data_size` up is accepted and the trailing bytes are ignored.
+pub fn try_from_slice_checked_resizable<T: TokenMetadataAccount>(
+    data: &[u8],
+    data_type: Key,
+    data_size: usize,
+) -> Result<T, ProgramError> {
+    if !data.is_empty() {
+        T::check_account_type(data, data_type, 0)?;
+    }
+    if data.len() < data_size {
+        return Err(MetadataError::SizeMismatch { expected: data_size, found: data.len() }.into());
     }
 
     let mut data_mut = data;
-    let result = T::deserialize(&mut data_mut).unwrap();
+    T::deserialize(&mut data_mut).map_err(|e| ProgramError::BorshIoError(e.to_string()))
+}
 
-    Ok(result)
+pub fn meta_deser_unchecked(buf: &mut &[u8]) -> Result<Metadata, BorshError> {
+    // Metadata corruption shouldn't appear until after edition_nonce.
+    let key: Key = BorshDeserialize::deserialize(buf)?;
+    let update_authority: Pubkey = BorshDeserialize::deserialize(buf)?;
+    let mint: Pubkey = BorshDeserialize::deserialize(buf)?;
+    let name: String = BorshDeserialize::deserialize(buf)?;
+    let symbol: String = BorshDeserialize::deserialize(buf)?;
+    let uri: String = BorshDeserialize::deserialize(buf)?;
+    let seller_fee_basis_points: u16 = BorshDeserialize::deserialize(buf)?;
+
+    // Resizing the creators array has left some accounts with a creators vector that doesn't
+    // decode. Nothing after it can be located then, so the remaining fields keep their defaults.
+    let Some(creators) = creators_deser_unchecked(buf) else {
+        return Ok(Metadata {
+            key,
+            update_authority,
+            mint,
+            data: Data { name, symbol, uri, seller_fee_basis_points, creators: None },
+            ..Default::default()
+        });
+    };
+    let data = Data { name, symbol, uri, seller_fee_basis_points, creators };
+    let primary_sale_happened: bool = BorshDeserialize::deserialize(buf)?;
+    let is_mutable: bool = BorshDeserialize::deserialize(buf)?;
+    let edition_nonce: Option<u8> = BorshDeserialize::deserialize(buf)?;
+
+    // V1.2
+    let token_standard_res: Result<Option<TokenStandard>, BorshError> = BorshDeserialize::deserialize(buf);
+    let collection_res: Result<Option<Collection>, BorshError> = BorshDeserialize::deserialize(buf);
+    let uses_res: Result<Option<Uses>, BorshError> = BorshDeserialize::deserialize(buf);
+
+    // V1.3. Tag 0 is `V1` with the collection size, tag 1 is `V2` with 8 bytes of padding.
+    let collection_details_res: Result<Option<CollectionDetails>, BorshError> = BorshDeserialize::deserialize(buf);
+
+    // pNFT - Programmable Config
+    let programmable_config_res: Result<Option<ProgrammableConfig>, BorshError> = BorshDeserialize::deserialize(buf);
+
+    // We can have accidentally valid, but corrupted data, particularly on the Collection struct,
+    // so to increase probability of catching errors. If any of these deserializations fail, set
+    // all values to None.
+    let (token_standard, collection, uses) = match (token_standard_res, collection_res, uses_res) {
+        (Ok(token_standard_res), Ok(collection_res), Ok(uses_res)) => (token_standard_res, collection_res, uses_res),
+        _ => (None, None, None),
+    };
+
+    // Programmable Config
+    let programmable_config = programmable_config_res.unwrap_or(None);
+    let collection_details = collection_details_res.unwrap_or(None);
+
+    let metadata = Metadata {
+        token_standard,
+        collection,
+        uses,
+        collection_details,
+        programmable_config,
+        key,
+        update_authority,
+        mint,
+        data,
+        primary_sale_happened,
+        is_mutable,
+        edition_nonce,
+    };
+
+    Ok(metadata)
+}
+
+/// Decodes the optional creators vector, leaving `buf` untouched and returning `None` when it
+/// doesn't decode or holds more creators than the program allows.
+fn creators_deser_unchecked(buf: &mut &[u8]) -> Option<Option<Vec<Creator>>> {
+    let mut peek: &[u8] = buf;
+    let creators: Option<Vec<Creator>> = BorshDeserialize::deserialize(&mut peek).ok()?;
+    if creators.as_ref().map_or(false, |creators| creators.len() > MAX_CREATOR_LIMIT) {
+        return None;
+    }
+    *buf = peek;
+    Some(creators)
 }
@@ -114,6 +114,7 @@ pub mod mpl_token_metadata_event {
         Update(super::UpdateEvent),
         #[prost(message, tag="45")]
         UpdateMetadataAccount(super::UpdateMetadataAccountEvent),
+        /// No longer emitted: UpdateMetadataAccountV2 is reported as an UpdateEvent with legacy set.
         #[prost(message, tag="46")]
         UpdateMetadataAccountV2(super::UpdateMetadataAccountV2Event),
         #[prost(message, tag="47")]
@@ -152,18 +153,10 @@ pub struct BubblegumSetCollectionSizeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BurnEvent {
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BurnEditionNftEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BurnNftEvent {
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CloseEscrowAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -172,10 +165,6 @@ pub struct ConvertMasterEditionV1ToV2Event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CreateEvent {
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateEscrowAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -184,10 +173,6 @@ pub struct CreateMasterEditionEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CreateMasterEditionV3Event {
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateMetadataAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -196,10 +181,6 @@ pub struct CreateMetadataAccountV2Event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DelegateEvent {
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeprecatedCreateMasterEditionEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -232,10 +213,6 @@ pub struct LockEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct MigrateEvent {
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MintNewEditionFromMasterEditionViaTokenEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -264,99 +241,393 @@ pub struct RevokeUseAuthorityEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SetAndVerifyCollectionEvent {
+pub struct SetAndVerifySizedCollectionItemEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SetAndVerifySizedCollectionItemEvent {
+pub struct SetTokenStandardEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SetTokenStandardEvent {
+pub struct ThawDelegatedAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SignMetadataEvent {
+pub struct TransferOutOfEscrowEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ThawDelegatedAccountEvent {
+pub struct UnlockEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransferEvent {
+pub struct UnverifyEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransferOutOfEscrowEvent {
+pub struct UnverifySizedCollectionItemEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnlockEvent {
+pub struct UpdateMetadataAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnverifyEvent {
+pub struct UpdateMetadataAccountV2Event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnverifyCollectionEvent {
+pub struct UpdatePrimarySaleHappenedViaTokenEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnverifySizedCollectionItemEvent {
+pub struct UtilizeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateEvent {
+pub struct PrintEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateMetadataAccountEvent {
+pub struct VerifyEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateMetadataAccountV2Event {
+pub struct SetCollectionSizeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdatePrimarySaleHappenedViaTokenEvent {
+pub struct CollectEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UtilizeEvent {
+pub struct UseEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PrintEvent {
+pub struct VerifySizedCollectionItemEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct VerifyEvent {
+pub struct CreateEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub asset_data: ::core::option::Option<AssetData>,
+    #[prost(uint32, optional, tag="8")]
+    pub decimals: ::core::option::Option<u32>,
+    #[prost(message, optional, tag="9")]
+    pub print_supply: ::core::option::Option<PrintSupply>,
+    /// Whether asset_data passes AssetData::validate. Invalid creations are still emitted, with
+    /// the first violation in validation_error.
+    #[prost(bool, tag="10")]
+    pub valid: bool,
+    #[prost(string, optional, tag="11")]
+    pub validation_error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrateEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    /// Name of the MigrationType variant, e.g. "ProgrammableV1". Unset when the instruction
+    /// carries no args, as it has since migrations were disabled.
+    #[prost(string, optional, tag="5")]
+    pub migration_type: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MintEvent {
+    #[prost(string, tag="1")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub token_owner: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="9")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub amount: u64,
 }
+/// Also emitted for the legacy UpdateMetadataAccountV2, which has no mint, payer or
+/// delegate accounts; mint and payer are then empty.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SetCollectionSizeEvent {
+pub struct UpdateEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub token: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub payer: ::prost::alloc::string::String,
+    /// Name of the UpdateArgs variant, e.g. "AsUpdateAuthorityV2", or "UpdateMetadataAccountV2".
+    #[prost(string, tag="7")]
+    pub update_type: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub new_update_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="9")]
+    pub data: ::core::option::Option<Data>,
+    #[prost(bool, optional, tag="10")]
+    pub primary_sale_happened: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag="11")]
+    pub is_mutable: ::core::option::Option<bool>,
+    #[prost(bool, tag="12")]
+    pub legacy: bool,
+    /// Only set when the update sets them; clearing isn't represented.
+    #[prost(message, optional, tag="13")]
+    pub collection: ::core::option::Option<Collection>,
+    #[prost(message, optional, tag="14")]
+    pub uses: ::core::option::Option<Uses>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CollectEvent {
+pub struct TransferEvent {
+    #[prost(string, tag="1")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub token_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub destination_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub owner_token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub destination_token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="9")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UseEvent {
+pub struct BurnEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="7")]
+    pub amount: u64,
 }
+/// The metadata account is closed by the burn, so this is the last event for the asset.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct VerifySizedCollectionItemEvent {
+pub struct BurnNftEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub collection_metadata: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegateEvent {
+    #[prost(string, optional, tag="1")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="2")]
+    pub delegate: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="5")]
+    pub token: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="7")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub payer: ::prost::alloc::string::String,
+    /// Name of the DelegateArgs variant, e.g. "TransferV1".
+    #[prost(string, tag="9")]
+    pub role: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="10")]
+    pub amount: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetData {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub uri: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub seller_fee_basis_points: u32,
+    #[prost(message, repeated, tag="5")]
+    pub creators: ::prost::alloc::vec::Vec<Creator>,
+    #[prost(bool, tag="6")]
+    pub primary_sale_happened: bool,
+    #[prost(bool, tag="7")]
+    pub is_mutable: bool,
+    #[prost(enumeration="TokenStandard", tag="8")]
+    pub token_standard: i32,
+    #[prost(message, optional, tag="9")]
+    pub collection: ::core::option::Option<Collection>,
+    #[prost(message, optional, tag="10")]
+    pub uses: ::core::option::Option<Uses>,
+    #[prost(message, optional, tag="11")]
+    pub collection_details: ::core::option::Option<CollectionDetails>,
+    #[prost(string, optional, tag="12")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Data {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub uri: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub seller_fee_basis_points: u32,
+    #[prost(message, repeated, tag="5")]
+    pub creators: ::prost::alloc::vec::Vec<Creator>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrintSupply {
+    /// Unset for an unlimited print supply.
+    #[prost(uint64, optional, tag="1")]
+    pub max_supply: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateMasterEditionV3Event {
+    #[prost(string, tag="1")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
+    /// Unset for an unlimited print supply.
+    #[prost(uint64, optional, tag="7")]
+    pub max_supply: ::core::option::Option<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VerifyCollectionEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnverifyCollectionEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub collection: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAndVerifyCollectionEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collection: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignMetadataEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub creator: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -446,9 +717,118 @@ pub struct Creator {
     pub address: ::prost::alloc::string::String,
     #[prost(bool, tag="2")]
     pub verified: bool,
+    /// Percentage of the royalties, not basis points.
     #[prost(uint32, tag="3")]
     pub share: u32,
 }
+/// Account states to decode, as produced by an account-changes module. Solana blocks
+/// carry no account data, so account handlers read from this instead.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountUpdates {
+    #[prost(message, repeated, tag="1")]
+    pub accounts: ::prost::alloc::vec::Vec<AccountUpdate>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountUpdate {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MasterEditionEvents {
+    #[prost(message, repeated, tag="1")]
+    pub master_editions: ::prost::alloc::vec::Vec<MasterEditionEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MasterEditionEvent {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(enumeration="Key", tag="2")]
+    pub key: i32,
+    #[prost(uint64, tag="3")]
+    pub supply: u64,
+    #[prost(uint64, optional, tag="4")]
+    pub max_supply: ::core::option::Option<u64>,
+}
+/// Decoded Metadata account. Strings have their null padding trimmed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub uri: ::prost::alloc::string::String,
+    #[prost(uint32, tag="6")]
+    pub seller_fee_basis_points: u32,
+    /// Empty when the metadata lists no creators. A creator is only verified once it has
+    /// signed the metadata, so unverified entries may be spoofed.
+    #[prost(message, repeated, tag="7")]
+    pub creators: ::prost::alloc::vec::Vec<Creator>,
+    #[prost(message, optional, tag="8")]
+    pub collection: ::core::option::Option<Collection>,
+    #[prost(enumeration="TokenStandard", optional, tag="9")]
+    pub token_standard: ::core::option::Option<i32>,
+    #[prost(bool, tag="10")]
+    pub primary_sale_happened: bool,
+    #[prost(bool, tag="11")]
+    pub is_mutable: bool,
+    /// Set on collection parents. Sizes are only tracked by V1; V2 details carry padding.
+    #[prost(message, optional, tag="12")]
+    pub collection_details: ::core::option::Option<CollectionDetails>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataRecords {
+    #[prost(message, repeated, tag="1")]
+    pub records: ::prost::alloc::vec::Vec<MetadataRecord>,
+}
+/// Metadata as set by a creation or update instruction, with strings trimmed of their null
+/// padding and duplicate creators removed. UPDATED records only set the fields the update changes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataRecord {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(enumeration="MetadataAction", tag="2")]
+    pub action: i32,
+    #[prost(string, tag="3")]
+    pub metadata_pda: ::prost::alloc::string::String,
+    /// Empty for legacy UpdateMetadataAccountV2 updates, which don't pass the mint.
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="5")]
+    pub update_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub symbol: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub uri: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag="9")]
+    pub seller_fee_bps: ::core::option::Option<u32>,
+    /// Only meaningful when name is set: creators are replaced along with the rest of the data.
+    #[prost(message, repeated, tag="10")]
+    pub creators: ::prost::alloc::vec::Vec<Creator>,
+    #[prost(message, optional, tag="11")]
+    pub collection: ::core::option::Option<Collection>,
+    #[prost(enumeration="TokenStandard", optional, tag="12")]
+    pub token_standard: ::core::option::Option<i32>,
+    #[prost(bool, optional, tag="13")]
+    pub is_mutable: ::core::option::Option<bool>,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum UseMethod {
@@ -481,4 +861,133 @@ impl UseMethod {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Key {
+    Uninitialized = 0,
+    EditionV1 = 1,
+    MasterEditionV1 = 2,
+    ReservationListV1 = 3,
+    MetadataV1 = 4,
+    ReservationListV2 = 5,
+    MasterEditionV2 = 6,
+    EditionMarker = 7,
+    UseAuthorityRecord = 8,
+    CollectionAuthorityRecord = 9,
+    TokenOwnedEscrow = 10,
+    TokenRecord = 11,
+    MetadataDelegate = 12,
+    EditionMarkerV2 = 13,
+    HolderDelegate = 14,
+}
+impl Key {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Key::Uninitialized => "UNINITIALIZED",
+            Key::EditionV1 => "EDITION_V1",
+            Key::MasterEditionV1 => "MASTER_EDITION_V1",
+            Key::ReservationListV1 => "RESERVATION_LIST_V1",
+            Key::MetadataV1 => "METADATA_V1",
+            Key::ReservationListV2 => "RESERVATION_LIST_V2",
+            Key::MasterEditionV2 => "MASTER_EDITION_V2",
+            Key::EditionMarker => "EDITION_MARKER",
+            Key::UseAuthorityRecord => "USE_AUTHORITY_RECORD",
+            Key::CollectionAuthorityRecord => "COLLECTION_AUTHORITY_RECORD",
+            Key::TokenOwnedEscrow => "TOKEN_OWNED_ESCROW",
+            Key::TokenRecord => "TOKEN_RECORD",
+            Key::MetadataDelegate => "METADATA_DELEGATE",
+            Key::EditionMarkerV2 => "EDITION_MARKER_V2",
+            Key::HolderDelegate => "HOLDER_DELEGATE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNINITIALIZED" => Some(Self::Uninitialized),
+            "EDITION_V1" => Some(Self::EditionV1),
+            "MASTER_EDITION_V1" => Some(Self::MasterEditionV1),
+            "RESERVATION_LIST_V1" => Some(Self::ReservationListV1),
+            "METADATA_V1" => Some(Self::MetadataV1),
+            "RESERVATION_LIST_V2" => Some(Self::ReservationListV2),
+            "MASTER_EDITION_V2" => Some(Self::MasterEditionV2),
+            "EDITION_MARKER" => Some(Self::EditionMarker),
+            "USE_AUTHORITY_RECORD" => Some(Self::UseAuthorityRecord),
+            "COLLECTION_AUTHORITY_RECORD" => Some(Self::CollectionAuthorityRecord),
+            "TOKEN_OWNED_ESCROW" => Some(Self::TokenOwnedEscrow),
+            "TOKEN_RECORD" => Some(Self::TokenRecord),
+            "METADATA_DELEGATE" => Some(Self::MetadataDelegate),
+            "EDITION_MARKER_V2" => Some(Self::EditionMarkerV2),
+            "HOLDER_DELEGATE" => Some(Self::HolderDelegate),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TokenStandard {
+    NonFungible = 0,
+    FungibleAsset = 1,
+    Fungible = 2,
+    NonFungibleEdition = 3,
+    ProgrammableNonFungible = 4,
+    ProgrammableNonFungibleEdition = 5,
+}
+impl TokenStandard {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TokenStandard::NonFungible => "NON_FUNGIBLE",
+            TokenStandard::FungibleAsset => "FUNGIBLE_ASSET",
+            TokenStandard::Fungible => "FUNGIBLE",
+            TokenStandard::NonFungibleEdition => "NON_FUNGIBLE_EDITION",
+            TokenStandard::ProgrammableNonFungible => "PROGRAMMABLE_NON_FUNGIBLE",
+            TokenStandard::ProgrammableNonFungibleEdition => "PROGRAMMABLE_NON_FUNGIBLE_EDITION",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NON_FUNGIBLE" => Some(Self::NonFungible),
+            "FUNGIBLE_ASSET" => Some(Self::FungibleAsset),
+            "FUNGIBLE" => Some(Self::Fungible),
+            "NON_FUNGIBLE_EDITION" => Some(Self::NonFungibleEdition),
+            "PROGRAMMABLE_NON_FUNGIBLE" => Some(Self::ProgrammableNonFungible),
+            "PROGRAMMABLE_NON_FUNGIBLE_EDITION" => Some(Self::ProgrammableNonFungibleEdition),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MetadataAction {
+    Created = 0,
+    Updated = 1,
+}
+impl MetadataAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MetadataAction::Created => "CREATED",
+            MetadataAction::Updated => "UPDATED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CREATED" => Some(Self::Created),
+            "UPDATED" => Some(Self::Updated),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
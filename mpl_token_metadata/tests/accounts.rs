@@ -0,0 +1,499 @@
+//! Decoding of the token-metadata accounts other than `Metadata`, from hand-laid bytes.
+use std::fmt::Debug;
+
+use borsh::BorshSerialize;
+use mpl_token_metadata_substream::mpl_token_metadata::constants::ID;
+use mpl_token_metadata_substream::mpl_token_metadata::error::{MetadataError, ProgramError};
+use mpl_token_metadata_substream::mpl_token_metadata::state::{
+    CollectionAuthorityRecord, Edition, EditionMarker, EditionMarkerV2, EditionMarkerV2Ref, EscrowAuthority,
+    HolderDelegateRecord, Key, MasterEdition, MasterEditionAccount, MasterEditionV1, MasterEditionV2, Metadata,
+    MetadataDelegateRecord, Reservation, ReservationList, ReservationListV1, ReservationListV2, ReservationV1,
+    TokenDelegateRole, TokenMetadataAccount, TokenOwnedEscrow, TokenRecord, TokenState, UseAuthorityRecord, UseMethod,
+    Uses, COLLECTION_AUTHORITY_RECORD_LEGACY_SIZE, COLLECTION_AUTHORITY_RECORD_SIZE, MAX_EDITION_LEN,
+    MAX_MASTER_EDITION_LEN, MAX_RESERVATION_LIST_SIZE, MAX_RESERVATION_LIST_V1_SIZE, TOKEN_RECORD_SIZE,
+    USE_AUTHORITY_RECORD_SIZE,
+};
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// A `MetadataDelegateRecord` with bump 254, mint `[3; 32]`, delegate `[7; 32]` and update
+/// authority `[5; 32]`: 98 bytes.
+fn metadata_delegate_record(key: Key) -> Vec<u8> {
+    [vec![key as u8, 254], vec![3; 32], vec![7; 32], vec![5; 32]].concat()
+}
+
+#[test]
+fn safe_deserialize() {
+    let record = MetadataDelegateRecord::safe_deserialize(&metadata_delegate_record(Key::MetadataDelegate)).unwrap();
+    assert_eq!(record, MetadataDelegateRecord {
+        key: Key::MetadataDelegate,
+        bump: 254,
+        mint: Pubkey([3; 32]),
+        delegate: Pubkey([7; 32]),
+        update_authority: Pubkey([5; 32]),
+    });
+}
+
+#[test]
+fn safe_deserialize_wrong_key() {
+    // A holder delegate record has the very same layout.
+    let data = metadata_delegate_record(Key::HolderDelegate);
+    assert_eq!(
+        MetadataDelegateRecord::safe_deserialize(&data),
+        Err(ProgramError::Metadata(MetadataError::DataTypeMismatch { expected_key: Key::MetadataDelegate, found_key: Key::HolderDelegate })),
+    );
+}
+
+#[test]
+fn safe_deserialize_wrong_size() {
+    let mut data = metadata_delegate_record(Key::MetadataDelegate);
+    data.pop();
+    assert_eq!(
+        MetadataDelegateRecord::safe_deserialize(&data),
+        Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: 98, found: 97 })),
+    );
+    assert_eq!(
+        MetadataDelegateRecord::safe_deserialize(&[]),
+        Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: 98, found: 0 })),
+    );
+}
+
+#[test]
+fn resized_accounts_are_accepted() {
+    // Reallocated larger by the program, with zeros after the canonical layout.
+    let data = padded(metadata_delegate_record(Key::MetadataDelegate), 98 + 32);
+    assert_eq!(
+        MetadataDelegateRecord::from_bytes(&data),
+        MetadataDelegateRecord::safe_deserialize(&metadata_delegate_record(Key::MetadataDelegate)),
+    );
+    let data = padded(master_edition_v2_bytes(3, Some(10)), MAX_MASTER_EDITION_LEN + 100);
+    let master_edition = MasterEditionV2::from_bytes(&data).unwrap();
+    assert_eq!(master_edition.supply, 3);
+    assert_eq!(master_edition.max_supply, Some(10));
+    // Smaller than the canonical size is still rejected.
+    let data = master_edition_v2_bytes(3, Some(10));
+    assert_eq!(
+        MasterEditionV2::from_bytes(&data[..MAX_MASTER_EDITION_LEN - 1]),
+        Err(ProgramError::Metadata(MetadataError::SizeMismatch {
+            expected: MAX_MASTER_EDITION_LEN,
+            found: MAX_MASTER_EDITION_LEN - 1,
+        })),
+    );
+}
+
+#[test]
+fn mismatch_errors_report_details() {
+    let error = MetadataDelegateRecord::from_bytes(&metadata_delegate_record(Key::HolderDelegate)).unwrap_err();
+    let ProgramError::Metadata(MetadataError::DataTypeMismatch { expected_key, found_key }) = &error else {
+        panic!("Expected DataTypeMismatch, got {:?}", error)
+    };
+    assert_eq!((*expected_key, *found_key), (Key::MetadataDelegate, Key::HolderDelegate));
+    assert_eq!(error.to_string(), "Data type mismatch: expected MetadataDelegate, found HolderDelegate");
+
+    let error = MetadataDelegateRecord::from_bytes(&metadata_delegate_record(Key::MetadataDelegate)[..50]).unwrap_err();
+    let ProgramError::Metadata(MetadataError::SizeMismatch { expected, found }) = &error else {
+        panic!("Expected SizeMismatch, got {:?}", error)
+    };
+    assert_eq!((*expected, *found), (98, 50));
+    assert_eq!(error.to_string(), "Size mismatch: expected 98 bytes, found 50");
+}
+
+#[test]
+fn from_bytes_with_correct_owner() {
+    let data = metadata_delegate_record(Key::MetadataDelegate);
+    assert_eq!(
+        MetadataDelegateRecord::from_bytes_with_owner(&data, &ID),
+        MetadataDelegateRecord::safe_deserialize(&data),
+    );
+    assert!(MetadataDelegateRecord::from_bytes_with_owner(&data, &ID).is_ok());
+    // The owner check comes before, not instead of, the account checks.
+    assert_eq!(
+        MetadataDelegateRecord::from_bytes_with_owner(&data[..97], &ID),
+        Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: 98, found: 97 })),
+    );
+}
+
+#[test]
+fn from_bytes_with_wrong_owner() {
+    let data = metadata_delegate_record(Key::MetadataDelegate);
+    // The same bytes owned by another program, e.g. a copy of the record.
+    assert_eq!(
+        MetadataDelegateRecord::from_bytes_with_owner(&data, &Pubkey([9; 32])),
+        Err(ProgramError::Metadata(MetadataError::IncorrectOwner)),
+    );
+    assert_eq!(
+        HolderDelegateRecord::from_bytes_with_owner(&[], &Pubkey::default()),
+        Err(ProgramError::Metadata(MetadataError::IncorrectOwner)),
+    );
+}
+
+#[test]
+fn unknown_key() {
+    assert_eq!(Key::try_from_u8(200), Err(MetadataError::UnknownKey(200)));
+    let mut data = metadata_delegate_record(Key::MetadataDelegate);
+    data[0] = 200;
+    assert_eq!(
+        MetadataDelegateRecord::safe_deserialize(&data),
+        Err(ProgramError::Metadata(MetadataError::UnknownKey(200))),
+    );
+    assert_eq!(
+        Metadata::from_bytes(&[200; 679]),
+        Err(ProgramError::Metadata(MetadataError::UnknownKey(200))),
+    );
+}
+
+fn padded(mut data: Vec<u8>, size: usize) -> Vec<u8> {
+    data.resize(size, 0);
+    data
+}
+
+/// A `MasterEditionV2` of `supply` prints out of `max_supply`, padded to the account size.
+fn master_edition_v2_bytes(supply: u64, max_supply: Option<u64>) -> Vec<u8> {
+    let max_supply = match max_supply {
+        Some(max_supply) => [vec![1], max_supply.to_le_bytes().to_vec()].concat(),
+        None => vec![0],
+    };
+    padded([vec![Key::MasterEditionV2 as u8], supply.to_le_bytes().to_vec(), max_supply].concat(), MAX_MASTER_EDITION_LEN)
+}
+
+#[test]
+fn master_edition_v2() {
+    let data = master_edition_v2_bytes(3, Some(10));
+    assert_eq!(MasterEditionV2::from_bytes(&data).unwrap(), MasterEditionV2 {
+        key: Key::MasterEditionV2,
+        supply: 3,
+        max_supply: Some(10),
+    });
+    let master_edition = MasterEditionAccount::from_bytes(&data).unwrap();
+    assert_eq!(master_edition.key(), Key::MasterEditionV2);
+    assert_eq!((master_edition.supply(), master_edition.max_supply()), (3, Some(10)));
+}
+
+#[test]
+fn master_edition_v2_unlimited_supply() {
+    let data = master_edition_v2_bytes(1_500, None);
+    let master_edition = MasterEditionAccount::from_bytes(&data).unwrap();
+    assert_eq!(master_edition, MasterEditionAccount::V2(MasterEditionV2 {
+        key: Key::MasterEditionV2,
+        supply: 1_500,
+        max_supply: None,
+    }));
+    assert_eq!(master_edition.max_supply(), None);
+}
+
+#[test]
+fn master_edition_v1_with_printing_mints() {
+    // The deprecated printing and one-time authorization mints follow the supplies.
+    let data = padded(
+        [vec![Key::MasterEditionV1 as u8], 2u64.to_le_bytes().to_vec(), vec![0], vec![8; 32], vec![9; 32]].concat(),
+        MAX_MASTER_EDITION_LEN,
+    );
+    let expected = MasterEditionV1 {
+        key: Key::MasterEditionV1,
+        supply: 2,
+        max_supply: None,
+        printing_mint: Pubkey([8; 32]),
+        one_time_printing_authorization_mint: Pubkey([9; 32]),
+    };
+    assert_eq!(MasterEditionV1::from_bytes(&data).unwrap(), expected);
+    assert_eq!(MasterEditionAccount::from_bytes(&data).unwrap(), MasterEditionAccount::V1(expected));
+}
+
+#[test]
+fn master_edition_of_another_type() {
+    let data = padded(vec![Key::EditionV1 as u8], MAX_MASTER_EDITION_LEN);
+    assert_eq!(
+        MasterEditionAccount::from_bytes(&data),
+        Err(ProgramError::Metadata(MetadataError::DataTypeMismatch { expected_key: Key::MasterEditionV2, found_key: Key::EditionV1 })),
+    );
+}
+
+#[test]
+fn edition() {
+    let data = padded([vec![Key::EditionV1 as u8], vec![6; 32], 249u64.to_le_bytes().to_vec()].concat(), MAX_EDITION_LEN);
+    assert_eq!(Edition::from_bytes(&data).unwrap(), Edition {
+        key: Key::EditionV1,
+        parent: Pubkey([6; 32]),
+        edition: 249,
+    });
+}
+
+#[test]
+fn edition_marker_boundaries() {
+    // Marker 0 holds editions 0 to 247, marker 1 editions 248 to 495.
+    assert_eq!((EditionMarker::marker_number(1), EditionMarker::get_index_and_mask(1)), (0, (0, 0b0100_0000)));
+    assert_eq!((EditionMarker::marker_number(247), EditionMarker::get_index_and_mask(247)), (0, (30, 0b0000_0001)));
+    assert_eq!((EditionMarker::marker_number(248), EditionMarker::get_index_and_mask(248)), (1, (0, 0b1000_0000)));
+    assert_eq!((EditionMarker::marker_number(249), EditionMarker::get_index_and_mask(249)), (1, (0, 0b0100_0000)));
+    assert_eq!((EditionMarker::marker_number(250), EditionMarker::get_index_and_mask(250)), (1, (0, 0b0010_0000)));
+}
+
+#[test]
+fn edition_marker_taken_editions() {
+    // Marker 1 with editions 248 and 249 printed, then 495, its last one.
+    let mut ledger = [0; 31];
+    ledger[0] = 0b1100_0000;
+    ledger[30] = 0b0000_0001;
+    let data = [vec![Key::EditionMarker as u8], ledger.to_vec()].concat();
+    let marker = EditionMarker::from_bytes(&data).unwrap();
+    assert!(marker.edition_taken(248));
+    assert!(marker.edition_taken(249));
+    assert!(!marker.edition_taken(250));
+    assert!(marker.edition_taken(495));
+    assert_eq!(marker.taken_editions(1).collect::<Vec<_>>(), vec![248, 249, 495]);
+}
+
+/// An `EditionMarkerV2` whose two-byte ledger records editions 0, 7 and 9, followed by the
+/// zeros left by a resize.
+fn edition_marker_v2_bytes() -> Vec<u8> {
+    [vec![Key::EditionMarkerV2 as u8], 2u32.to_le_bytes().to_vec(), vec![0b1000_0001, 0b0100_0000], vec![0; 8]].concat()
+}
+
+#[test]
+fn edition_marker_v2() {
+    let data = edition_marker_v2_bytes();
+    let marker = EditionMarkerV2::from_bytes(&data).unwrap();
+    assert_eq!(marker.ledger, vec![0b1000_0001, 0b0100_0000]);
+    assert_eq!(marker.count_taken(), 3);
+    let marker = EditionMarkerV2Ref::from_bytes(&data).unwrap();
+    assert_eq!(marker.ledger, &[0b1000_0001, 0b0100_0000]);
+    assert_eq!(marker.count_taken(), 3);
+    assert_eq!([0, 1, 7, 8, 9].map(|edition| marker.edition_taken(edition)), [Ok(true), Ok(false), Ok(true), Ok(false), Ok(true)]);
+}
+
+#[test]
+fn edition_marker_v2_beyond_the_ledger() {
+    let data = edition_marker_v2_bytes();
+    let marker = EditionMarkerV2::from_bytes(&data).unwrap();
+    assert_eq!(marker.edition_taken(16), Ok(false));
+    assert_eq!(marker.edition_taken(1_000_000), Ok(false));
+    let marker = EditionMarkerV2Ref::from_bytes(&data).unwrap();
+    assert_eq!(marker.edition_taken(16), Ok(false));
+    assert_eq!(marker.edition_taken(u32::MAX as u64), Ok(false));
+}
+
+#[test]
+fn edition_marker_v2_truncated_ledger() {
+    let data = [vec![Key::EditionMarkerV2 as u8], 4u32.to_le_bytes().to_vec(), vec![0xff; 2]].concat();
+    assert_eq!(EditionMarkerV2Ref::from_bytes(&data), Err(ProgramError::AccountDataTooSmall));
+}
+
+#[test]
+#[allow(deprecated)]
+fn token_record_locked_with_delegate() {
+    // Locked by a utility delegate `[7; 32]` approved under rule set revision 2.
+    let data = padded(
+        [vec![Key::TokenRecord as u8, 254, 1], vec![1], 2u64.to_le_bytes().to_vec(), vec![1], vec![7; 32], vec![1, 2], vec![0]].concat(),
+        TOKEN_RECORD_SIZE,
+    );
+    let record = TokenRecord::safe_deserialize(&data).unwrap();
+    assert_eq!(record, TokenRecord {
+        key: Key::TokenRecord,
+        bump: 254,
+        state: TokenState::Locked,
+        rule_set_revision: Some(2),
+        delegate: Some(Pubkey([7; 32])),
+        delegate_role: Some(TokenDelegateRole::Utility),
+        locked_transfer: None,
+    });
+    assert!(record.is_locked());
+}
+
+#[test]
+#[allow(deprecated)]
+fn token_record_unlocked_without_delegate() {
+    let data = padded(vec![Key::TokenRecord as u8, 255, 0, 0, 0, 0, 0], TOKEN_RECORD_SIZE);
+    let record = TokenRecord::safe_deserialize(&data).unwrap();
+    assert_eq!(record, TokenRecord { bump: 255, ..TokenRecord::default() });
+    assert!(!record.is_locked());
+    // The original layout, without `locked_transfer`, is 33 bytes shorter.
+    let legacy = padded(vec![Key::TokenRecord as u8, 255, 0, 0, 0, 0], TOKEN_RECORD_SIZE - 33);
+    assert_eq!(TokenRecord::safe_deserialize(&legacy).unwrap(), record);
+}
+
+#[test]
+fn token_record_wrong_size() {
+    let data = padded(vec![Key::TokenRecord as u8, 255], TOKEN_RECORD_SIZE - 1);
+    assert_eq!(
+        TokenRecord::safe_deserialize(&data),
+        Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: TOKEN_RECORD_SIZE, found: TOKEN_RECORD_SIZE - 1 })),
+    );
+}
+
+#[test]
+fn reservation_list_v1() {
+    // Master edition `[6; 32]`, snapshot at supply 4, two reservations with u8 spot counts.
+    let data = padded(
+        [
+            vec![Key::ReservationListV1 as u8],
+            vec![6; 32],
+            vec![1], 4u64.to_le_bytes().to_vec(),
+            2u32.to_le_bytes().to_vec(),
+            vec![1; 32], vec![1, 2],
+            vec![2; 32], vec![0, 3],
+        ].concat(),
+        MAX_RESERVATION_LIST_V1_SIZE,
+    );
+    let list = ReservationListV1::from_bytes(&data).unwrap();
+    assert_eq!(list, ReservationListV1 {
+        key: Key::ReservationListV1,
+        master_edition: Pubkey([6; 32]),
+        supply_snapshot: Some(4),
+        reservations: vec![
+            ReservationV1 { address: Pubkey([1; 32]), spots_remaining: 1, total_spots: 2 },
+            ReservationV1 { address: Pubkey([2; 32]), spots_remaining: 0, total_spots: 3 },
+        ],
+    });
+    assert_eq!(list.reservations()[1], Reservation { address: Pubkey([2; 32]), spots_remaining: 0, total_spots: 3 });
+    assert_eq!((list.total_reservation_spots(), list.current_reservation_spots()), (5, 5));
+}
+
+#[test]
+fn reservation_list_v2() {
+    // Master edition `[6; 32]`, no snapshot yet, one reservation of 10 spots out of 20 planned.
+    let data = padded(
+        [
+            vec![Key::ReservationListV2 as u8],
+            vec![6; 32],
+            vec![0],
+            1u32.to_le_bytes().to_vec(),
+            vec![1; 32], 10u64.to_le_bytes().to_vec(), 10u64.to_le_bytes().to_vec(),
+            20u64.to_le_bytes().to_vec(),
+            10u64.to_le_bytes().to_vec(),
+        ].concat(),
+        MAX_RESERVATION_LIST_SIZE,
+    );
+    let list = ReservationListV2::from_bytes(&data).unwrap();
+    assert_eq!(list, ReservationListV2 {
+        key: Key::ReservationListV2,
+        master_edition: Pubkey([6; 32]),
+        supply_snapshot: None,
+        reservations: vec![Reservation { address: Pubkey([1; 32]), spots_remaining: 10, total_spots: 10 }],
+        total_reservation_spots: 20,
+        current_reservation_spots: 10,
+    });
+    assert_eq!(list.master_edition(), Pubkey([6; 32]));
+    // A V1 list isn't decoded as a V2 one.
+    let v1 = padded(vec![Key::ReservationListV1 as u8], MAX_RESERVATION_LIST_SIZE);
+    assert_eq!(
+        ReservationListV2::from_bytes(&v1),
+        Err(ProgramError::Metadata(MetadataError::DataTypeMismatch { expected_key: Key::ReservationListV2, found_key: Key::ReservationListV1 })),
+    );
+}
+
+/// Serializes the default `T`, pads it to the account size and checks it decodes back equal.
+fn assert_default_round_trip<T>()
+where
+    T: TokenMetadataAccount + BorshSerialize + Default + PartialEq + Debug,
+{
+    let value = T::default();
+    let mut data = borsh::to_vec(&value).unwrap();
+    T::pad_length(&mut data).unwrap();
+    assert_eq!(data.len(), T::size());
+    assert_eq!(T::safe_deserialize(&data).unwrap(), value);
+}
+
+#[test]
+fn default_round_trips() {
+    assert_default_round_trip::<Metadata>();
+    assert_default_round_trip::<MasterEditionV2>();
+    assert_default_round_trip::<Edition>();
+    assert_default_round_trip::<EditionMarker>();
+    assert_default_round_trip::<MetadataDelegateRecord>();
+    assert_default_round_trip::<HolderDelegateRecord>();
+    assert_default_round_trip::<TokenRecord>();
+    assert_default_round_trip::<CollectionAuthorityRecord>();
+    assert_default_round_trip::<UseAuthorityRecord>();
+}
+
+#[test]
+fn use_authority_record() {
+    // 5 uses allowed, bump 253, then the 8 bytes of padding.
+    let data = padded([vec![Key::UseAuthorityRecord as u8], 5u64.to_le_bytes().to_vec(), vec![253]].concat(), USE_AUTHORITY_RECORD_SIZE);
+    assert_eq!(data.len(), 18);
+    let record = UseAuthorityRecord::from_bytes(&data).unwrap();
+    assert_eq!(record, UseAuthorityRecord { key: Key::UseAuthorityRecord, allowed_uses: 5, bump: 253 });
+    assert!(!record.bump_empty());
+    assert_eq!(record.allowed_uses_after(2), Ok(3));
+    assert_eq!(record.allowed_uses_after(6), Err(MetadataError::NotEnoughUses));
+    assert_eq!(
+        UseAuthorityRecord::from_bytes(&data[..10]),
+        Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: 18, found: 10 })),
+    );
+}
+
+#[test]
+fn uses_accounting() {
+    let uses = Uses { use_method: UseMethod::Burn, remaining: 2, total: 3 };
+    assert!(uses.use_method.burns_on_zero());
+    assert_eq!(uses.remaining_after(2), Ok(0));
+    assert_eq!(uses.remaining_after(3), Err(MetadataError::NotEnoughUses));
+    assert_eq!(uses.used(), Ok(1));
+    assert!(!UseMethod::Multiple.burns_on_zero());
+}
+
+#[test]
+fn collection_authority_record() {
+    let data = [vec![Key::CollectionAuthorityRecord as u8, 253, 1], vec![5; 32]].concat();
+    assert_eq!(data.len(), COLLECTION_AUTHORITY_RECORD_SIZE);
+    assert_eq!(CollectionAuthorityRecord::from_bytes(&data).unwrap(), CollectionAuthorityRecord {
+        key: Key::CollectionAuthorityRecord,
+        bump: 253,
+        update_authority: Some(Pubkey([5; 32])),
+    });
+}
+
+#[test]
+fn collection_authority_record_legacy() {
+    // Key and bump, then 9 bytes of padding that were never zeroed.
+    let data = [vec![Key::CollectionAuthorityRecord as u8, 254], vec![0xaa; 9]].concat();
+    assert_eq!(data.len(), COLLECTION_AUTHORITY_RECORD_LEGACY_SIZE);
+    assert_eq!(CollectionAuthorityRecord::from_bytes(&data).unwrap(), CollectionAuthorityRecord {
+        key: Key::CollectionAuthorityRecord,
+        bump: 254,
+        update_authority: None,
+    });
+}
+
+#[test]
+fn collection_authority_record_wrong_size() {
+    let data = padded(vec![Key::CollectionAuthorityRecord as u8, 254], 20);
+    assert_eq!(
+        CollectionAuthorityRecord::from_bytes(&data),
+        Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: COLLECTION_AUTHORITY_RECORD_SIZE, found: 20 })),
+    );
+}
+
+#[test]
+fn escrow_owned_by_the_token_owner() {
+    let data = [vec![Key::TokenOwnedEscrow as u8], vec![4; 32], vec![0, 252]].concat();
+    assert_eq!(TokenOwnedEscrow::from_bytes(&data).unwrap(), TokenOwnedEscrow {
+        key: Key::TokenOwnedEscrow,
+        base_token: Pubkey([4; 32]),
+        authority: EscrowAuthority::TokenOwner,
+        bump: 252,
+    });
+}
+
+#[test]
+fn escrow_owned_by_a_creator() {
+    let data = [vec![Key::TokenOwnedEscrow as u8], vec![4; 32], vec![1], vec![5; 32], vec![251]].concat();
+    assert_eq!(TokenOwnedEscrow::from_bytes(&data).unwrap(), TokenOwnedEscrow {
+        key: Key::TokenOwnedEscrow,
+        base_token: Pubkey([4; 32]),
+        authority: EscrowAuthority::Creator(Pubkey([5; 32])),
+        bump: 251,
+    });
+}
+
+#[test]
+fn malformed_escrow() {
+    // A creator authority cut short, and a token owner one missing its bump.
+    let creator = [vec![Key::TokenOwnedEscrow as u8], vec![4; 32], vec![1], vec![5; 16]].concat();
+    assert_eq!(TokenOwnedEscrow::from_bytes(&creator), Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: 67, found: 50 })));
+    let token_owner = [vec![Key::TokenOwnedEscrow as u8], vec![4; 32], vec![0]].concat();
+    assert_eq!(TokenOwnedEscrow::from_bytes(&token_owner), Err(ProgramError::Metadata(MetadataError::SizeMismatch { expected: 35, found: 34 })));
+    // An authority tag past the known variants.
+    let unknown_authority = [vec![Key::TokenOwnedEscrow as u8], vec![4; 32], vec![7, 252]].concat();
+    assert!(matches!(TokenOwnedEscrow::from_bytes(&unknown_authority), Err(ProgramError::BorshIoError(_))));
+    assert!(TokenOwnedEscrow::from_bytes(&[]).is_err());
+}
@@ -0,0 +1,77 @@
+use mpl_token_metadata_substream::mpl_token_metadata::error::MetadataError;
+use mpl_token_metadata_substream::mpl_token_metadata::state::{
+    validate_creators, Creator, Data, DataV2, MAX_NAME_LENGTH, MAX_URI_LENGTH,
+};
+use substreams_solana_utils::pubkey::Pubkey;
+
+fn creator(n: u8, share: u8) -> Creator {
+    Creator { address: Pubkey([n; 32]), verified: false, share }
+}
+
+fn data(name: &str, uri: &str) -> Data {
+    Data {
+        name: name.to_string(),
+        symbol: "DGN\0\0\0\0\0\0\0".to_string(),
+        uri: uri.to_string(),
+        seller_fee_basis_points: 500,
+        creators: Some(vec![creator(5, 100)]),
+    }
+}
+
+#[test]
+fn valid_creators() {
+    assert_eq!(validate_creators(&[creator(5, 100)]), Ok(()));
+    assert_eq!(validate_creators(&[creator(5, 0), creator(13, 100)]), Ok(()));
+    let five: Vec<Creator> = (1..=5).map(|n| creator(n, 20)).collect();
+    assert_eq!(validate_creators(&five), Ok(()));
+}
+
+#[test]
+fn invalid_creators() {
+    let six: Vec<Creator> = (1..=6).map(|n| creator(n, if n == 1 { 50 } else { 10 })).collect();
+    assert_eq!(validate_creators(&six), Err(MetadataError::CreatorsTooLong));
+    assert_eq!(validate_creators(&[]), Err(MetadataError::CreatorsMustBeAtleastOne));
+    assert_eq!(validate_creators(&[creator(5, 50), creator(5, 50)]), Err(MetadataError::DuplicateCreatorAddress));
+    assert_eq!(validate_creators(&[creator(5, 50), creator(13, 40)]), Err(MetadataError::ShareTotalMustBe100));
+    // Shares are summed without overflowing a u8.
+    assert_eq!(validate_creators(&[creator(5, 200), creator(13, 156)]), Err(MetadataError::ShareTotalMustBe100));
+}
+
+#[test]
+fn sanitized_puffed_name() {
+    // The name as stored on-chain, puffed with NULs to the field capacity.
+    let name = format!("{:\0<width$}", "Degen #1234", width = MAX_NAME_LENGTH);
+    assert_eq!(name.len(), 32);
+    let sanitized = data(&name, "https://arweave.net/degen-1234").sanitized();
+    assert_eq!(sanitized.name, "Degen #1234");
+    assert_eq!(sanitized.symbol, "DGN");
+    assert_eq!(sanitized.seller_fee_basis_points, 500);
+    assert_eq!(sanitized.creators, Some(vec![creator(5, 100)]));
+}
+
+#[test]
+fn sanitized_uri_at_capacity() {
+    // 198 ASCII bytes and a 2-byte character fill the 200-byte field without any padding.
+    let uri = format!("https://example.com/{}é", "a".repeat(MAX_URI_LENGTH - 22));
+    assert_eq!(uri.len(), MAX_URI_LENGTH);
+    assert_eq!(data("Degen #1234", &uri).sanitized().uri, uri);
+    // The same character just before the padding is kept whole.
+    let puffed = format!("{:\0<width$}", "https://example.com/é", width = MAX_URI_LENGTH);
+    assert_eq!(data("Degen #1234", &puffed).sanitized().uri, "https://example.com/é");
+}
+
+#[test]
+fn sanitized_data_v2() {
+    let data = DataV2 {
+        name: "Degen #1234\0\0".to_string(),
+        symbol: "DGN\0".to_string(),
+        uri: "https://arweave.net/degen-1234\0".to_string(),
+        seller_fee_basis_points: 500,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+    let sanitized = data.sanitized();
+    assert_eq!((sanitized.name.as_str(), sanitized.symbol.as_str()), ("Degen #1234", "DGN"));
+    assert_eq!(sanitized.uri, "https://arweave.net/degen-1234");
+}
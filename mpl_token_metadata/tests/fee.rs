@@ -0,0 +1,31 @@
+use mpl_token_metadata_substream::mpl_token_metadata::state::fee::{
+    has_fee_flag, FEE_FLAG_CLEARED, FEE_FLAG_SET, METADATA_FEE_FLAG_OFFSET,
+};
+use mpl_token_metadata_substream::mpl_token_metadata::state::MAX_METADATA_LEN;
+
+fn metadata_account(len: usize, fee_flag: u8) -> Vec<u8> {
+    let mut data = vec![4; len];
+    data[len - 1] = fee_flag;
+    data
+}
+
+#[test]
+fn fee_flag_set() {
+    assert!(has_fee_flag(&metadata_account(MAX_METADATA_LEN, FEE_FLAG_SET)));
+    assert_eq!(metadata_account(MAX_METADATA_LEN, FEE_FLAG_SET)[METADATA_FEE_FLAG_OFFSET], FEE_FLAG_SET);
+    // Resized accounts keep the flag as their last byte.
+    assert!(has_fee_flag(&metadata_account(MAX_METADATA_LEN + 100, FEE_FLAG_SET)));
+}
+
+#[test]
+fn fee_flag_cleared() {
+    assert!(!has_fee_flag(&metadata_account(MAX_METADATA_LEN, FEE_FLAG_CLEARED)));
+    assert!(!has_fee_flag(&metadata_account(MAX_METADATA_LEN + 100, FEE_FLAG_CLEARED)));
+}
+
+#[test]
+fn fee_flag_of_short_accounts() {
+    assert!(!has_fee_flag(&metadata_account(METADATA_FEE_FLAG_OFFSET, FEE_FLAG_SET)));
+    assert!(!has_fee_flag(&[FEE_FLAG_SET]));
+    assert!(!has_fee_flag(&[]));
+}
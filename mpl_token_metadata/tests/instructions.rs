@@ -0,0 +1,200 @@
+use test_support::{address, key, TransactionBuilder};
+use mpl_token_metadata_substream::mpl_token_metadata::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
+use mpl_token_metadata_substream::mpl_token_metadata::state::{self, AssetData, PrintSupply};
+use mpl_token_metadata_substream::parse_transaction;
+use mpl_token_metadata_substream::pb::mpl_token_metadata::{self as pb, BurnNftEvent, CreateEvent, MigrateEvent, TransferEvent};
+use mpl_token_metadata_substream::pb::mpl_token_metadata::mpl_token_metadata_event::Event;
+use substreams_solana_utils::pubkey::Pubkey;
+
+const BURN_NFT: u8 = 29;
+const CREATE: u8 = 42;
+const MIGRATE: u8 = 48;
+const TRANSFER: u8 = 49;
+
+/// Account keys `[1; 32]` to `[13; 32]`, at indexes 0 to 12, then the Token Metadata program,
+/// which also stands for the optional accounts left out.
+const METADATA_PROGRAM: u8 = 13;
+
+fn builder() -> TransactionBuilder {
+    let mut account_keys: Vec<Vec<u8>> = (1..=13).map(key).collect();
+    account_keys.push(MPL_TOKEN_METADATA_PROGRAM_ID.0.to_vec());
+    TransactionBuilder::new(account_keys)
+}
+
+fn single_event(builder: TransactionBuilder) -> Event {
+    let events = parse_transaction(&builder.build()).unwrap();
+    assert_eq!(events.len(), 1);
+    events[0].event.clone().unwrap()
+}
+
+fn programmable_asset_data() -> AssetData {
+    AssetData {
+        seller_fee_basis_points: 500,
+        creators: Some(vec![state::Creator { address: Pubkey([1; 32]), verified: true, share: 100 }]),
+        rule_set: Some(Pubkey([9; 32])),
+        ..AssetData::new(
+            state::TokenStandard::ProgrammableNonFungible,
+            "Programmable #1".to_string(),
+            "PNFT".to_string(),
+            "https://example.com/1.json".to_string(),
+        )
+    }
+}
+
+/// `CreateArgs::V1` for `asset_data`, with 0 decimals and no prints.
+fn create_data(asset_data: &AssetData) -> Vec<u8> {
+    [
+        vec![CREATE, 0],
+        borsh::to_vec(asset_data).unwrap(),
+        borsh::to_vec(&Some(0u8)).unwrap(),
+        borsh::to_vec(&Some(PrintSupply::Zero)).unwrap(),
+    ].concat()
+}
+
+// CreateV1 accounts: metadata, master edition, mint, authority, payer, update authority, then
+// the System program, Instructions sysvar and Token program.
+const CREATE_ACCOUNTS: [u8; 9] = [1, 2, 3, 0, 0, 0, 4, 5, 6];
+
+#[test]
+fn create_programmable_nft() {
+    let asset_data = programmable_asset_data();
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &CREATE_ACCOUNTS, create_data(&asset_data));
+    assert_eq!(single_event(transaction), Event::Create(CreateEvent {
+        metadata: address(2),
+        master_edition: Some(address(3)),
+        mint: address(4),
+        authority: address(1),
+        payer: address(1),
+        update_authority: address(1),
+        asset_data: Some(pb::AssetData {
+            name: "Programmable #1".to_string(),
+            symbol: "PNFT".to_string(),
+            uri: "https://example.com/1.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: vec![pb::Creator { address: address(1), verified: true, share: 100 }],
+            primary_sale_happened: false,
+            is_mutable: true,
+            token_standard: pb::TokenStandard::ProgrammableNonFungible.into(),
+            collection: None,
+            uses: None,
+            collection_details: None,
+            rule_set: Some(address(9)),
+        }),
+        decimals: Some(0),
+        print_supply: Some(pb::PrintSupply { max_supply: Some(0) }),
+        valid: true,
+        validation_error: None,
+    }));
+}
+
+#[test]
+fn create_with_invalid_creator_shares() {
+    let mut asset_data = programmable_asset_data();
+    asset_data.creators = Some(vec![state::Creator { address: Pubkey([1; 32]), verified: true, share: 90 }]);
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &CREATE_ACCOUNTS, create_data(&asset_data));
+    let Event::Create(event) = single_event(transaction) else { panic!("Expected Create") };
+    assert!(!event.valid);
+    assert!(event.validation_error.is_some());
+}
+
+#[test]
+fn create_without_master_edition() {
+    let asset_data = programmable_asset_data();
+    let accounts = [1, METADATA_PROGRAM, 3, 0, 0, 0, 4, 5, 6];
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &accounts, create_data(&asset_data));
+    let Event::Create(event) = single_event(transaction) else { panic!("Expected Create") };
+    assert_eq!(event.master_edition, None);
+}
+
+#[test]
+fn transfer_programmable_nft() {
+    // TransferArgs::V1 of 1 token without authorization data.
+    let data = [vec![TRANSFER, 0], 1u64.to_le_bytes().to_vec(), vec![0]].concat();
+    // token, token owner, destination, destination owner, mint, metadata, edition, owner and
+    // destination token records, authority, payer, then the System program, Instructions sysvar,
+    // Token and Associated Token programs, and no authorization rules program nor rule set.
+    let accounts = [1, 0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 9, 10, 11, 12, METADATA_PROGRAM, METADATA_PROGRAM];
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &accounts, data);
+    assert_eq!(single_event(transaction), Event::Transfer(TransferEvent {
+        token: address(2),
+        token_owner: address(1),
+        destination: address(3),
+        destination_owner: address(4),
+        mint: address(5),
+        metadata: address(6),
+        owner_token_record: Some(address(8)),
+        destination_token_record: Some(address(9)),
+        authority: address(1),
+        payer: address(1),
+        amount: 1,
+    }));
+}
+
+#[test]
+fn burn_nft() {
+    // metadata, owner, mint, token account, master edition, Token program and the metadata of
+    // the collection the NFT is verified in.
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &[1, 0, 2, 3, 4, 5, 6], vec![BURN_NFT]);
+    assert_eq!(single_event(transaction), Event::BurnNft(BurnNftEvent {
+        metadata: address(2),
+        owner: address(1),
+        mint: address(3),
+        token: address(4),
+        master_edition: address(5),
+        collection_metadata: Some(address(7)),
+    }));
+}
+
+#[test]
+fn burn_nft_outside_a_collection() {
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &[1, 0, 2, 3, 4, 5], vec![BURN_NFT]);
+    let Event::BurnNft(event) = single_event(transaction) else { panic!("Expected BurnNft") };
+    assert_eq!(event.collection_metadata, None);
+}
+
+#[test]
+fn burn_nft_missing_accounts_is_skipped() {
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &[1, 0, 2, 3], vec![BURN_NFT]).build();
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
+
+// Migrate accounts: metadata, edition, token, token owner, mint, payer, authority, collection
+// metadata, then the delegate record, token record, System program, Instructions sysvar, SPL
+// Token program, authorization rules program and rule set.
+const MIGRATE_ACCOUNTS: [u8; 15] = [1, 2, 3, 4, 5, 0, 0, 6, 7, 8, 9, 10, 11, METADATA_PROGRAM, METADATA_PROGRAM];
+
+#[test]
+fn migrate_to_programmable() {
+    // MigrateArgs::V1 to ProgrammableV1 with rule set `[9; 32]`.
+    let data = [vec![MIGRATE, 0, 1, 1], vec![9; 32]].concat();
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &MIGRATE_ACCOUNTS, data);
+    assert_eq!(single_event(transaction), Event::Migrate(MigrateEvent {
+        metadata: address(2),
+        mint: address(6),
+        authority: address(1),
+        collection_metadata: address(7),
+        migration_type: Some("ProgrammableV1".to_string()),
+        rule_set: Some(address(9)),
+    }));
+}
+
+#[test]
+fn migrate_without_args() {
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &MIGRATE_ACCOUNTS, vec![MIGRATE]);
+    let Event::Migrate(event) = single_event(transaction) else { panic!("Expected Migrate") };
+    assert_eq!(event.migration_type, None);
+    assert_eq!(event.rule_set, None);
+}
+
+#[test]
+fn migrate_unknown_migration_type_is_skipped() {
+    let transaction = builder().instruction(METADATA_PROGRAM as u32, &MIGRATE_ACCOUNTS, vec![MIGRATE, 0, 2, 0]).build();
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
+
+#[test]
+fn transaction_without_meta_is_skipped() {
+    let mut transaction = builder().instruction(METADATA_PROGRAM as u32, &[1, 0, 2, 3, 4, 5], vec![BURN_NFT]).build();
+    transaction.meta = None;
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
@@ -0,0 +1,185 @@
+//! Golden tests for the legacy instructions, on transactions laid out like the 2021–2022 mints:
+//! a Candy Machine v2 mint, whose CPIs create the metadata and master edition, set the primary
+//! sale and verify the collection, and a creator signing an existing NFT before its collection
+//! is verified.
+use test_support::{address, key, TransactionBuilder};
+use mpl_token_metadata_substream::mpl_token_metadata::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
+use mpl_token_metadata_substream::mpl_token_metadata::state::{self, DataV2};
+use mpl_token_metadata_substream::parse_transaction;
+use mpl_token_metadata_substream::pb::mpl_token_metadata::{
+    self as pb,
+    CreateMasterEditionV3Event,
+    CreateMetadataAccountV3Event,
+    SetAndVerifyCollectionEvent,
+    SignMetadataEvent,
+    UnverifyCollectionEvent,
+    UpdateEvent,
+    VerifyCollectionEvent,
+};
+use mpl_token_metadata_substream::pb::mpl_token_metadata::mpl_token_metadata_event::Event;
+use substreams_solana_utils::pubkey::Pubkey;
+
+const SIGN_METADATA: u8 = 7;
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+const CREATE_MASTER_EDITION_V3: u8 = 17;
+const VERIFY_COLLECTION: u8 = 18;
+const UNVERIFY_COLLECTION: u8 = 22;
+const SET_AND_VERIFY_COLLECTION: u8 = 25;
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+// Account indexes: the minter (fee payer), the NFT metadata, mint and master edition, the
+// update authority, the collection mint, metadata, master edition and authority record, the
+// System program, Rent sysvar and Token program, the artist, then the Token Metadata and Candy
+// Machine programs.
+const PAYER: u8 = 0;
+const METADATA: u8 = 1;
+const MINT: u8 = 2;
+const EDITION: u8 = 3;
+const UPDATE_AUTHORITY: u8 = 4;
+const COLLECTION_MINT: u8 = 5;
+const COLLECTION_METADATA: u8 = 6;
+const COLLECTION_MASTER_EDITION: u8 = 7;
+const COLLECTION_AUTHORITY_RECORD: u8 = 8;
+const SYSTEM_PROGRAM: u8 = 9;
+const RENT: u8 = 10;
+const TOKEN_PROGRAM: u8 = 11;
+const ARTIST: u8 = 12;
+const METADATA_PROGRAM: u32 = 13;
+const CANDY_MACHINE_PROGRAM: u32 = 14;
+
+fn builder() -> TransactionBuilder {
+    let mut account_keys: Vec<Vec<u8>> = (1..=13).map(key).collect();
+    account_keys.push(MPL_TOKEN_METADATA_PROGRAM_ID.0.to_vec());
+    account_keys.push(key(20));
+    TransactionBuilder::new(account_keys)
+}
+
+fn events(builder: TransactionBuilder) -> Vec<Event> {
+    parse_transaction(&builder.build()).unwrap().into_iter().map(|event| event.event.unwrap()).collect()
+}
+
+/// The data minted by the Candy Machine: its creator PDA, verified with no share, and the artist.
+fn candy_machine_data() -> DataV2 {
+    DataV2 {
+        name: "Degen #1234".to_string(),
+        symbol: "DGN".to_string(),
+        uri: "https://arweave.net/degen-1234".to_string(),
+        seller_fee_basis_points: 500,
+        creators: Some(vec![
+            state::Creator { address: Pubkey([5; 32]), verified: true, share: 0 },
+            state::Creator { address: Pubkey([13; 32]), verified: false, share: 100 },
+        ]),
+        collection: None,
+        uses: None,
+    }
+}
+
+#[test]
+fn candy_machine_v2_mint() {
+    // CreateMetadataAccountArgsV3: mutable, not a collection parent.
+    let create_metadata = [vec![CREATE_METADATA_ACCOUNT_V3], borsh::to_vec(&candy_machine_data()).unwrap(), vec![1, 0]].concat();
+    // CreateMasterEditionArgs with a max supply of 0.
+    let create_master_edition = [vec![CREATE_MASTER_EDITION_V3, 1], 0u64.to_le_bytes().to_vec()].concat();
+    // UpdateMetadataAccountArgsV2 only setting primary_sale_happened.
+    let update_metadata = vec![UPDATE_METADATA_ACCOUNT_V2, 0, 0, 1, 1, 0];
+    let transaction = builder()
+        .instruction(CANDY_MACHINE_PROGRAM, &[PAYER, MINT, METADATA_PROGRAM as u8], vec![0xd3, 0x39, 0x06, 0xa7, 0x0f, 0xdb, 0x23, 0xfb])
+        .inner_instruction(METADATA_PROGRAM, &[METADATA, MINT, PAYER, PAYER, UPDATE_AUTHORITY, SYSTEM_PROGRAM, RENT], create_metadata, 2)
+        .inner_instruction(METADATA_PROGRAM, &[EDITION, MINT, UPDATE_AUTHORITY, PAYER, PAYER, METADATA, TOKEN_PROGRAM, SYSTEM_PROGRAM, RENT], create_master_edition, 2)
+        .inner_instruction(METADATA_PROGRAM, &[METADATA, UPDATE_AUTHORITY], update_metadata, 2)
+        .inner_instruction(
+            METADATA_PROGRAM,
+            &[METADATA, UPDATE_AUTHORITY, PAYER, UPDATE_AUTHORITY, COLLECTION_MINT, COLLECTION_METADATA, COLLECTION_MASTER_EDITION, COLLECTION_AUTHORITY_RECORD],
+            vec![SET_AND_VERIFY_COLLECTION],
+            2,
+        );
+
+    assert_eq!(events(transaction), vec![
+        Event::CreateMetadataAccountV3(CreateMetadataAccountV3Event {
+            metadata: address(2),
+            mint: address(3),
+            update_authority: address(5),
+            data: Some(pb::DataV2 {
+                name: "Degen #1234".to_string(),
+                symbol: "DGN".to_string(),
+                uri: "https://arweave.net/degen-1234".to_string(),
+                seller_fee_basis_points: 500,
+                creators: vec![
+                    pb::Creator { address: address(5), verified: true, share: 0 },
+                    pb::Creator { address: address(13), verified: false, share: 100 },
+                ],
+                collection: None,
+                uses: None,
+            }),
+            is_mutable: true,
+            collection_details: None,
+        }),
+        Event::CreateMasterEditionV3(CreateMasterEditionV3Event {
+            edition: address(4),
+            mint: address(3),
+            update_authority: address(5),
+            mint_authority: address(1),
+            payer: address(1),
+            metadata: address(2),
+            max_supply: Some(0),
+        }),
+        Event::Update(UpdateEvent {
+            authority: address(5),
+            delegate_record: None,
+            token: None,
+            mint: String::new(),
+            metadata: address(2),
+            payer: String::new(),
+            update_type: "UpdateMetadataAccountV2".to_string(),
+            new_update_authority: None,
+            data: None,
+            primary_sale_happened: Some(true),
+            is_mutable: None,
+            legacy: true,
+            collection: None,
+            uses: None,
+        }),
+        Event::SetAndVerifyCollection(SetAndVerifyCollectionEvent {
+            metadata: address(2),
+            collection_authority: address(5),
+            payer: address(1),
+            update_authority: address(5),
+            collection_mint: address(6),
+            collection: address(7),
+            collection_master_edition: address(8),
+            collection_authority_record: Some(address(9)),
+        }),
+    ]);
+}
+
+#[test]
+fn creator_sign_and_collection_verification() {
+    let transaction = builder()
+        .instruction(METADATA_PROGRAM, &[METADATA, ARTIST], vec![SIGN_METADATA])
+        .instruction(METADATA_PROGRAM, &[METADATA, UPDATE_AUTHORITY, PAYER, COLLECTION_MINT, COLLECTION_METADATA, COLLECTION_MASTER_EDITION], vec![VERIFY_COLLECTION])
+        .instruction(METADATA_PROGRAM, &[METADATA, UPDATE_AUTHORITY, COLLECTION_MINT, COLLECTION_METADATA, COLLECTION_MASTER_EDITION], vec![UNVERIFY_COLLECTION]);
+
+    assert_eq!(events(transaction), vec![
+        Event::SignMetadata(SignMetadataEvent {
+            metadata: address(2),
+            creator: address(13),
+        }),
+        Event::VerifyCollection(VerifyCollectionEvent {
+            metadata: address(2),
+            collection_authority: address(5),
+            payer: address(1),
+            collection_mint: address(6),
+            collection: address(7),
+            collection_master_edition: address(8),
+            collection_authority_record: None,
+        }),
+        Event::UnverifyCollection(UnverifyCollectionEvent {
+            metadata: address(2),
+            collection_authority: address(5),
+            collection_mint: address(6),
+            collection: address(7),
+            collection_master_edition: address(8),
+            collection_authority_record: None,
+        }),
+    ]);
+}
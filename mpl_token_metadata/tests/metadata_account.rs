@@ -0,0 +1,171 @@
+//! `Metadata::from_bytes` on metadata accounts of each generation, laid out byte by byte.
+use mpl_token_metadata_substream::mpl_token_metadata::state::{
+    Collection, CollectionDetails, Creator, Key, Metadata, ProgrammableConfig, TokenStandard, MAX_METADATA_LEN,
+    MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// A Borsh string holding `value` followed by NUL bytes up to `capacity`, as the program puffs
+/// name, symbol and uri.
+fn puffed(value: &str, capacity: usize) -> Vec<u8> {
+    let mut bytes = value.as_bytes().to_vec();
+    bytes.resize(capacity, 0);
+    [(capacity as u32).to_le_bytes().to_vec(), bytes].concat()
+}
+
+fn creator(n: u8, verified: bool, share: u8) -> Vec<u8> {
+    [vec![n; 32], vec![verified as u8, share]].concat()
+}
+
+/// Every field up to `edition_nonce`, as written by the program in 2021: update authority
+/// `[5; 32]`, mint `[3; 32]`, puffed name, symbol and uri, 5% royalties to a verified creator
+/// `[5; 32]` and an unverified one `[13; 32]`, sold, mutable, edition nonce 254.
+fn metadata_2021() -> Vec<u8> {
+    [
+        vec![Key::MetadataV1 as u8],
+        vec![5; 32],
+        vec![3; 32],
+        puffed("Degen #1234", MAX_NAME_LENGTH),
+        puffed("DGN", MAX_SYMBOL_LENGTH),
+        puffed("https://arweave.net/degen-1234", MAX_URI_LENGTH),
+        500u16.to_le_bytes().to_vec(),
+        vec![1],
+        2u32.to_le_bytes().to_vec(),
+        creator(5, true, 0),
+        creator(13, false, 100),
+        vec![1, 1],
+        vec![1, 254],
+    ].concat()
+}
+
+/// A current programmable NFT, with a programmable config of rule set `[9; 32]`.
+fn metadata_programmable() -> Vec<u8> {
+    metadata_with_config(TokenStandard::ProgrammableNonFungible, [vec![1, 0, 1], vec![9; 32]].concat())
+}
+
+/// The 2021 layout followed by `token_standard`, the verified collection `[7; 32]`, no uses nor
+/// collection details, and the given programmable config bytes.
+fn metadata_with_config(token_standard: TokenStandard, programmable_config: Vec<u8>) -> Vec<u8> {
+    metadata_with_details(token_standard, vec![0], programmable_config)
+}
+
+/// Same as `metadata_with_config`, with the given collection details bytes.
+fn metadata_with_details(token_standard: TokenStandard, collection_details: Vec<u8>, programmable_config: Vec<u8>) -> Vec<u8> {
+    [
+        metadata_2021(),
+        vec![1, token_standard as u8],
+        [vec![1, 1], vec![7; 32]].concat(),
+        vec![0],
+        collection_details,
+        programmable_config,
+    ].concat()
+}
+
+fn padded(mut data: Vec<u8>) -> Vec<u8> {
+    data.resize(MAX_METADATA_LEN, 0);
+    data
+}
+
+fn assert_2021_fields(metadata: &Metadata) {
+    assert_eq!(metadata.key, Key::MetadataV1);
+    assert_eq!(metadata.update_authority, Pubkey([5; 32]));
+    assert_eq!(metadata.mint, Pubkey([3; 32]));
+    assert_eq!(metadata.name_trimmed(), "Degen #1234");
+    assert_eq!(metadata.data.name.len(), MAX_NAME_LENGTH);
+    assert_eq!(metadata.symbol_trimmed(), "DGN");
+    assert_eq!(metadata.uri_trimmed(), "https://arweave.net/degen-1234");
+    assert_eq!(metadata.data.seller_fee_basis_points, 500);
+    assert_eq!(metadata.data.creators, Some(vec![
+        Creator { address: Pubkey([5; 32]), verified: true, share: 0 },
+        Creator { address: Pubkey([13; 32]), verified: false, share: 100 },
+    ]));
+    assert!(metadata.primary_sale_happened);
+    assert!(metadata.is_mutable);
+    assert_eq!(metadata.edition_nonce, Some(254));
+}
+
+#[test]
+fn metadata_2021_without_newer_fields() {
+    let metadata = Metadata::from_bytes(&metadata_2021()).unwrap();
+    assert_2021_fields(&metadata);
+    assert_eq!(metadata.token_standard, None);
+    assert_eq!(metadata.collection, None);
+    assert_eq!(metadata.uses, None);
+    assert_eq!(metadata.collection_details, None);
+    assert_eq!(metadata.programmable_config, None);
+}
+
+#[test]
+fn metadata_padded_to_679_bytes() {
+    let data = padded(metadata_2021());
+    assert_eq!(data.len(), 679);
+    let metadata = Metadata::from_bytes(&data).unwrap();
+    assert_2021_fields(&metadata);
+    assert_eq!(metadata.token_standard, None);
+    assert_eq!(metadata.collection, None);
+    assert_eq!(metadata.programmable_config, None);
+}
+
+#[test]
+fn metadata_programmable_nft() {
+    let metadata = Metadata::from_bytes(&padded(metadata_programmable())).unwrap();
+    assert_2021_fields(&metadata);
+    assert_eq!(metadata.token_standard, Some(TokenStandard::ProgrammableNonFungible));
+    assert_eq!(metadata.collection, Some(Collection { verified: true, key: Pubkey([7; 32]) }));
+    assert_eq!(metadata.uses, None);
+    assert_eq!(metadata.collection_details, None);
+    assert_eq!(metadata.programmable_config, Some(ProgrammableConfig::V1 { rule_set: Some(Pubkey([9; 32])) }));
+}
+
+#[test]
+fn metadata_programmable_config_without_rule_set() {
+    let data = padded(metadata_with_config(TokenStandard::ProgrammableNonFungible, vec![1, 0, 0]));
+    let metadata = Metadata::from_bytes(&data).unwrap();
+    assert_eq!(metadata.programmable_config, Some(ProgrammableConfig::V1 { rule_set: None }));
+}
+
+#[test]
+fn metadata_without_programmable_config() {
+    let data = padded(metadata_with_config(TokenStandard::NonFungible, vec![0]));
+    let metadata = Metadata::from_bytes(&data).unwrap();
+    assert_eq!(metadata.token_standard, Some(TokenStandard::NonFungible));
+    assert_eq!(metadata.collection, Some(Collection { verified: true, key: Pubkey([7; 32]) }));
+    assert_eq!(metadata.programmable_config, None);
+}
+
+#[test]
+fn metadata_collection_details_v2() {
+    // A collection NFT created since size tracking was deprecated: `V2` and its 8 bytes of
+    // padding, followed by the programmable config.
+    let details = [vec![1], vec![0; 8]].concat();
+    let config = [vec![1, 0, 1], vec![9; 32]].concat();
+    let data = padded(metadata_with_details(TokenStandard::ProgrammableNonFungible, details, config));
+    let metadata = Metadata::from_bytes(&data).unwrap();
+    assert_2021_fields(&metadata);
+    assert_eq!(metadata.collection_details, Some(CollectionDetails::V2 { padding: [0; 8] }));
+    assert_eq!(metadata.collection_details.as_ref().unwrap().size(), None);
+    assert_eq!(metadata.programmable_config, Some(ProgrammableConfig::V1 { rule_set: Some(Pubkey([9; 32])) }));
+}
+
+#[test]
+#[allow(deprecated)]
+fn metadata_collection_details_v1() {
+    let details = [vec![0], 42u64.to_le_bytes().to_vec()].concat();
+    let data = padded(metadata_with_details(TokenStandard::NonFungible, details, vec![0]));
+    let metadata = Metadata::from_bytes(&data).unwrap();
+    assert_eq!(metadata.collection_details, Some(CollectionDetails::V1 { size: 42 }));
+    assert_eq!(metadata.collection_details.as_ref().unwrap().size(), Some(42));
+    assert_eq!(metadata.programmable_config, None);
+}
+
+#[test]
+fn metadata_with_corrupted_creators() {
+    // A creators vector claiming 200 entries, more than the program allows, as left by resizes.
+    let mut data = metadata_2021();
+    let creators_offset = 1 + 32 + 32 + (4 + MAX_NAME_LENGTH) + (4 + MAX_SYMBOL_LENGTH) + (4 + MAX_URI_LENGTH) + 2;
+    data[creators_offset + 1..creators_offset + 5].copy_from_slice(&200u32.to_le_bytes());
+    let metadata = Metadata::from_bytes(&padded(data)).unwrap();
+    assert_eq!(metadata.name_trimmed(), "Degen #1234");
+    assert_eq!(metadata.data.creators, None);
+    assert_eq!(metadata.edition_nonce, None);
+}
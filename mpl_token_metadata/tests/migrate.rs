@@ -0,0 +1,46 @@
+//! Borsh layouts of `MigrationType` and the `Migrate` args.
+use mpl_token_metadata_substream::mpl_token_metadata::error::ProgramError;
+use mpl_token_metadata_substream::mpl_token_metadata::instruction::MigrateArgs;
+use mpl_token_metadata_substream::mpl_token_metadata::state::MigrationType;
+use substreams_solana_utils::pubkey::Pubkey;
+
+#[test]
+fn migration_type_fixtures() {
+    for (migration_type, bytes) in [(MigrationType::CollectionV1, [0]), (MigrationType::ProgrammableV1, [1])] {
+        assert_eq!(borsh::to_vec(&migration_type).unwrap(), bytes);
+        assert_eq!(MigrationType::from_bytes(&bytes), Ok(migration_type.clone()));
+        assert_eq!(borsh::from_slice::<MigrationType>(&bytes).unwrap(), migration_type);
+    }
+}
+
+#[test]
+fn migrate_args_fixtures() {
+    // Version 0, ProgrammableV1, rule set `[9; 32]`.
+    let data = [vec![0, 1, 1], vec![9; 32]].concat();
+    assert_eq!(
+        MigrateArgs::from_bytes(&data),
+        Ok(Some(MigrateArgs::V1 { migration_type: MigrationType::ProgrammableV1, rule_set: Some(Pubkey([9; 32])) })),
+    );
+    assert_eq!(
+        MigrateArgs::from_bytes(&[0, 0, 0]),
+        Ok(Some(MigrateArgs::V1 { migration_type: MigrationType::CollectionV1, rule_set: None })),
+    );
+    // The current instruction, reduced to its discriminator.
+    assert_eq!(MigrateArgs::from_bytes(&[]), Ok(None));
+}
+
+#[test]
+fn unknown_migration_type() {
+    assert_eq!(MigrationType::try_from_u8(2), Err(ProgramError::UnknownMigrationType(2)));
+    assert_eq!(MigrationType::from_bytes(&[200]), Err(ProgramError::UnknownMigrationType(200)));
+    assert_eq!(MigrateArgs::from_bytes(&[0, 5, 0]), Err(ProgramError::UnknownMigrationType(5)));
+    assert_eq!(ProgramError::UnknownMigrationType(5).to_string(), "Unknown migration type 5");
+}
+
+#[test]
+fn malformed_migration_type() {
+    assert_eq!(MigrationType::from_bytes(&[]), Err(ProgramError::InvalidAccountData));
+    assert_eq!(MigrationType::from_bytes(&[0, 0]), Err(ProgramError::InvalidAccountData));
+    assert_eq!(MigrateArgs::from_bytes(&[1, 0, 0]), Err(ProgramError::InvalidInstructionData));
+    assert_eq!(MigrateArgs::from_bytes(&[0]), Err(ProgramError::InvalidInstructionData));
+}
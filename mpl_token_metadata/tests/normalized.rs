@@ -0,0 +1,110 @@
+//! Trimmed accessors and `Metadata::normalized`, over generated names, symbols and uris.
+use mpl_token_metadata_substream::mpl_token_metadata::state::{
+    Creator, Data, Metadata, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// Characters of 1 to 4 bytes, spaces and NULs, so that generated values end on multi-byte
+/// characters and hold NULs inside as well as at the end.
+const ALPHABET: [char; 7] = ['a', 'Z', ' ', 'é', '€', '🦀', '\0'];
+
+/// xorshift64, for reproducible cases without a property testing dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Up to `capacity` bytes of random characters, puffed with NULs to `capacity` as the
+    /// program stores them, or left unpuffed.
+    fn value(&mut self, capacity: usize) -> String {
+        let mut value = String::new();
+        let len = self.next() as usize % (capacity + 1);
+        loop {
+            let c = ALPHABET[self.next() as usize % ALPHABET.len()];
+            if value.len() + c.len_utf8() > len {
+                break;
+            }
+            value.push(c);
+        }
+        if self.next() % 2 == 0 {
+            value.extend(std::iter::repeat('\0').take(capacity - value.len()));
+        }
+        value
+    }
+}
+
+fn metadata(rng: &mut Rng) -> Metadata {
+    Metadata {
+        data: Data {
+            name: rng.value(MAX_NAME_LENGTH),
+            symbol: rng.value(MAX_SYMBOL_LENGTH),
+            uri: rng.value(MAX_URI_LENGTH),
+            seller_fee_basis_points: 500,
+            creators: Some(vec![
+                Creator { address: Pubkey([5; 32]), verified: true, share: 50 },
+                Creator { address: Pubkey([13; 32]), verified: false, share: 50 },
+                Creator { address: Pubkey([5; 32]), verified: false, share: 0 },
+            ]),
+        },
+        primary_sale_happened: rng.next() % 2 == 0,
+        is_mutable: rng.next() % 2 == 0,
+        ..Metadata::default()
+    }
+}
+
+/// `trimmed` is `value` without its trailing NULs: a prefix of it ending on a character
+/// boundary, so valid UTF-8, with only NULs after it.
+fn assert_trimmed(value: &str, trimmed: &str) {
+    assert!(value.is_char_boundary(trimmed.len()), "{:?} splits a character of {:?}", trimmed, value);
+    assert_eq!(&value[..trimmed.len()], trimmed);
+    assert!(std::str::from_utf8(trimmed.as_bytes()).is_ok());
+    assert!(!trimmed.ends_with('\0'));
+    assert!(value[trimmed.len()..].chars().all(|c| c == '\0'));
+}
+
+#[test]
+fn trimming_keeps_valid_utf8() {
+    let mut rng = Rng(0x5eed);
+    for _ in 0..1000 {
+        let metadata = metadata(&mut rng);
+        assert_trimmed(&metadata.data.name, metadata.name_trimmed());
+        assert_trimmed(&metadata.data.symbol, metadata.symbol_trimmed());
+        assert_trimmed(&metadata.data.uri, metadata.uri_trimmed());
+    }
+}
+
+#[test]
+fn trimming_is_idempotent() {
+    let mut rng = Rng(0x5eed);
+    for _ in 0..1000 {
+        let metadata = metadata(&mut rng);
+        let normalized = metadata.normalized();
+        assert_eq!(normalized.data.name, metadata.name_trimmed());
+        assert_eq!(normalized.data.symbol, metadata.symbol_trimmed());
+        assert_eq!(normalized.data.uri, metadata.uri_trimmed());
+        assert_eq!(normalized.name_trimmed(), normalized.data.name);
+        assert_eq!(normalized.symbol_trimmed(), normalized.data.symbol);
+        assert_eq!(normalized.uri_trimmed(), normalized.data.uri);
+        assert_eq!(normalized.normalized(), normalized);
+    }
+}
+
+#[test]
+fn normalized_dedups_creators_and_keeps_flags() {
+    let mut rng = Rng(0x5eed);
+    for _ in 0..100 {
+        let metadata = metadata(&mut rng);
+        let normalized = metadata.normalized();
+        assert_eq!(normalized.data.creators, Some(vec![
+            Creator { address: Pubkey([5; 32]), verified: true, share: 50 },
+            Creator { address: Pubkey([13; 32]), verified: false, share: 50 },
+        ]));
+        assert_eq!(normalized.primary_sale_happened, metadata.primary_sale_happened);
+        assert_eq!(normalized.is_mutable, metadata.is_mutable);
+    }
+}
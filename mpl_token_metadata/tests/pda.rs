@@ -0,0 +1,88 @@
+use mpl_token_metadata_substream::mpl_token_metadata::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
+use mpl_token_metadata_substream::mpl_token_metadata::instruction::{HolderDelegateRole, MetadataDelegateRole};
+use mpl_token_metadata_substream::mpl_token_metadata::pda::{find_master_edition_account, find_metadata_account};
+use mpl_token_metadata_substream::mpl_token_metadata::state::{HolderDelegateRecord, Key, MetadataDelegateRecord};
+use spl_token_substream::FromBase58;
+use substreams_solana_utils::pubkey::Pubkey;
+
+fn pubkey(address: &str) -> Pubkey {
+    Pubkey::from_base58(address).unwrap()
+}
+
+// Metadata and master edition accounts of mainnet mints, with their bumps.
+
+#[test]
+fn wrapped_sol() {
+    let mint = pubkey("So11111111111111111111111111111111111111112");
+    assert_eq!(find_metadata_account(&mint), (pubkey("6dM4TqWyWJsbx7obrdLcviBkTafD5E8av61zfU6jq57X"), 255));
+    assert_eq!(find_master_edition_account(&mint), (pubkey("7r1W5yu5i7ev1wPNGsNuRLcdKW1sCy2x4rwyQkdi9ew2"), 254));
+}
+
+#[test]
+fn usdc() {
+    let mint = pubkey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+    assert_eq!(find_metadata_account(&mint), (pubkey("5x38Kp4hvdomTCnCrAny4UtMUt5rQBdB6px2K1Ui45Wq"), 255));
+    assert_eq!(find_master_edition_account(&mint), (pubkey("A7FGB2kzjpDPRLMeqRLgW9XZ3JQ2RYRL4w5kUZv64ZB"), 252));
+}
+
+#[test]
+fn bonk() {
+    // Bumps below 255: the first candidates are on the curve.
+    let mint = pubkey("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263");
+    assert_eq!(find_metadata_account(&mint), (pubkey("FDZZbyY9XGpL3CNKUZxLk3wFTTQYL3TkDiDzqxrizcPN"), 250));
+    assert_eq!(find_master_edition_account(&mint), (pubkey("HkNjWCgs7PYfYb7vo9nVGRvh5PCCvQv3q4oaQUuKD7Pt"), 255));
+}
+
+/// A delegate record of mint `[3; 32]`, update authority (or holder) `[5; 32]` and delegate
+/// `[7; 32]`, with the bump of its address.
+fn metadata_delegate_record(bump: u8) -> MetadataDelegateRecord {
+    MetadataDelegateRecord {
+        key: Key::MetadataDelegate,
+        bump,
+        mint: Pubkey([3; 32]),
+        delegate: Pubkey([7; 32]),
+        update_authority: Pubkey([5; 32]),
+    }
+}
+
+// Delegate record addresses, derived from the seeds ["metadata", program id, mint, role seed,
+// update authority, delegate] by a separate implementation of `find_program_address`.
+
+#[test]
+fn collection_delegate_role() {
+    let record = metadata_delegate_record(252);
+    let address = pubkey("BBk9Z3jYxmtCfF3ENTcRUZfKeGppZJcM3ZmfhtnrEQXp");
+    assert_eq!(record.resolve_role(&MPL_TOKEN_METADATA_PROGRAM_ID, &address), Some(MetadataDelegateRole::Collection));
+}
+
+#[test]
+fn data_delegate_role() {
+    let record = metadata_delegate_record(254);
+    let address = pubkey("9SkJ6etWYr1JkxkX17p6wBgy6REw7PMuS5BXMPZdoec7");
+    assert_eq!(record.resolve_role(&MPL_TOKEN_METADATA_PROGRAM_ID, &address), Some(MetadataDelegateRole::Data));
+}
+
+#[test]
+fn unresolved_delegate_role() {
+    let address = pubkey("BBk9Z3jYxmtCfF3ENTcRUZfKeGppZJcM3ZmfhtnrEQXp");
+    // Another bump than the address was derived with.
+    assert_eq!(metadata_delegate_record(251).resolve_role(&MPL_TOKEN_METADATA_PROGRAM_ID, &address), None);
+    // Another delegate.
+    let record = MetadataDelegateRecord { delegate: Pubkey([8; 32]), ..metadata_delegate_record(252) };
+    assert_eq!(record.resolve_role(&MPL_TOKEN_METADATA_PROGRAM_ID, &address), None);
+    // Not the address of the record.
+    assert_eq!(metadata_delegate_record(252).resolve_role(&MPL_TOKEN_METADATA_PROGRAM_ID, &Pubkey([9; 32])), None);
+}
+
+#[test]
+fn print_delegate_role() {
+    let record = HolderDelegateRecord {
+        key: Key::HolderDelegate,
+        bump: 255,
+        mint: Pubkey([3; 32]),
+        delegate: Pubkey([7; 32]),
+        update_authority: Pubkey([5; 32]),
+    };
+    let address = pubkey("9ZPHwZqh2UKAX4vEyiZNagXYAMCFeaCG3kXbVZScDySR");
+    assert_eq!(record.resolve_role(&MPL_TOKEN_METADATA_PROGRAM_ID, &address), Some(HolderDelegateRole::PrintDelegate));
+}
@@ -0,0 +1,86 @@
+//! JSON round-trips of the state types, with pubkeys as base58 strings.
+#![cfg(feature = "serde-feature")]
+use mpl_token_metadata_substream::mpl_token_metadata::state::{
+    Collection, Creator, Data, Key, Metadata, MetadataDelegateRecord, ProgrammableConfig, TokenStandard,
+};
+use serde_json::json;
+use substreams_solana_utils::pubkey::Pubkey;
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+fn base58(n: u8) -> String {
+    bs58::encode([n; 32]).into_string()
+}
+
+#[test]
+fn metadata() {
+    let metadata = Metadata {
+        update_authority: Pubkey([5; 32]),
+        mint: Pubkey([3; 32]),
+        data: Data {
+            name: "Degen #1234".to_string(),
+            symbol: "DGN".to_string(),
+            uri: "https://arweave.net/degen-1234".to_string(),
+            seller_fee_basis_points: 500,
+            creators: Some(vec![Creator { address: Pubkey([5; 32]), verified: true, share: 100 }]),
+        },
+        token_standard: Some(TokenStandard::ProgrammableNonFungible),
+        collection: Some(Collection { verified: true, key: Pubkey([7; 32]) }),
+        programmable_config: Some(ProgrammableConfig::V1 { rule_set: Some(Pubkey([9; 32])) }),
+        ..Metadata::default()
+    };
+    let value = serde_json::to_value(&metadata).unwrap();
+    assert_eq!(value["mint"], json!(base58(3)));
+    assert_eq!(value["data"]["creators"][0]["address"], json!(base58(5)));
+    assert_eq!(value["programmable_config"], json!({ "V1": { "rule_set": base58(9) } }));
+    assert_eq!(round_trip(&metadata), metadata);
+
+    let metadata = Metadata { programmable_config: Some(ProgrammableConfig::V1 { rule_set: None }), ..metadata };
+    assert_eq!(serde_json::to_value(&metadata).unwrap()["programmable_config"], json!({ "V1": { "rule_set": null } }));
+    assert_eq!(round_trip(&metadata), metadata);
+}
+
+#[test]
+fn metadata_delegate_record() {
+    let record = MetadataDelegateRecord {
+        key: Key::MetadataDelegate,
+        bump: 254,
+        mint: Pubkey([3; 32]),
+        delegate: Pubkey([7; 32]),
+        update_authority: Pubkey([5; 32]),
+    };
+    assert_eq!(serde_json::to_value(&record).unwrap(), json!({
+        "key": "MetadataDelegate",
+        "bump": 254,
+        "mint": base58(3),
+        "delegate": base58(7),
+        "update_authority": base58(5),
+    }));
+    assert_eq!(round_trip(&record), record);
+}
+
+#[test]
+fn token_standard() {
+    assert_eq!(serde_json::to_string(&TokenStandard::Fungible).unwrap(), "\"Fungible\"");
+    assert_eq!(round_trip(&TokenStandard::ProgrammableNonFungibleEdition), TokenStandard::ProgrammableNonFungibleEdition);
+}
+
+#[test]
+fn collection() {
+    let collection = Collection { verified: false, key: Pubkey([7; 32]) };
+    assert_eq!(serde_json::to_value(&collection).unwrap(), json!({ "verified": false, "key": base58(7) }));
+    assert_eq!(round_trip(&collection), collection);
+}
+
+#[test]
+fn invalid_pubkey() {
+    let json = json!({ "verified": true, "key": "not base58!" });
+    assert!(serde_json::from_value::<Collection>(json).is_err());
+    let json = json!({ "verified": true, "key": bs58::encode([7; 31]).into_string() });
+    assert!(serde_json::from_value::<Collection>(json).is_err());
+}
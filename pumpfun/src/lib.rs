@@ -26,10 +26,20 @@ fn pumpfun_events(block: Block) -> Result<PumpfunBlockEvents, Error> {
     Ok(PumpfunBlockEvents { transactions })
 }
 
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
 pub fn parse_block(block: &Block) -> Result<Vec<PumpfunTransactionEvents>, Error> {
     let mut block_events: Vec<PumpfunTransactionEvents> = Vec::new();
     for transaction in block.transactions() {
-        let events = parse_transaction(transaction)?;
+        let events = match parse_transaction(transaction) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
         if !events.is_empty() {
             block_events.push(PumpfunTransactionEvents {
                 signature: utils::transaction::get_signature(&transaction),
@@ -41,14 +51,18 @@ pub fn parse_block(block: &Block) -> Result<Vec<PumpfunTransactionEvents>, Error
 }
 
 pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<PumpfunEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
         return Ok(Vec::new())
     }
 
     let mut events: Vec<PumpfunEvent> = Vec::new();
 
-    let context = get_context(transaction).unwrap();
-    let instructions = get_structured_instructions(transaction).unwrap();
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
 
     for instruction in instructions.flattened().iter() {
         if instruction.program_id() != PUMPFUN_PROGRAM_ID {
@@ -5,9 +5,39 @@ use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
 
 pub mod raydium_amm;
-use raydium_amm::instruction::AmmInstruction;
+use raydium_amm::instruction::{AmmInstruction, SetParamsInstruction};
 use raydium_amm::constants::RAYDIUM_AMM_PROGRAM_ID;
 use raydium_amm::log::{decode_ray_log, RayLog};
+pub use raydium_amm::log::{parse_ray_log, SwapLog};
+
+pub mod raydium_clmm;
+use raydium_clmm::instruction::{
+    ClmmInstruction,
+    SwapInstruction as ClmmSwapInstruction,
+    OpenPositionInstruction,
+    IncreaseLiquidityInstruction,
+    DecreaseLiquidityInstruction,
+    CollectFeeInstruction,
+    CreatePoolInstruction,
+};
+use raydium_clmm::constants::RAYDIUM_CLMM_PROGRAM_ID;
+
+pub mod raydium_cpmm;
+use raydium_cpmm::instruction::{
+    CpmmInstruction,
+    InitializeInstruction as CpmmInitializeInstruction,
+    DepositInstruction as CpmmDepositInstruction,
+    WithdrawInstruction as CpmmWithdrawInstruction,
+};
+use raydium_cpmm::constants::RAYDIUM_CPMM_PROGRAM_ID;
+
+pub mod raydium_stable;
+use raydium_stable::instruction::StableInstruction;
+use raydium_stable::constants::RAYDIUM_STABLE_PROGRAM_ID;
+
+pub mod serum_dex;
+use serum_dex::instruction::{DexInstruction, NewOrderInstructionV3, Side, OrderType};
+use serum_dex::constants::SERUM_DEX_PROGRAM_IDS;
 
 use substreams_solana_utils as utils;
 use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
@@ -16,10 +46,16 @@ use utils::pubkey::Pubkey;
 use utils::log::Log;
 
 use spl_token_substream;
+use token_2022_substream;
+use token_2022_substream::pb::token_2022::token2022_event::Event as Token2022Event;
+
+pub mod price;
+use price::execution_price;
 
 pub mod pb;
 use pb::raydium_amm::*;
 use pb::raydium_amm::raydium_amm_event::Event;
+use pb::raydium_amm::serum_dex_event::Event as SerumDexEventOneof;
 
 #[substreams::handlers::map]
 fn raydium_amm_events(block: Block) -> Result<RaydiumAmmBlockEvents, Error> {
@@ -27,6 +63,54 @@ fn raydium_amm_events(block: Block) -> Result<RaydiumAmmBlockEvents, Error> {
     Ok(RaydiumAmmBlockEvents { transactions})
 }
 
+#[substreams::handlers::map]
+fn raydium_clmm_events(block: Block) -> Result<RaydiumAmmBlockEvents, Error> {
+    let transactions = retain_events(parse_block(&block), is_clmm_event);
+    Ok(RaydiumAmmBlockEvents { transactions })
+}
+
+#[substreams::handlers::map]
+fn raydium_cpmm_events(block: Block) -> Result<RaydiumAmmBlockEvents, Error> {
+    let transactions = retain_events(parse_block(&block), is_cpmm_event);
+    Ok(RaydiumAmmBlockEvents { transactions })
+}
+
+#[substreams::handlers::map]
+fn serum_dex_events(block: Block) -> Result<SerumDexBlockEvents, Error> {
+    let transactions = parse_serum_block(&block);
+    Ok(SerumDexBlockEvents { transactions })
+}
+
+/// Keeps the events matching `predicate`, dropping transactions left without any.
+pub fn retain_events(transactions: Vec<RaydiumAmmTransactionEvents>, predicate: fn(&Event) -> bool) -> Vec<RaydiumAmmTransactionEvents> {
+    transactions.into_iter().filter_map(|mut transaction| {
+        transaction.events.retain(|event| event.event.as_ref().is_some_and(predicate));
+        (!transaction.events.is_empty()).then_some(transaction)
+    }).collect()
+}
+
+pub fn is_clmm_event(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::ClmmSwap(_)
+            | Event::ClmmOpenPosition(_)
+            | Event::ClmmIncreaseLiquidity(_)
+            | Event::ClmmDecreaseLiquidity(_)
+            | Event::ClmmCollectFee(_)
+            | Event::ClmmCreatePool(_)
+    )
+}
+
+pub fn is_cpmm_event(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::CpmmInitialize(_)
+            | Event::CpmmDeposit(_)
+            | Event::CpmmWithdraw(_)
+            | Event::CpmmSwap(_)
+    )
+}
+
 pub fn parse_block(block: &Block) -> Vec<RaydiumAmmTransactionEvents> {
     let mut block_events: Vec<RaydiumAmmTransactionEvents> = Vec::new();
     for transaction in block.transactions.iter() {
@@ -43,7 +127,11 @@ pub fn parse_block(block: &Block) -> Vec<RaydiumAmmTransactionEvents> {
 }
 
 pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<RaydiumAmmEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new());
+    };
+    if meta.err.is_some() {
         return Ok(Vec::new());
     }
 
@@ -53,11 +141,19 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Raydi
     let instructions = get_structured_instructions(transaction)?;
 
     for instruction in instructions.flattened().iter() {
-        if instruction.program_id() != RAYDIUM_AMM_PROGRAM_ID {
+        let result = if instruction.program_id() == RAYDIUM_AMM_PROGRAM_ID {
+            parse_instruction(&instruction, &context)
+        } else if instruction.program_id() == RAYDIUM_CLMM_PROGRAM_ID {
+            parse_clmm_instruction(&instruction, &context)
+        } else if instruction.program_id() == RAYDIUM_CPMM_PROGRAM_ID {
+            parse_cpmm_instruction(&instruction, &context)
+        } else if instruction.program_id() == RAYDIUM_STABLE_PROGRAM_ID {
+            parse_stable_instruction(&instruction, &context)
+        } else {
             continue;
-        }
+        };
 
-        match parse_instruction(&instruction, &context) {
+        match result {
             Ok(Some(event)) => {
                 events.push(RaydiumAmmEvent {
                     event: Some(event),
@@ -67,9 +163,38 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Raydi
             Err(error) => substreams::log::println(format!("Failed to process instruction of transaction {}: {}", &context.signature, error))
         }
     }
+
+    enrich_swap_events(&mut events, transaction);
     Ok(events)
 }
 
+/// Attaches decimals, execution price and the token balance check to swap events from the
+/// transaction's token balances.
+pub fn enrich_swap_events(events: &mut [RaydiumAmmEvent], transaction: &ConfirmedTransaction) {
+    if !events.iter().any(|event| matches!(event.event, Some(Event::Swap(_)))) {
+        return;
+    }
+    let decimals = spl_token_substream::token_balances::mint_decimals(transaction);
+    let deltas = spl_token_substream::token_balances::token_balance_deltas(transaction);
+    for event in events.iter_mut() {
+        let Some(Event::Swap(swap)) = event.event.as_mut() else {
+            continue;
+        };
+        swap.decimals_in = decimals.get(&swap.mint_in).copied();
+        swap.decimals_out = decimals.get(&swap.mint_out).copied();
+        if let (Some(decimals_in), Some(decimals_out)) = (swap.decimals_in, swap.decimals_out) {
+            swap.price = execution_price(swap.amount_in, decimals_in, swap.amount_out, decimals_out);
+        }
+        let source_delta = deltas.get(&swap.user_source_token_account);
+        let destination_delta = deltas.get(&swap.user_destination_token_account);
+        if let (Some(source_delta), Some(destination_delta)) = (source_delta, destination_delta) {
+            swap.balance_check_available = true;
+            swap.balances_consistent = *source_delta == -(swap.amount_in as i128)
+                && *destination_delta == swap.amount_out as i128;
+        }
+    }
+}
+
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext
@@ -100,41 +225,98 @@ pub fn parse_instruction<'a>(
             let event = _parse_withdraw_pnl_instruction(instruction, context)?;
             Ok(Some(Event::WithdrawPnl(event)))
         }
+        AmmInstruction::SetParams(set_params) => {
+            let event = _parse_set_params_instruction(instruction, context, set_params)?;
+            Ok(Some(Event::SetParams(event)))
+        }
         _ => Ok(None),
     }
 }
 
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, String> {
+    instruction.accounts().get(index).cloned().ok_or_else(|| format!("Missing account {}.", index))
+}
+
+/// The `n`-th inner instruction counted from the end, `n` starting at 1.
+fn _get_inner_instruction<'b, 'a>(instruction: &'b StructuredInstruction<'a>, n: usize) -> Result<&'b StructuredInstruction<'a>, String> {
+    let inner_instructions = instruction.inner_instructions();
+    inner_instructions.len().checked_sub(n)
+        .and_then(|index| inner_instructions.get(index))
+        .ok_or_else(|| format!("Missing inner instruction {} from the end.", n))
+}
+
+fn _parse_set_params_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    set_params: SetParamsInstruction,
+) -> Result<SetParamsEvent, String> {
+    let amm = _get_account(instruction, 1)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
+    let admin = instruction.accounts().get(15).map(|account| account.to_string());
+
+    let fees = set_params.fees.map(|fees| AmmFees {
+        min_separate_numerator: fees.min_separate_numerator,
+        min_separate_denominator: fees.min_separate_denominator,
+        trade_fee_numerator: fees.trade_fee_numerator,
+        trade_fee_denominator: fees.trade_fee_denominator,
+        pnl_numerator: fees.pnl_numerator,
+        pnl_denominator: fees.pnl_denominator,
+        swap_fee_numerator: fees.swap_fee_numerator,
+        swap_fee_denominator: fees.swap_fee_denominator,
+    });
+
+    Ok(SetParamsEvent {
+        amm,
+        authority,
+        admin,
+        param: set_params.param.into(),
+        value: set_params.value,
+        new_pubkey: set_params.new_pubkey.map(|pubkey| pubkey.to_string()),
+        fees,
+        last_order_numerator: set_params.last_order_distance.map(|distance| distance.last_order_numerator),
+        last_order_denominator: set_params.last_order_distance.map(|distance| distance.last_order_denominator),
+    })
+}
+
 fn _parse_swap_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
 ) -> Result<SwapEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts().last().unwrap().to_string();
+    // The target orders account (4) is optional, shifting every later account by one when present.
+    let delta = if instruction.accounts().len() == 17 { 0 } else { 1 };
+    let amm = _get_account(instruction, 1)?.to_string();
+    let coin_vault = _get_account(instruction, 4 + delta)?;
+    let pc_vault = _get_account(instruction, 5 + delta)?;
+    let market = _get_account(instruction, 7 + delta)?.to_string();
+    let user_source_token_account = _get_account(instruction, 14 + delta)?.to_string();
+    let user_destination_token_account = _get_account(instruction, 15 + delta)?.to_string();
+    let user = _get_account(instruction, 16 + delta)?.to_string();
 
-    let instructions_len = instruction.inner_instructions().len();
-    let transfer_in = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let transfer_out = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let transfer_in = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 2)?, context)?;
+    let transfer_out = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 1)?, context)?;
 
     let amount_in = transfer_in.amount;
     let amount_out = transfer_out.amount;
-    let mint_in = transfer_in.source.unwrap().mint;
-    let mint_out = transfer_out.source.unwrap().mint;
+    let mint_in = transfer_in.source.ok_or("Missing transfer source.")?.mint;
+    let mint_out = transfer_out.source.ok_or("Missing transfer source.")?.mint;
 
-    let delta = if instruction.accounts().len() == 17 { 0 } else { 1 };
-    let coin_mint = context.get_token_account(&instruction.accounts()[4 + delta]).unwrap().mint.to_string();
-    let pc_mint = context.get_token_account(&instruction.accounts()[5 + delta]).unwrap().mint.to_string();
+    let coin_mint = context.get_token_account(&coin_vault).ok_or("Unknown coin vault.")?.mint.to_string();
+    let pc_mint = context.get_token_account(&pc_vault).ok_or("Unknown pc vault.")?.mint.to_string();
 
     let direction = (if mint_out == coin_mint { "coin" } else { "pc" }).to_string();
 
-    let (pool_coin_amount, pool_pc_amount) = match parse_log(instruction) {
-        Ok(RayLog::SwapBaseIn(swap_base_in)) => {
-            (Some(swap_base_in.pool_coin), Some(swap_base_in.pool_pc))
-        },
-        Ok(RayLog::SwapBaseOut(swap_base_out)) => {
-            (Some(swap_base_out.pool_coin), Some(swap_base_out.pool_pc))
-        },
-        _ => (None, None),
-    };
+    let vaults = [coin_vault.to_string(), pc_vault.to_string()];
+    let (amount_in_exact, amount_out_exact) = _get_exact_swap_amounts(
+        instruction,
+        context,
+        &user_source_token_account,
+        &user_destination_token_account,
+        &vaults,
+    );
+
+    let swap_log = parse_log(instruction).ok().and_then(|log| log.swap());
+    let pool_coin_amount = swap_log.as_ref().map(|log| log.pool_coin_before);
+    let pool_pc_amount = swap_log.as_ref().map(|log| log.pool_pc_before);
 
     Ok(SwapEvent {
         amm,
@@ -148,27 +330,71 @@ fn _parse_swap_instruction<'a>(
         pool_pc_amount,
         coin_mint,
         pc_mint,
+        decimals_in: None,
+        decimals_out: None,
+        user_source_token_account,
+        user_destination_token_account,
+        coin_vault: coin_vault.to_string(),
+        pc_vault: pc_vault.to_string(),
+        market,
+        amount_in_exact,
+        amount_out_exact,
+        price: None,
+        ray_log_amount_in: swap_log.as_ref().map(|log| log.amount_in),
+        ray_log_amount_out: swap_log.as_ref().map(|log| log.amount_out),
+        balances_consistent: false,
+        balance_check_available: false,
+        pool_type: PoolType::AmmV4.into(),
     })
 }
 
+/// Matches the token transfers directly invoked by a swap against the user and vault accounts.
+/// Each direction is only filled when exactly one transfer matches it.
+fn _get_exact_swap_amounts<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    user_source_token_account: &str,
+    user_destination_token_account: &str,
+    vaults: &[String],
+) -> (Option<u64>, Option<u64>) {
+    let transfers: Vec<_> = instruction.inner_instructions().iter()
+        .filter_map(|inner_instruction| spl_token_substream::parse_transfer_instruction(inner_instruction, context).ok())
+        .filter_map(|transfer| Some((transfer.source?.address, transfer.destination?.address, transfer.amount)))
+        .collect();
+
+    let single_amount = |from: &dyn Fn(&str) -> bool, to: &dyn Fn(&str) -> bool| {
+        let mut matching = transfers.iter().filter(|(source, destination, _)| from(source.as_str()) && to(destination.as_str()));
+        match (matching.next(), matching.next()) {
+            (Some((_, _, amount)), None) => Some(*amount),
+            _ => None,
+        }
+    };
+    let is_vault = |address: &str| vaults.iter().any(|vault| vault == address);
+
+    let amount_in = single_amount(&|source| source == user_source_token_account, &is_vault);
+    let amount_out = single_amount(&is_vault, &|destination| destination == user_destination_token_account);
+    (amount_in, amount_out)
+}
+
 fn _parse_initialize_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
     nonce: u8,
 ) -> Result<InitializeEvent, String> {
-    let amm = instruction.accounts()[4].to_string();
-    let user = instruction.accounts()[17].to_string();
+    let amm = _get_account(instruction, 4)?.to_string();
+    let coin_vault = _get_account(instruction, 10)?.to_string();
+    let pc_vault = _get_account(instruction, 11)?.to_string();
+    let user = _get_account(instruction, 17)?.to_string();
 
-    let instructions_len = instruction.inner_instructions().len();
-    let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 3], context)?;
-    let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let coin_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 3)?, context)?;
+    let pc_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 2)?, context)?;
+    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(_get_inner_instruction(instruction, 1)?, context)?;
 
     let pc_init_amount = pc_transfer.amount;
     let coin_init_amount = coin_transfer.amount;
     let lp_init_amount = lp_mint_to.amount;
-    let pc_mint = pc_transfer.source.unwrap().mint;
-    let coin_mint = coin_transfer.source.unwrap().mint;
+    let pc_mint = pc_transfer.source.ok_or("Missing transfer source.")?.mint;
+    let coin_mint = coin_transfer.source.ok_or("Missing transfer source.")?.mint;
     let lp_mint = lp_mint_to.mint;
 
     let market = match parse_log(instruction) {
@@ -187,6 +413,8 @@ fn _parse_initialize_instruction<'a>(
         lp_mint,
         nonce: nonce as u32,
         market,
+        coin_vault,
+        pc_vault,
     })
 }
 
@@ -194,19 +422,21 @@ fn _parse_deposit_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext
 ) -> Result<DepositEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts()[12].to_string();
+    let amm = _get_account(instruction, 1)?.to_string();
+    let coin_vault = _get_account(instruction, 6)?.to_string();
+    let pc_vault = _get_account(instruction, 7)?.to_string();
+    let market = _get_account(instruction, 8)?.to_string();
+    let user = _get_account(instruction, 12)?.to_string();
 
-    let instructions_len = instruction.inner_instructions().len();
-    let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 3], context)?;
-    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let pc_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 2)?, context)?;
+    let coin_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 3)?, context)?;
+    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(_get_inner_instruction(instruction, 1)?, context)?;
 
     let pc_amount = pc_transfer.amount;
     let coin_amount = coin_transfer.amount;
     let lp_amount = lp_mint_to.amount;
-    let pc_mint = pc_transfer.source.unwrap().mint;
-    let coin_mint = coin_transfer.source.unwrap().mint;
+    let pc_mint = pc_transfer.source.ok_or("Missing transfer source.")?.mint;
+    let coin_mint = coin_transfer.source.ok_or("Missing transfer source.")?.mint;
     let lp_mint = lp_mint_to.mint;
 
     let (pool_pc_amount, pool_coin_amount, pool_lp_amount) = match parse_log(instruction) {
@@ -228,6 +458,9 @@ fn _parse_deposit_instruction<'a>(
         pool_pc_amount,
         pool_coin_amount,
         pool_lp_amount,
+        coin_vault,
+        pc_vault,
+        market,
     })
 }
 
@@ -235,20 +468,22 @@ fn _parse_withdraw_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
 ) -> Result<WithdrawEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts()[16].to_string();
+    let amm = _get_account(instruction, 1)?.to_string();
+    let coin_vault = _get_account(instruction, 6)?.to_string();
+    let pc_vault = _get_account(instruction, 7)?.to_string();
+    let market = _get_account(instruction, 9)?.to_string();
+    let user = _get_account(instruction, 16)?.to_string();
 
-    let instructions_len = instruction.inner_instructions().len();
-    let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 3], context)?;
-    let lp_burn = spl_token_substream::parse_burn_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let pc_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 2)?, context)?;
+    let coin_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 3)?, context)?;
+    let lp_burn = spl_token_substream::parse_burn_instruction(_get_inner_instruction(instruction, 1)?, context)?;
 
     let pc_amount = pc_transfer.amount;
     let coin_amount = coin_transfer.amount;
     let lp_amount = lp_burn.amount;
-    let pc_mint = pc_transfer.source.unwrap().mint;
-    let coin_mint = coin_transfer.source.unwrap().mint;
-    let lp_mint = lp_burn.source.unwrap().mint;
+    let pc_mint = pc_transfer.source.ok_or("Missing transfer source.")?.mint;
+    let coin_mint = coin_transfer.source.ok_or("Missing transfer source.")?.mint;
+    let lp_mint = lp_burn.source.ok_or("Missing burn source.")?.mint;
 
     let (pool_pc_amount, pool_coin_amount, pool_lp_amount) = match parse_log(instruction) {
         Ok(RayLog::Withdraw(withdraw)) => {
@@ -269,6 +504,9 @@ fn _parse_withdraw_instruction<'a>(
         pool_pc_amount,
         pool_coin_amount,
         pool_lp_amount,
+        coin_vault,
+        pc_vault,
+        market,
     })
 }
 
@@ -276,18 +514,18 @@ fn _parse_withdraw_pnl_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<WithdrawPnlEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts()[9].to_string();
+    let amm = _get_account(instruction, 1)?.to_string();
+    let user = _get_account(instruction, 9)?.to_string();
 
     let instructions_len = instruction.inner_instructions().len();
     if instructions_len == 2 || instructions_len == 3 {
-        let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
-        let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
+        let pc_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 1)?, context)?;
+        let coin_transfer = spl_token_substream::parse_transfer_instruction(_get_inner_instruction(instruction, 2)?, context)?;
 
         let pc_amount = Some(pc_transfer.amount);
         let coin_amount = Some(coin_transfer.amount);
-        let pc_mint = Some(pc_transfer.source.unwrap().mint);
-        let coin_mint = Some(coin_transfer.source.unwrap().mint);
+        let pc_mint = Some(pc_transfer.source.ok_or("Missing transfer source.")?.mint);
+        let coin_mint = Some(coin_transfer.source.ok_or("Missing transfer source.")?.mint);
 
         return Ok(WithdrawPnlEvent {
             amm,
@@ -309,6 +547,726 @@ fn _parse_withdraw_pnl_instruction(
     }
 }
 
+pub fn parse_clmm_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext
+) -> Result<Option<Event>, String> {
+    if instruction.program_id() != RAYDIUM_CLMM_PROGRAM_ID {
+        return Err("Instruction does not originate from Raydium CLMM Program.".into());
+    }
+    let unpacked = ClmmInstruction::unpack(&instruction.data())?;
+    match unpacked {
+        ClmmInstruction::Swap(swap) => {
+            let event = _parse_clmm_swap_instruction(instruction, context, &swap, false)?;
+            Ok(Some(Event::ClmmSwap(event)))
+        },
+        ClmmInstruction::SwapV2(swap) => {
+            let event = _parse_clmm_swap_instruction(instruction, context, &swap, true)?;
+            Ok(Some(Event::ClmmSwap(event)))
+        },
+        ClmmInstruction::OpenPosition(args) | ClmmInstruction::OpenPositionV2(args) => {
+            let event = _parse_clmm_open_position_instruction(instruction, context, &args, false)?;
+            Ok(Some(Event::ClmmOpenPosition(event)))
+        },
+        ClmmInstruction::OpenPositionWithToken22Nft(args) => {
+            let event = _parse_clmm_open_position_instruction(instruction, context, &args, true)?;
+            Ok(Some(Event::ClmmOpenPosition(event)))
+        },
+        ClmmInstruction::IncreaseLiquidity(args) | ClmmInstruction::IncreaseLiquidityV2(args) => {
+            let event = _parse_clmm_increase_liquidity_instruction(instruction, context, &args)?;
+            Ok(Some(Event::ClmmIncreaseLiquidity(event)))
+        },
+        ClmmInstruction::DecreaseLiquidity(args) | ClmmInstruction::DecreaseLiquidityV2(args) => {
+            let event = _parse_clmm_decrease_liquidity_instruction(instruction, context, &args)?;
+            Ok(Some(Event::ClmmDecreaseLiquidity(event)))
+        },
+        ClmmInstruction::CollectProtocolFee(args) => {
+            let event = _parse_clmm_collect_fee_instruction(instruction, context, &args, false)?;
+            Ok(Some(Event::ClmmCollectFee(event)))
+        },
+        ClmmInstruction::CollectFundFee(args) => {
+            let event = _parse_clmm_collect_fee_instruction(instruction, context, &args, true)?;
+            Ok(Some(Event::ClmmCollectFee(event)))
+        },
+        ClmmInstruction::CreatePool(args) => {
+            let event = _parse_clmm_create_pool_instruction(instruction, context, &args)?;
+            Ok(Some(Event::ClmmCreatePool(event)))
+        },
+        ClmmInstruction::Unknown => Ok(None),
+    }
+}
+
+fn _parse_clmm_swap_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    swap: &ClmmSwapInstruction,
+    v2: bool,
+) -> Result<ClmmSwapEvent, String> {
+    // swap_v2 adds token_program_2022, memo_program, input_vault_mint and output_vault_mint
+    // before the tick arrays.
+    let tick_arrays_start = if v2 { 13 } else { 9 };
+    let accounts = instruction.accounts();
+    if accounts.len() < tick_arrays_start {
+        return Err(format!("Expected at least {} accounts, found {}.", tick_arrays_start, accounts.len()));
+    }
+
+    let user = accounts[0].to_string();
+    let pool_state = accounts[2].to_string();
+    let input_token_account = accounts[3].to_string();
+    let output_token_account = accounts[4].to_string();
+    let input_vault = accounts[5].to_string();
+    let output_vault = accounts[6].to_string();
+    let tick_arrays = accounts[tick_arrays_start..].iter().map(|account| account.to_string()).collect();
+
+    Ok(ClmmSwapEvent {
+        pool_state,
+        user,
+        input_token_account,
+        output_token_account,
+        input_vault,
+        output_vault,
+        tick_arrays,
+        amount: swap.amount,
+        other_amount_threshold: swap.other_amount_threshold,
+        sqrt_price_limit_x64: swap.sqrt_price_limit_x64.to_string(),
+        is_base_input: swap.is_base_input,
+    })
+}
+
+fn _parse_clmm_open_position_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    args: &OpenPositionInstruction,
+    token22_nft: bool,
+) -> Result<ClmmOpenPositionEvent, String> {
+    // open_position_with_token22_nft has no metadata account, shifting everything after the NFT account.
+    let offset = if token22_nft { 0 } else { 1 };
+
+    let payer = _get_account(instruction, 0)?.to_string();
+    let owner = _get_account(instruction, 1)?.to_string();
+    let position_nft_mint = _get_account(instruction, 2)?.to_string();
+    let position_nft_account = _get_account(instruction, 3)?.to_string();
+    let pool_state = _get_account(instruction, 4 + offset)?.to_string();
+    let tick_arrays = vec![
+        _get_account(instruction, 6 + offset)?.to_string(),
+        _get_account(instruction, 7 + offset)?.to_string(),
+    ];
+    let personal_position = _get_account(instruction, 8 + offset)?.to_string();
+    let token_vault0 = _get_account(instruction, 11 + offset)?.to_string();
+    let token_vault1 = _get_account(instruction, 12 + offset)?.to_string();
+
+    Ok(ClmmOpenPositionEvent {
+        pool_state,
+        owner,
+        payer,
+        position_nft_mint,
+        position_nft_account,
+        personal_position,
+        tick_lower_index: args.tick_lower_index,
+        tick_upper_index: args.tick_upper_index,
+        tick_arrays,
+        liquidity: args.liquidity.to_string(),
+        amount0_max: args.amount_0_max,
+        amount1_max: args.amount_1_max,
+        token_vault0,
+        token_vault1,
+    })
+}
+
+fn _parse_clmm_increase_liquidity_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    args: &IncreaseLiquidityInstruction,
+) -> Result<ClmmIncreaseLiquidityEvent, String> {
+    let owner = _get_account(instruction, 0)?.to_string();
+    let position_nft_account = _get_account(instruction, 1)?;
+    let pool_state = _get_account(instruction, 2)?.to_string();
+    let personal_position = _get_account(instruction, 4)?.to_string();
+    let tick_arrays = vec![
+        _get_account(instruction, 5)?.to_string(),
+        _get_account(instruction, 6)?.to_string(),
+    ];
+    let token_vault0 = _get_account(instruction, 9)?.to_string();
+    let token_vault1 = _get_account(instruction, 10)?.to_string();
+    let position_nft_mint = context.get_token_account(&position_nft_account).map(|account| account.mint.to_string());
+
+    Ok(ClmmIncreaseLiquidityEvent {
+        pool_state,
+        owner,
+        position_nft_account: position_nft_account.to_string(),
+        position_nft_mint,
+        personal_position,
+        tick_arrays,
+        liquidity: args.liquidity.to_string(),
+        amount0_max: args.amount_0_max,
+        amount1_max: args.amount_1_max,
+        token_vault0,
+        token_vault1,
+    })
+}
+
+fn _parse_clmm_decrease_liquidity_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    args: &DecreaseLiquidityInstruction,
+) -> Result<ClmmDecreaseLiquidityEvent, String> {
+    let owner = _get_account(instruction, 0)?.to_string();
+    let position_nft_account = _get_account(instruction, 1)?;
+    let personal_position = _get_account(instruction, 2)?.to_string();
+    let pool_state = _get_account(instruction, 3)?.to_string();
+    let token_vault0 = _get_account(instruction, 5)?.to_string();
+    let token_vault1 = _get_account(instruction, 6)?.to_string();
+    let tick_arrays = vec![
+        _get_account(instruction, 7)?.to_string(),
+        _get_account(instruction, 8)?.to_string(),
+    ];
+    let recipient_token_account0 = _get_account(instruction, 9)?.to_string();
+    let recipient_token_account1 = _get_account(instruction, 10)?.to_string();
+    let position_nft_mint = context.get_token_account(&position_nft_account).map(|account| account.mint.to_string());
+
+    Ok(ClmmDecreaseLiquidityEvent {
+        pool_state,
+        owner,
+        position_nft_account: position_nft_account.to_string(),
+        position_nft_mint,
+        personal_position,
+        tick_arrays,
+        liquidity: args.liquidity.to_string(),
+        amount0_min: args.amount_0_min,
+        amount1_min: args.amount_1_min,
+        token_vault0,
+        token_vault1,
+        recipient_token_account0,
+        recipient_token_account1,
+    })
+}
+
+fn _parse_clmm_collect_fee_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    args: &CollectFeeInstruction,
+    is_fund_fee: bool,
+) -> Result<ClmmCollectFeeEvent, String> {
+    let owner = _get_account(instruction, 0)?.to_string();
+    let pool_state = _get_account(instruction, 1)?.to_string();
+    let token_vault0 = _get_account(instruction, 3)?.to_string();
+    let token_vault1 = _get_account(instruction, 4)?.to_string();
+    let recipient_token_account0 = _get_account(instruction, 7)?.to_string();
+    let recipient_token_account1 = _get_account(instruction, 8)?.to_string();
+
+    Ok(ClmmCollectFeeEvent {
+        pool_state,
+        owner,
+        token_vault0,
+        token_vault1,
+        recipient_token_account0,
+        recipient_token_account1,
+        amount0_requested: args.amount_0_requested,
+        amount1_requested: args.amount_1_requested,
+        is_fund_fee,
+    })
+}
+
+fn _parse_clmm_create_pool_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    args: &CreatePoolInstruction,
+) -> Result<ClmmCreatePoolEvent, String> {
+    let pool_creator = _get_account(instruction, 0)?.to_string();
+    let amm_config = _get_account(instruction, 1)?.to_string();
+    let pool_state = _get_account(instruction, 2)?.to_string();
+    let token_mint0 = _get_account(instruction, 3)?.to_string();
+    let token_mint1 = _get_account(instruction, 4)?.to_string();
+    let token_vault0 = _get_account(instruction, 5)?.to_string();
+    let token_vault1 = _get_account(instruction, 6)?.to_string();
+
+    Ok(ClmmCreatePoolEvent {
+        pool_state,
+        pool_creator,
+        amm_config,
+        token_mint0,
+        token_mint1,
+        token_vault0,
+        token_vault1,
+        sqrt_price_x64: args.sqrt_price_x64.to_string(),
+        open_time: args.open_time,
+    })
+}
+
+pub fn parse_cpmm_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext
+) -> Result<Option<Event>, String> {
+    if instruction.program_id() != RAYDIUM_CPMM_PROGRAM_ID {
+        return Err("Instruction does not originate from Raydium CPMM Program.".into());
+    }
+    let unpacked = CpmmInstruction::unpack(&instruction.data())?;
+    match unpacked {
+        CpmmInstruction::Initialize(args) => {
+            let event = _parse_cpmm_initialize_instruction(instruction, context, &args)?;
+            Ok(Some(Event::CpmmInitialize(event)))
+        },
+        CpmmInstruction::Deposit(args) => {
+            let event = _parse_cpmm_deposit_instruction(instruction, context, &args)?;
+            Ok(Some(Event::CpmmDeposit(event)))
+        },
+        CpmmInstruction::Withdraw(args) => {
+            let event = _parse_cpmm_withdraw_instruction(instruction, context, &args)?;
+            Ok(Some(Event::CpmmWithdraw(event)))
+        },
+        CpmmInstruction::SwapBaseInput(args) => {
+            let event = _parse_cpmm_swap_instruction(instruction, context, args.amount_in, args.minimum_amount_out, true)?;
+            Ok(Some(Event::CpmmSwap(event)))
+        },
+        CpmmInstruction::SwapBaseOutput(args) => {
+            let event = _parse_cpmm_swap_instruction(instruction, context, args.amount_out, args.max_amount_in, false)?;
+            Ok(Some(Event::CpmmSwap(event)))
+        },
+        CpmmInstruction::Unknown => Ok(None),
+    }
+}
+
+fn _parse_cpmm_initialize_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    args: &CpmmInitializeInstruction,
+) -> Result<CpmmInitializeEvent, String> {
+    let creator = _get_account(instruction, 0)?.to_string();
+    let amm_config = _get_account(instruction, 1)?.to_string();
+    let pool_state = _get_account(instruction, 3)?.to_string();
+    let token_mint0 = _get_account(instruction, 4)?.to_string();
+    let token_mint1 = _get_account(instruction, 5)?.to_string();
+    let lp_mint = _get_account(instruction, 6)?.to_string();
+    let creator_token_account0 = _get_account(instruction, 7)?.to_string();
+    let creator_token_account1 = _get_account(instruction, 8)?.to_string();
+    let token_vault0 = _get_account(instruction, 10)?.to_string();
+    let token_vault1 = _get_account(instruction, 11)?.to_string();
+
+    Ok(CpmmInitializeEvent {
+        pool_state,
+        creator,
+        amm_config,
+        lp_mint,
+        token_mint0,
+        token_mint1,
+        token_vault0,
+        token_vault1,
+        creator_token_account0,
+        creator_token_account1,
+        init_amount0: args.init_amount_0,
+        init_amount1: args.init_amount_1,
+        open_time: args.open_time,
+    })
+}
+
+fn _parse_cpmm_deposit_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    args: &CpmmDepositInstruction,
+) -> Result<CpmmDepositEvent, String> {
+    let owner = _get_account(instruction, 0)?.to_string();
+    let pool_state = _get_account(instruction, 2)?.to_string();
+    let owner_lp_token_account = _get_account(instruction, 3)?.to_string();
+    let user_token_account0 = _get_account(instruction, 4)?.to_string();
+    let user_token_account1 = _get_account(instruction, 5)?.to_string();
+    let token_vault0 = _get_account(instruction, 6)?.to_string();
+    let token_vault1 = _get_account(instruction, 7)?.to_string();
+    let token_mint0 = _get_account(instruction, 10)?.to_string();
+    let token_mint1 = _get_account(instruction, 11)?.to_string();
+    let lp_mint = _get_account(instruction, 12)?.to_string();
+
+    let transfers = _get_token_transfers(instruction, context);
+    let amount0_exact = _get_single_transfer_amount(&transfers, &user_token_account0, &token_vault0);
+    let amount1_exact = _get_single_transfer_amount(&transfers, &user_token_account1, &token_vault1);
+
+    Ok(CpmmDepositEvent {
+        pool_state,
+        owner,
+        lp_mint,
+        owner_lp_token_account,
+        user_token_account0,
+        user_token_account1,
+        token_vault0,
+        token_vault1,
+        token_mint0,
+        token_mint1,
+        lp_token_amount: args.lp_token_amount,
+        maximum_amount0: args.maximum_token_0_amount,
+        maximum_amount1: args.maximum_token_1_amount,
+        amount0_exact,
+        amount1_exact,
+    })
+}
+
+fn _parse_cpmm_withdraw_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    args: &CpmmWithdrawInstruction,
+) -> Result<CpmmWithdrawEvent, String> {
+    let owner = _get_account(instruction, 0)?.to_string();
+    let pool_state = _get_account(instruction, 2)?.to_string();
+    let owner_lp_token_account = _get_account(instruction, 3)?.to_string();
+    let user_token_account0 = _get_account(instruction, 4)?.to_string();
+    let user_token_account1 = _get_account(instruction, 5)?.to_string();
+    let token_vault0 = _get_account(instruction, 6)?.to_string();
+    let token_vault1 = _get_account(instruction, 7)?.to_string();
+    let token_mint0 = _get_account(instruction, 10)?.to_string();
+    let token_mint1 = _get_account(instruction, 11)?.to_string();
+    let lp_mint = _get_account(instruction, 12)?.to_string();
+
+    let transfers = _get_token_transfers(instruction, context);
+    let amount0_exact = _get_single_transfer_amount(&transfers, &token_vault0, &user_token_account0);
+    let amount1_exact = _get_single_transfer_amount(&transfers, &token_vault1, &user_token_account1);
+
+    Ok(CpmmWithdrawEvent {
+        pool_state,
+        owner,
+        lp_mint,
+        owner_lp_token_account,
+        user_token_account0,
+        user_token_account1,
+        token_vault0,
+        token_vault1,
+        token_mint0,
+        token_mint1,
+        lp_token_amount: args.lp_token_amount,
+        minimum_amount0: args.minimum_token_0_amount,
+        minimum_amount1: args.minimum_token_1_amount,
+        amount0_exact,
+        amount1_exact,
+    })
+}
+
+fn _parse_cpmm_swap_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    amount: u64,
+    other_amount_threshold: u64,
+    is_base_input: bool,
+) -> Result<CpmmSwapEvent, String> {
+    let user = _get_account(instruction, 0)?.to_string();
+    let amm_config = _get_account(instruction, 2)?.to_string();
+    let pool_state = _get_account(instruction, 3)?.to_string();
+    let input_token_account = _get_account(instruction, 4)?.to_string();
+    let output_token_account = _get_account(instruction, 5)?.to_string();
+    let input_vault = _get_account(instruction, 6)?.to_string();
+    let output_vault = _get_account(instruction, 7)?.to_string();
+    let input_mint = _get_account(instruction, 10)?.to_string();
+    let output_mint = _get_account(instruction, 11)?.to_string();
+
+    let transfers = _get_token_transfers(instruction, context);
+    let amount_in_exact = _get_single_transfer_amount(&transfers, &input_token_account, &input_vault);
+    let amount_out_exact = _get_single_transfer_amount(&transfers, &output_vault, &output_token_account);
+
+    Ok(CpmmSwapEvent {
+        pool_state,
+        amm_config,
+        user,
+        input_token_account,
+        output_token_account,
+        input_vault,
+        output_vault,
+        input_mint,
+        output_mint,
+        amount,
+        other_amount_threshold,
+        is_base_input,
+        amount_in_exact,
+        amount_out_exact,
+    })
+}
+
+pub fn parse_stable_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext
+) -> Result<Option<Event>, String> {
+    if instruction.program_id() != RAYDIUM_STABLE_PROGRAM_ID {
+        return Err("Instruction does not originate from Raydium Stable Program.".into());
+    }
+    match StableInstruction::unpack(&instruction.data())? {
+        StableInstruction::SwapBaseIn(_) | StableInstruction::SwapBaseOut(_) => {
+            let event = _parse_stable_swap_instruction(instruction, context)?;
+            Ok(Some(Event::Swap(event)))
+        },
+        StableInstruction::Unknown => Ok(None),
+    }
+}
+
+/// Unlike AMM v4 there is no target orders account, but a model data account after the vaults:
+/// token program, amm, authority, open orders, coin vault, pc vault, model data, serum program,
+/// market, bids, asks, event queue, market coin vault, market pc vault, vault signer, user
+/// source, user destination, user.
+fn _parse_stable_swap_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<SwapEvent, String> {
+    let amm = _get_account(instruction, 1)?.to_string();
+    let coin_vault = _get_account(instruction, 4)?;
+    let pc_vault = _get_account(instruction, 5)?;
+    let market = _get_account(instruction, 8)?.to_string();
+    let user_source_token_account = _get_account(instruction, 15)?.to_string();
+    let user_destination_token_account = _get_account(instruction, 16)?.to_string();
+    let user = _get_account(instruction, 17)?.to_string();
+
+    let coin_mint = context.get_token_account(&coin_vault).ok_or("Unknown coin vault.")?.mint.to_string();
+    let pc_mint = context.get_token_account(&pc_vault).ok_or("Unknown pc vault.")?.mint.to_string();
+    let coin_vault = coin_vault.to_string();
+    let pc_vault = pc_vault.to_string();
+
+    let transfers = _get_token_transfers(instruction, context);
+    let (vault_in, vault_out, mint_in, mint_out, direction) =
+        if _get_single_transfer_amount(&transfers, &user_source_token_account, &pc_vault).is_some() {
+            (&pc_vault, &coin_vault, pc_mint.clone(), coin_mint.clone(), "coin")
+        } else {
+            (&coin_vault, &pc_vault, coin_mint.clone(), pc_mint.clone(), "pc")
+        };
+    let amount_in = _get_single_transfer_amount(&transfers, &user_source_token_account, vault_in)
+        .ok_or("Missing transfer to the pool.")?;
+    let amount_out = _get_single_transfer_amount(&transfers, vault_out, &user_destination_token_account)
+        .ok_or("Missing transfer from the pool.")?;
+
+    Ok(SwapEvent {
+        amm,
+        user,
+        mint_in,
+        mint_out,
+        amount_in,
+        amount_out,
+        direction: direction.to_string(),
+        pool_coin_amount: None,
+        pool_pc_amount: None,
+        coin_mint,
+        pc_mint,
+        decimals_in: None,
+        decimals_out: None,
+        user_source_token_account,
+        user_destination_token_account,
+        coin_vault,
+        pc_vault,
+        market,
+        amount_in_exact: Some(amount_in),
+        amount_out_exact: Some(amount_out),
+        price: None,
+        ray_log_amount_in: None,
+        ray_log_amount_out: None,
+        balances_consistent: false,
+        balance_check_available: false,
+        pool_type: PoolType::Stable.into(),
+    })
+}
+
+/// (source, destination, amount) of the Token and Token-2022 transfers directly under `instruction`.
+fn _get_token_transfers<'a>(instruction: &StructuredInstruction<'a>, context: &TransactionContext) -> Vec<(String, String, u64)> {
+    instruction.inner_instructions().iter().filter_map(|inner_instruction| {
+        if let Ok(transfer) = spl_token_substream::parse_transfer_instruction(inner_instruction, context) {
+            return Some((transfer.source?.address, transfer.destination?.address, transfer.amount));
+        }
+        match token_2022_substream::parse_instruction(inner_instruction, context) {
+            Ok(Some(Token2022Event::Transfer(transfer))) => Some((transfer.source, transfer.destination, transfer.amount)),
+            _ => None,
+        }
+    }).collect()
+}
+
+fn _get_single_transfer_amount(transfers: &[(String, String, u64)], source: &str, destination: &str) -> Option<u64> {
+    let mut matching = transfers.iter().filter(|(from, to, _)| from == source && to == destination);
+    match (matching.next(), matching.next()) {
+        (Some((_, _, amount)), None) => Some(*amount),
+        _ => None,
+    }
+}
+
+pub fn parse_serum_block(block: &Block) -> Vec<SerumDexTransactionEvents> {
+    let mut block_events: Vec<SerumDexTransactionEvents> = Vec::new();
+    for transaction in block.transactions.iter() {
+        if let Ok(events) = parse_serum_transaction(transaction) {
+            if !events.is_empty() {
+                block_events.push(SerumDexTransactionEvents {
+                    signature: utils::transaction::get_signature(&transaction),
+                    events,
+                });
+            }
+        }
+    }
+    block_events
+}
+
+/// Parses the DEX instructions of `transaction`, whether top-level or invoked by another
+/// program such as Raydium AMM v4.
+pub fn parse_serum_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<SerumDexEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new());
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new());
+    }
+
+    let mut events: Vec<SerumDexEvent> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+    _collect_serum_events(&instructions, 1, None, &context, &mut events);
+
+    Ok(events)
+}
+
+fn _collect_serum_events<'a>(
+    instructions: &[StructuredInstruction<'a>],
+    stack_height: u32,
+    invoking_program: Option<&Pubkey>,
+    context: &TransactionContext,
+    events: &mut Vec<SerumDexEvent>,
+) {
+    for instruction in instructions {
+        let program_id = instruction.program_id();
+        if SERUM_DEX_PROGRAM_IDS.contains(&program_id) {
+            match parse_serum_instruction(instruction, context) {
+                Ok(Some(event)) => events.push(SerumDexEvent {
+                    program: program_id.to_string(),
+                    stack_height,
+                    invoking_program: invoking_program.map(|program| program.to_string()),
+                    event: Some(event),
+                }),
+                Ok(None) => (),
+                Err(error) => substreams::log::println(format!("Failed to process instruction of transaction {}: {}", &context.signature, error))
+            }
+        }
+        _collect_serum_events(instruction.inner_instructions(), stack_height + 1, Some(&program_id), context, events);
+    }
+}
+
+pub fn parse_serum_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext
+) -> Result<Option<SerumDexEventOneof>, String> {
+    if !SERUM_DEX_PROGRAM_IDS.contains(&instruction.program_id()) {
+        return Err("Instruction does not originate from the Serum DEX Program.".into());
+    }
+    let unpacked = DexInstruction::unpack(&instruction.data())?;
+    match unpacked {
+        DexInstruction::NewOrderV3(order) => {
+            let event = _parse_serum_new_order_v3_instruction(instruction, context, &order)?;
+            Ok(Some(SerumDexEventOneof::NewOrderV3(event)))
+        },
+        DexInstruction::MatchOrders(limit) => {
+            let event = _parse_serum_match_orders_instruction(instruction, context, limit)?;
+            Ok(Some(SerumDexEventOneof::MatchOrders(event)))
+        },
+        DexInstruction::ConsumeEvents(limit) => {
+            let event = _parse_serum_consume_events_instruction(instruction, context, limit)?;
+            Ok(Some(SerumDexEventOneof::ConsumeEvents(event)))
+        },
+        DexInstruction::SettleFunds => {
+            let event = _parse_serum_settle_funds_instruction(instruction, context)?;
+            Ok(Some(SerumDexEventOneof::SettleFunds(event)))
+        },
+        DexInstruction::Unsupported(_) => Ok(None),
+    }
+}
+
+fn _parse_serum_new_order_v3_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    order: &NewOrderInstructionV3,
+) -> Result<SerumNewOrderV3Event, String> {
+    let market = _get_account(instruction, 0)?.to_string();
+    let open_orders = _get_account(instruction, 1)?.to_string();
+    let request_queue = _get_account(instruction, 2)?.to_string();
+    let event_queue = _get_account(instruction, 3)?.to_string();
+    let payer = _get_account(instruction, 6)?.to_string();
+    let owner = _get_account(instruction, 7)?.to_string();
+    let side = match order.side {
+        Side::Bid => SerumSide::Bid,
+        Side::Ask => SerumSide::Ask,
+    };
+    let order_type = match order.order_type {
+        OrderType::Limit => SerumOrderType::Limit,
+        OrderType::ImmediateOrCancel => SerumOrderType::ImmediateOrCancel,
+        OrderType::PostOnly => SerumOrderType::PostOnly,
+    };
+
+    Ok(SerumNewOrderV3Event {
+        market,
+        open_orders,
+        request_queue,
+        event_queue,
+        payer,
+        owner,
+        side: side.into(),
+        limit_price: order.limit_price,
+        max_coin_qty: order.max_coin_qty,
+        max_native_pc_qty_including_fees: order.max_native_pc_qty_including_fees,
+        order_type: order_type.into(),
+        client_order_id: order.client_order_id,
+        limit: order.limit.into(),
+    })
+}
+
+fn _parse_serum_match_orders_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    limit: u16,
+) -> Result<SerumMatchOrdersEvent, String> {
+    let market = _get_account(instruction, 0)?.to_string();
+    let request_queue = _get_account(instruction, 1)?.to_string();
+    let event_queue = _get_account(instruction, 2)?.to_string();
+
+    Ok(SerumMatchOrdersEvent {
+        market,
+        request_queue,
+        event_queue,
+        limit: limit.into(),
+    })
+}
+
+fn _parse_serum_consume_events_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    _context: &TransactionContext,
+    limit: u16,
+) -> Result<SerumConsumeEventsEvent, String> {
+    // The open orders accounts come first, followed by four fixed accounts.
+    let accounts = instruction.accounts();
+    let fixed_start = accounts.len().checked_sub(4).ok_or("Instruction is missing accounts.")?;
+    let market = accounts[fixed_start].to_string();
+    let event_queue = accounts[fixed_start + 1].to_string();
+    let open_orders = accounts[..fixed_start].iter().map(|account| account.to_string()).collect();
+
+    Ok(SerumConsumeEventsEvent {
+        market,
+        event_queue,
+        open_orders,
+        limit: limit.into(),
+    })
+}
+
+fn _parse_serum_settle_funds_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<SerumSettleFundsEvent, String> {
+    let market = _get_account(instruction, 0)?.to_string();
+    let open_orders = _get_account(instruction, 1)?.to_string();
+    let owner = _get_account(instruction, 2)?.to_string();
+    let coin_vault = _get_account(instruction, 3)?.to_string();
+    let pc_vault = _get_account(instruction, 4)?.to_string();
+    let coin_wallet = _get_account(instruction, 5)?.to_string();
+    let pc_wallet = _get_account(instruction, 6)?.to_string();
+
+    let transfers = _get_token_transfers(instruction, context);
+    let coin_amount = _get_single_transfer_amount(&transfers, &coin_vault, &coin_wallet);
+    let pc_amount = _get_single_transfer_amount(&transfers, &pc_vault, &pc_wallet);
+
+    Ok(SerumSettleFundsEvent {
+        market,
+        open_orders,
+        owner,
+        coin_wallet,
+        pc_wallet,
+        coin_amount,
+        pc_amount,
+    })
+}
+
 fn parse_log(instruction: &StructuredInstruction) -> Result<RayLog, String> {
     let re = regex::Regex::new(r"ray_log: (.+)").unwrap();
     let log_message = instruction.logs().iter().rev().find_map(|log| {
@@ -320,7 +1278,7 @@ fn parse_log(instruction: &StructuredInstruction) -> Result<RayLog, String> {
     });
     match log_message {
         Some(message) => match re.captures(message.as_str()) {
-            Some(captures) => Ok(decode_ray_log(&captures[1])),
+            Some(captures) => decode_ray_log(&captures[1]),
             None => return Err("Failed to capture log message".to_string()),
         },
         None => return Err("Log message not found".to_string()),
@@ -16,7 +16,7 @@ pub struct RaydiumAmmTransactionEvents {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RaydiumAmmEvent {
-    #[prost(oneof="raydium_amm_event::Event", tags="1, 2, 3, 4, 5")]
+    #[prost(oneof="raydium_amm_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16")]
     pub event: ::core::option::Option<raydium_amm_event::Event>,
 }
 /// Nested message and enum types in `RaydiumAmmEvent`.
@@ -34,6 +34,28 @@ pub mod raydium_amm_event {
         WithdrawPnl(super::WithdrawPnlEvent),
         #[prost(message, tag="5")]
         Swap(super::SwapEvent),
+        #[prost(message, tag="6")]
+        ClmmSwap(super::ClmmSwapEvent),
+        #[prost(message, tag="7")]
+        SetParams(super::SetParamsEvent),
+        #[prost(message, tag="8")]
+        ClmmOpenPosition(super::ClmmOpenPositionEvent),
+        #[prost(message, tag="9")]
+        ClmmIncreaseLiquidity(super::ClmmIncreaseLiquidityEvent),
+        #[prost(message, tag="10")]
+        ClmmDecreaseLiquidity(super::ClmmDecreaseLiquidityEvent),
+        #[prost(message, tag="11")]
+        ClmmCollectFee(super::ClmmCollectFeeEvent),
+        #[prost(message, tag="12")]
+        ClmmCreatePool(super::ClmmCreatePoolEvent),
+        #[prost(message, tag="13")]
+        CpmmInitialize(super::CpmmInitializeEvent),
+        #[prost(message, tag="14")]
+        CpmmDeposit(super::CpmmDepositEvent),
+        #[prost(message, tag="15")]
+        CpmmWithdraw(super::CpmmWithdrawEvent),
+        #[prost(message, tag="16")]
+        CpmmSwap(super::CpmmSwapEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -59,6 +81,10 @@ pub struct InitializeEvent {
     pub nonce: u32,
     #[prost(string, optional, tag="10")]
     pub market: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="11")]
+    pub coin_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub pc_vault: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -85,6 +111,12 @@ pub struct DepositEvent {
     pub pool_coin_amount: ::core::option::Option<u64>,
     #[prost(uint64, optional, tag="11")]
     pub pool_lp_amount: ::core::option::Option<u64>,
+    #[prost(string, tag="12")]
+    pub coin_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub pc_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="14")]
+    pub market: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -111,6 +143,12 @@ pub struct WithdrawEvent {
     pub pool_coin_amount: ::core::option::Option<u64>,
     #[prost(uint64, optional, tag="11")]
     pub pool_lp_amount: ::core::option::Option<u64>,
+    #[prost(string, tag="12")]
+    pub coin_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub pc_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="14")]
+    pub market: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -153,5 +191,583 @@ pub struct SwapEvent {
     pub pc_mint: ::prost::alloc::string::String,
     #[prost(string, tag="11")]
     pub coin_mint: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag="12")]
+    pub decimals_in: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="13")]
+    pub decimals_out: ::core::option::Option<u32>,
+    #[prost(string, tag="14")]
+    pub user_source_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="15")]
+    pub user_destination_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="16")]
+    pub coin_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="17")]
+    pub pc_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="18")]
+    pub market: ::prost::alloc::string::String,
+    /// Amounts of the inner transfers between the user and the pool vaults. Unset unless exactly
+    /// one transfer matches each direction.
+    #[prost(uint64, optional, tag="19")]
+    pub amount_in_exact: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="20")]
+    pub amount_out_exact: ::core::option::Option<u64>,
+    /// Execution price as amount in per unit of amount out, both in UI units, with exactly 12
+    /// fractional digits (truncated). Unset when either amount is zero or decimals are unknown.
+    #[prost(string, optional, tag="21")]
+    pub price: ::core::option::Option<::prost::alloc::string::String>,
+    /// Executed amounts reported by the program in ray_log, the reference for the filled amounts.
+    /// Unset when the log is missing or truncated.
+    #[prost(uint64, optional, tag="22")]
+    pub ray_log_amount_in: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="23")]
+    pub ray_log_amount_out: ::core::option::Option<u64>,
+    /// Whether amountIn matches the decrease of userSourceTokenAccount and amountOut the increase
+    /// of userDestinationTokenAccount, over the whole transaction. Only meaningful when
+    /// balanceCheckAvailable, i.e. both accounts appear in the token balances.
+    #[prost(bool, tag="24")]
+    pub balances_consistent: bool,
+    #[prost(bool, tag="25")]
+    pub balance_check_available: bool,
+    /// AMM the swap went through. Stable-swap events have no ray_log or pool amounts, and take
+    /// their amounts from the inner transfers, so amountInExact and amountOutExact are always set.
+    #[prost(enumeration="PoolType", tag="26")]
+    pub pool_type: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClmmSwapEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub input_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub output_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub input_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub output_vault: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="7")]
+    pub tick_arrays: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, tag="8")]
+    pub amount: u64,
+    #[prost(uint64, tag="9")]
+    pub other_amount_threshold: u64,
+    /// u128 rendered in decimal.
+    #[prost(string, tag="10")]
+    pub sqrt_price_limit_x64: ::prost::alloc::string::String,
+    #[prost(bool, tag="11")]
+    pub is_base_input: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClmmOpenPositionEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub position_nft_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub position_nft_account: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub personal_position: ::prost::alloc::string::String,
+    #[prost(int32, tag="7")]
+    pub tick_lower_index: i32,
+    #[prost(int32, tag="8")]
+    pub tick_upper_index: i32,
+    /// Lower and upper tick arrays of the position.
+    #[prost(string, repeated, tag="9")]
+    pub tick_arrays: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// u128 rendered in decimal.
+    #[prost(string, tag="10")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub amount0_max: u64,
+    #[prost(uint64, tag="12")]
+    pub amount1_max: u64,
+    #[prost(string, tag="13")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="14")]
+    pub token_vault1: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClmmIncreaseLiquidityEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub position_nft_account: ::prost::alloc::string::String,
+    /// Resolved from the transaction's token accounts; unset when the NFT account isn't listed there.
+    #[prost(string, optional, tag="4")]
+    pub position_nft_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub personal_position: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="6")]
+    pub tick_arrays: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="7")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub amount0_max: u64,
+    #[prost(uint64, tag="9")]
+    pub amount1_max: u64,
+    #[prost(string, tag="10")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub token_vault1: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClmmDecreaseLiquidityEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub position_nft_account: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub position_nft_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub personal_position: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="6")]
+    pub tick_arrays: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="7")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub amount0_min: u64,
+    #[prost(uint64, tag="9")]
+    pub amount1_min: u64,
+    #[prost(string, tag="10")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub token_vault1: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub recipient_token_account0: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub recipient_token_account1: ::prost::alloc::string::String,
+}
+/// Protocol or fund fee collection by the pool admin.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClmmCollectFeeEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token_vault1: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub recipient_token_account0: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub recipient_token_account1: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub amount0_requested: u64,
+    #[prost(uint64, tag="8")]
+    pub amount1_requested: u64,
+    #[prost(bool, tag="9")]
+    pub is_fund_fee: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClmmCreatePoolEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool_creator: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub amm_config: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token_mint0: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token_mint1: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub token_vault1: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub sqrt_price_x64: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub open_time: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CpmmInitializeEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub creator: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub amm_config: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub lp_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token_mint0: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub token_mint1: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub token_vault1: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub creator_token_account0: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub creator_token_account1: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub init_amount0: u64,
+    #[prost(uint64, tag="12")]
+    pub init_amount1: u64,
+    #[prost(uint64, tag="13")]
+    pub open_time: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CpmmDepositEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub lp_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub owner_lp_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub user_token_account0: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub user_token_account1: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub token_vault1: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub token_mint0: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub token_mint1: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub lp_token_amount: u64,
+    #[prost(uint64, tag="12")]
+    pub maximum_amount0: u64,
+    #[prost(uint64, tag="13")]
+    pub maximum_amount1: u64,
+    /// Amounts of the inner transfers into the vaults, unset when no single matching transfer is found.
+    #[prost(uint64, optional, tag="14")]
+    pub amount0_exact: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="15")]
+    pub amount1_exact: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CpmmWithdrawEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub lp_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub owner_lp_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub user_token_account0: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub user_token_account1: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub token_vault0: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub token_vault1: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub token_mint0: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub token_mint1: ::prost::alloc::string::String,
+    #[prost(uint64, tag="11")]
+    pub lp_token_amount: u64,
+    #[prost(uint64, tag="12")]
+    pub minimum_amount0: u64,
+    #[prost(uint64, tag="13")]
+    pub minimum_amount1: u64,
+    #[prost(uint64, optional, tag="14")]
+    pub amount0_exact: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="15")]
+    pub amount1_exact: ::core::option::Option<u64>,
+}
+/// Both swap_base_input and swap_base_output. amount is the fixed side (input when isBaseInput)
+/// and otherAmountThreshold the slippage bound on the other side.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CpmmSwapEvent {
+    #[prost(string, tag="1")]
+    pub pool_state: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub amm_config: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub input_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub output_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub input_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub output_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub input_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub output_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub amount: u64,
+    #[prost(uint64, tag="11")]
+    pub other_amount_threshold: u64,
+    #[prost(bool, tag="12")]
+    pub is_base_input: bool,
+    /// Transfer amounts before any Token-2022 transfer fee is withheld.
+    #[prost(uint64, optional, tag="13")]
+    pub amount_in_exact: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="14")]
+    pub amount_out_exact: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetParamsEvent {
+    #[prost(string, tag="1")]
+    pub amm: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub admin: ::core::option::Option<::prost::alloc::string::String>,
+    /// AmmParams selector, e.g. 9 for Fees or 10 for AmmOwner.
+    #[prost(uint32, tag="4")]
+    pub param: u32,
+    #[prost(uint64, optional, tag="5")]
+    pub value: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="6")]
+    pub new_pubkey: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="7")]
+    pub fees: ::core::option::Option<AmmFees>,
+    #[prost(uint64, optional, tag="8")]
+    pub last_order_numerator: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="9")]
+    pub last_order_denominator: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AmmFees {
+    #[prost(uint64, tag="1")]
+    pub min_separate_numerator: u64,
+    #[prost(uint64, tag="2")]
+    pub min_separate_denominator: u64,
+    #[prost(uint64, tag="3")]
+    pub trade_fee_numerator: u64,
+    #[prost(uint64, tag="4")]
+    pub trade_fee_denominator: u64,
+    #[prost(uint64, tag="5")]
+    pub pnl_numerator: u64,
+    #[prost(uint64, tag="6")]
+    pub pnl_denominator: u64,
+    #[prost(uint64, tag="7")]
+    pub swap_fee_numerator: u64,
+    #[prost(uint64, tag="8")]
+    pub swap_fee_denominator: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerumDexBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<SerumDexTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerumDexTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<SerumDexEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerumDexEvent {
+    #[prost(string, tag="1")]
+    pub program: ::prost::alloc::string::String,
+    /// 1 for top-level instructions, greater when invoked through CPI.
+    #[prost(uint32, tag="2")]
+    pub stack_height: u32,
+    /// Program that invoked the DEX, unset for top-level instructions.
+    #[prost(string, optional, tag="3")]
+    pub invoking_program: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(oneof="serum_dex_event::Event", tags="4, 5, 6, 7")]
+    pub event: ::core::option::Option<serum_dex_event::Event>,
+}
+/// Nested message and enum types in `SerumDexEvent`.
+pub mod serum_dex_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="4")]
+        NewOrderV3(super::SerumNewOrderV3Event),
+        #[prost(message, tag="5")]
+        MatchOrders(super::SerumMatchOrdersEvent),
+        #[prost(message, tag="6")]
+        ConsumeEvents(super::SerumConsumeEventsEvent),
+        #[prost(message, tag="7")]
+        SettleFunds(super::SerumSettleFundsEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerumNewOrderV3Event {
+    #[prost(string, tag="1")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub open_orders: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub request_queue: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub event_queue: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(enumeration="SerumSide", tag="7")]
+    pub side: i32,
+    #[prost(uint64, tag="8")]
+    pub limit_price: u64,
+    #[prost(uint64, tag="9")]
+    pub max_coin_qty: u64,
+    #[prost(uint64, tag="10")]
+    pub max_native_pc_qty_including_fees: u64,
+    #[prost(enumeration="SerumOrderType", tag="11")]
+    pub order_type: i32,
+    #[prost(uint64, tag="12")]
+    pub client_order_id: u64,
+    #[prost(uint32, tag="13")]
+    pub limit: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerumMatchOrdersEvent {
+    #[prost(string, tag="1")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub request_queue: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub event_queue: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub limit: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerumConsumeEventsEvent {
+    #[prost(string, tag="1")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub event_queue: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="3")]
+    pub open_orders: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint32, tag="4")]
+    pub limit: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerumSettleFundsEvent {
+    #[prost(string, tag="1")]
+    pub market: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub open_orders: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub coin_wallet: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub pc_wallet: ::prost::alloc::string::String,
+    /// Amounts of the inner transfers out of the vaults, unset when the leg settled nothing.
+    #[prost(uint64, optional, tag="6")]
+    pub coin_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="7")]
+    pub pc_amount: ::core::option::Option<u64>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PoolType {
+    AmmV4 = 0,
+    Stable = 1,
+}
+impl PoolType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PoolType::AmmV4 => "AMM_V4",
+            PoolType::Stable => "STABLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AMM_V4" => Some(Self::AmmV4),
+            "STABLE" => Some(Self::Stable),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SerumSide {
+    Bid = 0,
+    Ask = 1,
+}
+impl SerumSide {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SerumSide::Bid => "BID",
+            SerumSide::Ask => "ASK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BID" => Some(Self::Bid),
+            "ASK" => Some(Self::Ask),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SerumOrderType {
+    Limit = 0,
+    ImmediateOrCancel = 1,
+    PostOnly = 2,
+}
+impl SerumOrderType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SerumOrderType::Limit => "LIMIT",
+            SerumOrderType::ImmediateOrCancel => "IMMEDIATE_OR_CANCEL",
+            SerumOrderType::PostOnly => "POST_ONLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LIMIT" => Some(Self::Limit),
+            "IMMEDIATE_OR_CANCEL" => Some(Self::ImmediateOrCancel),
+            "POST_ONLY" => Some(Self::PostOnly),
+            _ => None,
+        }
+    }
 }
 // @@protoc_insertion_point(module)
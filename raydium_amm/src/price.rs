@@ -0,0 +1,28 @@
+/// Number of fractional digits of the prices returned by `execution_price`.
+pub const PRICE_DECIMALS: u32 = 12;
+
+/// Amount in per unit of amount out, both scaled by their decimals, as a decimal string with
+/// `PRICE_DECIMALS` fractional digits. Computed with integer long division so it is the same on
+/// every platform; the last digit is truncated, not rounded.
+///
+/// Returns `None` when either amount is zero or the scaled amounts don't fit in a `u128`.
+pub fn execution_price(amount_in: u64, decimals_in: u32, amount_out: u64, decimals_out: u32) -> Option<String> {
+    if amount_in == 0 || amount_out == 0 {
+        return None;
+    }
+    let numerator = (amount_in as u128).checked_mul(10u128.checked_pow(decimals_out)?)?;
+    let denominator = (amount_out as u128).checked_mul(10u128.checked_pow(decimals_in)?)?;
+    // The remainder is below the denominator, so multiplying it by 10 can only overflow for
+    // denominators above u128::MAX / 10.
+    denominator.checked_mul(10)?;
+
+    let integer = numerator / denominator;
+    let mut remainder = numerator % denominator;
+    let mut fraction = String::with_capacity(PRICE_DECIMALS as usize);
+    for _ in 0..PRICE_DECIMALS {
+        remainder *= 10;
+        fraction.push(char::from(b'0' + (remainder / denominator) as u8));
+        remainder %= denominator;
+    }
+    Some(format!("{}.{}", integer, fraction))
+}
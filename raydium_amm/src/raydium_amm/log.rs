@@ -128,28 +128,80 @@ pub struct SwapBaseOutLog {
     pub deduct_in: u64,
 }
 
-pub fn decode_ray_log(log: &str) -> RayLog {
-    let bytes = base64::decode(log).unwrap();
-    match LogType::from_u8(bytes[0]) {
-        LogType::Init => {
-            let log: InitLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::Init(log);
-        }
-        LogType::Deposit => {
-            let log: DepositLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::Deposit(log);
-        }
-        LogType::Withdraw => {
-            let log: WithdrawLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::Withdraw(log);
-        }
-        LogType::SwapBaseIn => {
-            let log: SwapBaseInLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::SwapBaseIn(log);
-        }
-        LogType::SwapBaseOut => {
-            let log: SwapBaseOutLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::SwapBaseOut(log);
-        }
+/// Decodes the base64 payload of a `ray_log` program log.
+pub fn decode_ray_log(log: &str) -> Result<RayLog, String> {
+    let bytes = base64::decode(log.trim()).map_err(|_| "Invalid ray_log base64.")?;
+    let log_type = *bytes.first().ok_or("Empty ray_log.")?;
+    // Matched on the raw byte, since LogType::from_u8 panics on unknown types.
+    let log = match log_type {
+        0 => bincode::deserialize(&bytes).map(RayLog::Init),
+        1 => bincode::deserialize(&bytes).map(RayLog::Deposit),
+        2 => bincode::deserialize(&bytes).map(RayLog::Withdraw),
+        3 => bincode::deserialize(&bytes).map(RayLog::SwapBaseIn),
+        4 => bincode::deserialize(&bytes).map(RayLog::SwapBaseOut),
+        _ => return Err(format!("Unknown ray_log type {}.", log_type)),
+    };
+    log.map_err(|_| format!("Truncated ray_log of type {}.", log_type))
+}
+
+/// Decodes the swap `ray_log` in a program log line, e.g. `Program log: ray_log: <base64>`.
+/// Returns `None` for other lines, other ray_log types and undecodable payloads.
+pub fn parse_ray_log(log: &str) -> Option<RayLog> {
+    let (_, payload) = log.split_once("ray_log: ")?;
+    match decode_ray_log(payload).ok()? {
+        log @ (RayLog::SwapBaseIn(_) | RayLog::SwapBaseOut(_)) => Some(log),
+        _ => None,
+    }
+}
+
+/// Decodes every `ray_log` among the log messages of a transaction, in order. Logs that fail to
+/// decode are skipped.
+pub fn decode_ray_logs(log_messages: &[String]) -> Vec<RayLog> {
+    log_messages.iter()
+        .filter_map(|message| message.strip_prefix("Program log: ray_log: "))
+        .filter_map(|log| decode_ray_log(log).ok())
+        .collect()
+}
+
+/// Direction of a swap in `ray_log`, as `SwapDirection` in the AMM program.
+pub const SWAP_DIRECTION_PC_TO_COIN: u64 = 1;
+pub const SWAP_DIRECTION_COIN_TO_PC: u64 = 2;
+
+/// The executed amounts and pool reserves of a swap, common to both swap logs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SwapLog {
+    pub direction: u64,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub pool_coin_before: u64,
+    pub pool_pc_before: u64,
+    /// Reserves before the swap plus the amount in and minus the amount out, fees included.
+    pub pool_coin_after: u64,
+    pub pool_pc_after: u64,
+}
+
+impl RayLog {
+    /// The swap described by a `SwapBaseIn` or `SwapBaseOut` log. `None` for other logs and
+    /// unknown directions.
+    pub fn swap(&self) -> Option<SwapLog> {
+        let (direction, amount_in, amount_out, pool_coin, pool_pc) = match self {
+            RayLog::SwapBaseIn(log) => (log.direction, log.amount_in, log.out_amount, log.pool_coin, log.pool_pc),
+            RayLog::SwapBaseOut(log) => (log.direction, log.deduct_in, log.amount_out, log.pool_coin, log.pool_pc),
+            _ => return None,
+        };
+        let (pool_coin_after, pool_pc_after) = match direction {
+            SWAP_DIRECTION_PC_TO_COIN => (pool_coin.checked_sub(amount_out)?, pool_pc.checked_add(amount_in)?),
+            SWAP_DIRECTION_COIN_TO_PC => (pool_coin.checked_add(amount_in)?, pool_pc.checked_sub(amount_out)?),
+            _ => return None,
+        };
+        Some(SwapLog {
+            direction,
+            amount_in,
+            amount_out,
+            pool_coin_before: pool_coin,
+            pool_pc_before: pool_pc,
+            pool_coin_after,
+            pool_pc_after,
+        })
     }
 }
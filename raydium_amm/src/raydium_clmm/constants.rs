@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey = Pubkey(b58!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"));
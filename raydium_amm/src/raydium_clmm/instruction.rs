@@ -0,0 +1,130 @@
+use borsh::BorshDeserialize;
+
+#[derive(Debug)]
+pub enum ClmmInstruction {
+    Swap(SwapInstruction),
+    SwapV2(SwapInstruction),
+    OpenPosition(OpenPositionInstruction),
+    OpenPositionV2(OpenPositionInstruction),
+    OpenPositionWithToken22Nft(OpenPositionInstruction),
+    IncreaseLiquidity(IncreaseLiquidityInstruction),
+    IncreaseLiquidityV2(IncreaseLiquidityInstruction),
+    DecreaseLiquidity(DecreaseLiquidityInstruction),
+    DecreaseLiquidityV2(DecreaseLiquidityInstruction),
+    CollectProtocolFee(CollectFeeInstruction),
+    CollectFundFee(CollectFeeInstruction),
+    CreatePool(CreatePoolInstruction),
+    Unknown,
+}
+
+impl ClmmInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < 8 {
+            return Err("Instruction data is shorter than the discriminator.");
+        }
+        let (tag, data) = data.split_at(8);
+        match tag {
+            [248, 198, 158, 145, 225, 117, 135, 200] => Ok(Self::Swap(SwapInstruction::unpack(data)?)),
+            [43, 4, 237, 11, 26, 201, 30, 98] => Ok(Self::SwapV2(SwapInstruction::unpack(data)?)),
+            [135, 128, 47, 77, 15, 152, 240, 49] => Ok(Self::OpenPosition(OpenPositionInstruction::unpack(data)?)),
+            [77, 184, 74, 214, 112, 86, 241, 199] => Ok(Self::OpenPositionV2(OpenPositionInstruction::unpack(data)?)),
+            [77, 255, 174, 82, 125, 29, 201, 46] => Ok(Self::OpenPositionWithToken22Nft(OpenPositionInstruction::unpack(data)?)),
+            [46, 156, 243, 118, 13, 205, 251, 178] => Ok(Self::IncreaseLiquidity(IncreaseLiquidityInstruction::unpack(data)?)),
+            [133, 29, 89, 223, 69, 238, 176, 10] => Ok(Self::IncreaseLiquidityV2(IncreaseLiquidityInstruction::unpack(data)?)),
+            [160, 38, 208, 111, 104, 91, 44, 1] => Ok(Self::DecreaseLiquidity(DecreaseLiquidityInstruction::unpack(data)?)),
+            [58, 127, 188, 62, 79, 82, 196, 96] => Ok(Self::DecreaseLiquidityV2(DecreaseLiquidityInstruction::unpack(data)?)),
+            [136, 136, 252, 221, 194, 66, 126, 89] => Ok(Self::CollectProtocolFee(CollectFeeInstruction::unpack(data)?)),
+            [167, 138, 78, 149, 223, 194, 6, 126] => Ok(Self::CollectFundFee(CollectFeeInstruction::unpack(data)?)),
+            [233, 146, 209, 142, 207, 104, 64, 188] => Ok(Self::CreatePool(CreatePoolInstruction::unpack(data)?)),
+            _ => Ok(Self::Unknown),
+        }
+    }
+}
+
+/// Arguments shared by `swap` and `swap_v2`.
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapInstruction {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit_x64: u128,
+    pub is_base_input: bool,
+}
+
+impl SwapInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapInstruction.")
+    }
+}
+
+/// Arguments shared by `open_position`, `open_position_v2` and `open_position_with_token22_nft`.
+/// The trailing `with_metadata` and `base_flag` of the latter two are not decoded.
+#[derive(Debug, BorshDeserialize)]
+pub struct OpenPositionInstruction {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+}
+
+impl OpenPositionInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize OpenPositionInstruction.")
+    }
+}
+
+/// Arguments shared by `increase_liquidity` and `increase_liquidity_v2`, whose trailing
+/// `base_flag` is not decoded.
+#[derive(Debug, BorshDeserialize)]
+pub struct IncreaseLiquidityInstruction {
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+}
+
+impl IncreaseLiquidityInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize IncreaseLiquidityInstruction.")
+    }
+}
+
+/// Arguments shared by `decrease_liquidity` and `decrease_liquidity_v2`.
+#[derive(Debug, BorshDeserialize)]
+pub struct DecreaseLiquidityInstruction {
+    pub liquidity: u128,
+    pub amount_0_min: u64,
+    pub amount_1_min: u64,
+}
+
+impl DecreaseLiquidityInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize DecreaseLiquidityInstruction.")
+    }
+}
+
+/// Arguments shared by `collect_protocol_fee` and `collect_fund_fee`.
+#[derive(Debug, BorshDeserialize)]
+pub struct CollectFeeInstruction {
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+}
+
+impl CollectFeeInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize CollectFeeInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct CreatePoolInstruction {
+    pub sqrt_price_x64: u128,
+    pub open_time: u64,
+}
+
+impl CreatePoolInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize CreatePoolInstruction.")
+    }
+}
@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey = Pubkey(b58!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"));
@@ -0,0 +1,91 @@
+use borsh::BorshDeserialize;
+
+#[derive(Debug)]
+pub enum CpmmInstruction {
+    Initialize(InitializeInstruction),
+    Deposit(DepositInstruction),
+    Withdraw(WithdrawInstruction),
+    SwapBaseInput(SwapBaseInputInstruction),
+    SwapBaseOutput(SwapBaseOutputInstruction),
+    Unknown,
+}
+
+impl CpmmInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < 8 {
+            return Err("Instruction data is shorter than the discriminator.");
+        }
+        let (tag, data) = data.split_at(8);
+        match tag {
+            [175, 175, 109, 31, 13, 152, 155, 237] => Ok(Self::Initialize(InitializeInstruction::unpack(data)?)),
+            [242, 35, 198, 137, 82, 225, 242, 182] => Ok(Self::Deposit(DepositInstruction::unpack(data)?)),
+            [183, 18, 70, 156, 148, 109, 161, 34] => Ok(Self::Withdraw(WithdrawInstruction::unpack(data)?)),
+            [143, 190, 90, 218, 196, 30, 51, 222] => Ok(Self::SwapBaseInput(SwapBaseInputInstruction::unpack(data)?)),
+            [55, 217, 98, 86, 163, 74, 180, 173] => Ok(Self::SwapBaseOutput(SwapBaseOutputInstruction::unpack(data)?)),
+            _ => Ok(Self::Unknown),
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct InitializeInstruction {
+    pub init_amount_0: u64,
+    pub init_amount_1: u64,
+    pub open_time: u64,
+}
+
+impl InitializeInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize InitializeInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct DepositInstruction {
+    pub lp_token_amount: u64,
+    pub maximum_token_0_amount: u64,
+    pub maximum_token_1_amount: u64,
+}
+
+impl DepositInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize DepositInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct WithdrawInstruction {
+    pub lp_token_amount: u64,
+    pub minimum_token_0_amount: u64,
+    pub minimum_token_1_amount: u64,
+}
+
+impl WithdrawInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize WithdrawInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapBaseInputInstruction {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+impl SwapBaseInputInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapBaseInputInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapBaseOutputInstruction {
+    pub max_amount_in: u64,
+    pub amount_out: u64,
+}
+
+impl SwapBaseOutputInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapBaseOutputInstruction.")
+    }
+}
@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const RAYDIUM_STABLE_PROGRAM_ID: Pubkey = Pubkey(b58!("5quBtoiQqxF9Jv6KYKctB59NT3gtJD2Y65kdnB1Uev3h"));
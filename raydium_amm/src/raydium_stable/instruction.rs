@@ -0,0 +1,45 @@
+use borsh::BorshDeserialize;
+
+/// Instructions of the Raydium stable-swap AMM. It keeps the single-byte tags of AMM v4 for
+/// swaps; its other instructions are not decoded.
+#[derive(Debug)]
+pub enum StableInstruction {
+    SwapBaseIn(SwapBaseInInstruction),
+    SwapBaseOut(SwapBaseOutInstruction),
+    Unknown,
+}
+
+impl StableInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        let (&tag, data) = data.split_first().ok_or("Invalid instruction data")?;
+        match tag {
+            9 => Ok(Self::SwapBaseIn(SwapBaseInInstruction::unpack(data)?)),
+            11 => Ok(Self::SwapBaseOut(SwapBaseOutInstruction::unpack(data)?)),
+            _ => Ok(Self::Unknown),
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapBaseInInstruction {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+impl SwapBaseInInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapBaseInInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapBaseOutInstruction {
+    pub max_amount_in: u64,
+    pub amount_out: u64,
+}
+
+impl SwapBaseOutInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapBaseOutInstruction.")
+    }
+}
@@ -0,0 +1,8 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const SERUM_DEX_V3_PROGRAM_ID: Pubkey = Pubkey(b58!("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin"));
+pub const OPENBOOK_DEX_PROGRAM_ID: Pubkey = Pubkey(b58!("srmqPvymJeFKQ4zGQed1GPNKvgGNdNBj6p2J6LcdrEhZ"));
+
+/// Serum v3 and its OpenBook fork share the same instruction set.
+pub const SERUM_DEX_PROGRAM_IDS: [Pubkey; 2] = [SERUM_DEX_V3_PROGRAM_ID, OPENBOOK_DEX_PROGRAM_ID];
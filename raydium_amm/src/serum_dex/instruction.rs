@@ -0,0 +1,162 @@
+#[derive(Debug)]
+pub enum DexInstruction {
+    /// 0. `[writable]` the market
+    /// 1. `[writable]` the OpenOrders account to use
+    /// 2. `[writable]` the request queue
+    /// 3. `[writable]` the event queue
+    /// 4. `[writable]` bids
+    /// 5. `[writable]` asks
+    /// 6. `[writable]` the (coin or price currency) account paying for the order
+    /// 7. `[signer]` owner of the OpenOrders account
+    /// 8. `[writable]` coin vault
+    /// 9. `[writable]` pc vault
+    /// 10. `[]` spl token program
+    /// 11. `[]` the rent sysvar
+    /// 12. `[]` (optional) the (M)SRM account used for fee discounts
+    NewOrderV3(NewOrderInstructionV3),
+    /// 0. `[writable]` market
+    /// 1. `[writable]` req_q
+    /// 2. `[writable]` event_q
+    /// 3. `[writable]` bids
+    /// 4. `[writable]` asks
+    MatchOrders(u16),
+    /// 0..n-4. `[writable]` OpenOrders accounts
+    /// n-4. `[writable]` market
+    /// n-3. `[writable]` event queue
+    /// n-2. `[writable]` coin fee receivable account
+    /// n-1. `[writable]` pc fee receivable account
+    ConsumeEvents(u16),
+    /// 0. `[writable]` market
+    /// 1. `[writable]` OpenOrders
+    /// 2. `[signer]` the OpenOrders owner
+    /// 3. `[writable]` coin vault
+    /// 4. `[writable]` pc vault
+    /// 5. `[writable]` coin wallet
+    /// 6. `[writable]` pc wallet
+    /// 7. `[]` vault signer
+    /// 8. `[]` spl token program
+    /// 9. `[writable]` (optional) referrer pc wallet
+    SettleFunds,
+    /// Any other instruction of the DEX, identified by its tag.
+    Unsupported(u32),
+}
+
+#[derive(Debug)]
+pub struct NewOrderInstructionV3 {
+    pub side: Side,
+    pub limit_price: u64,
+    pub max_coin_qty: u64,
+    pub max_native_pc_qty_including_fees: u64,
+    pub self_trade_behavior: u32,
+    pub order_type: OrderType,
+    pub client_order_id: u64,
+    pub limit: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum OrderType {
+    Limit,
+    ImmediateOrCancel,
+    PostOnly,
+}
+
+impl DexInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (&version, rest) = input.split_first().ok_or("Invalid instruction data")?;
+        if version != 0 {
+            return Err("Unsupported instruction version");
+        }
+        let (tag, rest) = Self::unpack_u32(rest)?;
+        Ok(match tag {
+            2 => {
+                let (limit, _rest) = Self::unpack_u16(rest)?;
+                Self::MatchOrders(limit)
+            }
+            3 => {
+                let (limit, _rest) = Self::unpack_u16(rest)?;
+                Self::ConsumeEvents(limit)
+            }
+            5 => Self::SettleFunds,
+            10 => {
+                let (side, rest) = Self::unpack_u32(rest)?;
+                let (limit_price, rest) = Self::unpack_u64(rest)?;
+                let (max_coin_qty, rest) = Self::unpack_u64(rest)?;
+                let (max_native_pc_qty_including_fees, rest) = Self::unpack_u64(rest)?;
+                let (self_trade_behavior, rest) = Self::unpack_u32(rest)?;
+                let (order_type, rest) = Self::unpack_u32(rest)?;
+                let (client_order_id, rest) = Self::unpack_u64(rest)?;
+                let (limit, _rest) = Self::unpack_u16(rest)?;
+                let side = match side {
+                    0 => Side::Bid,
+                    1 => Side::Ask,
+                    _ => return Err("Invalid order side"),
+                };
+                let order_type = match order_type {
+                    0 => OrderType::Limit,
+                    1 => OrderType::ImmediateOrCancel,
+                    2 => OrderType::PostOnly,
+                    _ => return Err("Invalid order type"),
+                };
+                Self::NewOrderV3(NewOrderInstructionV3 {
+                    side,
+                    limit_price,
+                    max_coin_qty,
+                    max_native_pc_qty_including_fees,
+                    self_trade_behavior,
+                    order_type,
+                    client_order_id,
+                    limit,
+                })
+            }
+            _ => Self::Unsupported(tag),
+        })
+    }
+
+    fn unpack_u16(input: &[u8]) -> Result<(u16, &[u8]), &'static str> {
+        if input.len() >= 2 {
+            let (amount, rest) = input.split_at(2);
+            let amount = amount
+                .get(..2)
+                .and_then(|slice| slice.try_into().ok())
+                .map(u16::from_le_bytes)
+                .ok_or("Invalid instruction data")?;
+            Ok((amount, rest))
+        } else {
+            Err("Invalid instruction data")
+        }
+    }
+
+    fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), &'static str> {
+        if input.len() >= 4 {
+            let (amount, rest) = input.split_at(4);
+            let amount = amount
+                .get(..4)
+                .and_then(|slice| slice.try_into().ok())
+                .map(u32::from_le_bytes)
+                .ok_or("Invalid instruction data")?;
+            Ok((amount, rest))
+        } else {
+            Err("Invalid instruction data")
+        }
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        if input.len() >= 8 {
+            let (amount, rest) = input.split_at(8);
+            let amount = amount
+                .get(..8)
+                .and_then(|slice| slice.try_into().ok())
+                .map(u64::from_le_bytes)
+                .ok_or("Invalid instruction data")?;
+            Ok((amount, rest))
+        } else {
+            Err("Invalid instruction data")
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use test_support::{address, key, TransactionBuilder};
+use raydium_amm_substream::parse_transaction;
+use raydium_amm_substream::pb::raydium_amm::{ClmmOpenPositionEvent, ClmmSwapEvent};
+use raydium_amm_substream::pb::raydium_amm::raydium_amm_event::Event;
+use raydium_amm_substream::raydium_clmm::constants::RAYDIUM_CLMM_PROGRAM_ID;
+
+const SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+const OPEN_POSITION: [u8; 8] = [135, 128, 47, 77, 15, 152, 240, 49];
+
+/// Account keys `[1; 32]` to `[19; 32]`, at indexes 0 to 18, then the CLMM program.
+const CLMM_PROGRAM: u32 = 19;
+
+fn builder() -> TransactionBuilder {
+    let mut account_keys: Vec<Vec<u8>> = (1..=19).map(key).collect();
+    account_keys.push(RAYDIUM_CLMM_PROGRAM_ID.0.to_vec());
+    TransactionBuilder::new(account_keys)
+}
+
+fn single_event(builder: TransactionBuilder) -> Event {
+    let events = parse_transaction(&builder.build()).unwrap();
+    assert_eq!(events.len(), 1);
+    events[0].event.clone().unwrap()
+}
+
+fn swap_v2_data() -> Vec<u8> {
+    let sqrt_price_limit_x64: u128 = 79_226_673_515_401_279_992_447_579_055;
+    [
+        SWAP_V2.to_vec(),
+        1_000_000u64.to_le_bytes().to_vec(),
+        990_000u64.to_le_bytes().to_vec(),
+        sqrt_price_limit_x64.to_le_bytes().to_vec(),
+        vec![1],
+    ].concat()
+}
+
+#[test]
+fn swap_v2() {
+    // payer, amm config, pool state, input and output token accounts, input and output vaults,
+    // observation state, Token, Token-2022 and Memo programs, input and output vault mints,
+    // then two tick arrays.
+    let accounts: Vec<u8> = (0..15).collect();
+    let transaction = builder().instruction(CLMM_PROGRAM, &accounts, swap_v2_data());
+    assert_eq!(single_event(transaction), Event::ClmmSwap(ClmmSwapEvent {
+        pool_state: address(3),
+        user: address(1),
+        input_token_account: address(4),
+        output_token_account: address(5),
+        input_vault: address(6),
+        output_vault: address(7),
+        tick_arrays: vec![address(14), address(15)],
+        amount: 1_000_000,
+        other_amount_threshold: 990_000,
+        sqrt_price_limit_x64: "79226673515401279992447579055".to_string(),
+        is_base_input: true,
+    }));
+}
+
+#[test]
+fn swap_v2_missing_accounts_is_skipped() {
+    let accounts: Vec<u8> = (0..12).collect();
+    let transaction = builder().instruction(CLMM_PROGRAM, &accounts, swap_v2_data()).build();
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
+
+#[test]
+fn open_position() {
+    let data = [
+        OPEN_POSITION.to_vec(),
+        (-120i32).to_le_bytes().to_vec(),
+        120i32.to_le_bytes().to_vec(),
+        (-3600i32).to_le_bytes().to_vec(),
+        0i32.to_le_bytes().to_vec(),
+        5_000_000u128.to_le_bytes().to_vec(),
+        2_000_000u64.to_le_bytes().to_vec(),
+        3_000_000u64.to_le_bytes().to_vec(),
+    ].concat();
+    // payer, position NFT owner, mint and account, metadata, pool state, protocol position,
+    // lower and upper tick arrays, personal position, both user token accounts, both vaults,
+    // then the Rent sysvar, System, Token, Associated Token and Metadata programs.
+    let accounts: Vec<u8> = (0..19).collect();
+    let transaction = builder().instruction(CLMM_PROGRAM, &accounts, data);
+    assert_eq!(single_event(transaction), Event::ClmmOpenPosition(ClmmOpenPositionEvent {
+        pool_state: address(6),
+        owner: address(2),
+        payer: address(1),
+        position_nft_mint: address(3),
+        position_nft_account: address(4),
+        personal_position: address(10),
+        tick_lower_index: -120,
+        tick_upper_index: 120,
+        tick_arrays: vec![address(8), address(9)],
+        liquidity: "5000000".to_string(),
+        amount0_max: 2_000_000,
+        amount1_max: 3_000_000,
+        token_vault0: address(13),
+        token_vault1: address(14),
+    }));
+}
@@ -0,0 +1,121 @@
+use test_support::{address, key, TransactionBuilder};
+use raydium_amm_substream::parse_transaction;
+use raydium_amm_substream::pb::raydium_amm::{CpmmInitializeEvent, CpmmSwapEvent};
+use raydium_amm_substream::pb::raydium_amm::raydium_amm_event::Event;
+use raydium_amm_substream::raydium_cpmm::constants::RAYDIUM_CPMM_PROGRAM_ID;
+use substreams_solana_utils::spl_token::TOKEN_PROGRAM_ID;
+
+const INITIALIZE: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+const SWAP_BASE_INPUT: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
+const SWAP_BASE_OUTPUT: [u8; 8] = [55, 217, 98, 86, 163, 74, 180, 173];
+
+// Account keys `[1; 32]` to `[13; 32]`, at indexes 0 to 12, then the Token and CPMM programs.
+const TOKEN_PROGRAM: u8 = 13;
+const CPMM_PROGRAM: u32 = 14;
+
+// Swap account indexes: the user, the pool authority, amm config and pool state, the user's input
+// and output token accounts, the input and output vaults, both token programs, both mints and the
+// observation state.
+const USER: u8 = 0;
+const AUTHORITY: u8 = 1;
+const INPUT_TOKEN_ACCOUNT: u8 = 4;
+const OUTPUT_TOKEN_ACCOUNT: u8 = 5;
+const INPUT_VAULT: u8 = 6;
+const OUTPUT_VAULT: u8 = 7;
+const INPUT_MINT: u8 = 11;
+const OUTPUT_MINT: u8 = 12;
+const SWAP_ACCOUNTS: [u8; 13] = [USER, AUTHORITY, 2, 3, INPUT_TOKEN_ACCOUNT, OUTPUT_TOKEN_ACCOUNT, INPUT_VAULT, OUTPUT_VAULT, TOKEN_PROGRAM, TOKEN_PROGRAM, 10, 11, 12];
+
+fn builder() -> TransactionBuilder {
+    let mut account_keys: Vec<Vec<u8>> = (1..=13).map(key).collect();
+    account_keys.push(TOKEN_PROGRAM_ID.0.to_vec());
+    account_keys.push(RAYDIUM_CPMM_PROGRAM_ID.0.to_vec());
+    TransactionBuilder::new(account_keys)
+}
+
+fn swap_data(discriminator: [u8; 8], amount: u64, other_amount_threshold: u64) -> Vec<u8> {
+    [discriminator.to_vec(), amount.to_le_bytes().to_vec(), other_amount_threshold.to_le_bytes().to_vec()].concat()
+}
+
+fn transfer_data(amount: u64) -> Vec<u8> {
+    [vec![3], amount.to_le_bytes().to_vec()].concat()
+}
+
+fn single_event(builder: TransactionBuilder) -> Event {
+    let events = parse_transaction(&builder.build()).unwrap();
+    assert_eq!(events.len(), 1);
+    events[0].event.clone().unwrap()
+}
+
+#[test]
+fn swap_base_input() {
+    let transaction = builder()
+        .instruction(CPMM_PROGRAM, &SWAP_ACCOUNTS, swap_data(SWAP_BASE_INPUT, 1_000_000, 1_900_000))
+        .inner_instruction(TOKEN_PROGRAM as u32, &[INPUT_TOKEN_ACCOUNT, INPUT_VAULT, USER], transfer_data(1_000_000), 2)
+        .inner_instruction(TOKEN_PROGRAM as u32, &[OUTPUT_VAULT, OUTPUT_TOKEN_ACCOUNT, AUTHORITY], transfer_data(1_950_000), 2)
+        .pre_token_balance(INPUT_TOKEN_ACCOUNT as u32, INPUT_MINT, 1, 1_000_000, 6)
+        .pre_token_balance(OUTPUT_TOKEN_ACCOUNT as u32, OUTPUT_MINT, 1, 0, 6)
+        .pre_token_balance(INPUT_VAULT as u32, INPUT_MINT, 2, 500_000_000, 6)
+        .pre_token_balance(OUTPUT_VAULT as u32, OUTPUT_MINT, 2, 900_000_000, 6)
+        .post_token_balance(INPUT_TOKEN_ACCOUNT as u32, INPUT_MINT, 1, 0, 6)
+        .post_token_balance(OUTPUT_TOKEN_ACCOUNT as u32, OUTPUT_MINT, 1, 1_950_000, 6)
+        .post_token_balance(INPUT_VAULT as u32, INPUT_MINT, 2, 501_000_000, 6)
+        .post_token_balance(OUTPUT_VAULT as u32, OUTPUT_MINT, 2, 898_050_000, 6);
+    assert_eq!(single_event(transaction), Event::CpmmSwap(CpmmSwapEvent {
+        pool_state: address(4),
+        amm_config: address(3),
+        user: address(1),
+        input_token_account: address(5),
+        output_token_account: address(6),
+        input_vault: address(7),
+        output_vault: address(8),
+        input_mint: address(11),
+        output_mint: address(12),
+        amount: 1_000_000,
+        other_amount_threshold: 1_900_000,
+        is_base_input: true,
+        amount_in_exact: Some(1_000_000),
+        amount_out_exact: Some(1_950_000),
+    }));
+}
+
+#[test]
+fn swap_base_output_without_transfers() {
+    // Without inner transfers, as in a failed simulation, the exact amounts stay unset.
+    let transaction = builder().instruction(CPMM_PROGRAM, &SWAP_ACCOUNTS, swap_data(SWAP_BASE_OUTPUT, 2_000_000, 1_100_000));
+    let Event::CpmmSwap(swap) = single_event(transaction) else { panic!("Expected CpmmSwap") };
+    assert_eq!(swap.amount, 2_000_000);
+    assert_eq!(swap.other_amount_threshold, 1_100_000);
+    assert!(!swap.is_base_input);
+    assert_eq!(swap.amount_in_exact, None);
+    assert_eq!(swap.amount_out_exact, None);
+}
+
+#[test]
+fn initialize() {
+    let data = [
+        INITIALIZE.to_vec(),
+        1_000_000_000u64.to_le_bytes().to_vec(),
+        2_000_000_000u64.to_le_bytes().to_vec(),
+        1_700_000_000u64.to_le_bytes().to_vec(),
+    ].concat();
+    // creator, amm config, authority, pool state, both mints, LP mint, the creator's token and
+    // LP accounts, both vaults and the pool creation fee receiver.
+    let accounts: Vec<u8> = (0..13).collect();
+    let transaction = builder().instruction(CPMM_PROGRAM, &accounts, data);
+    assert_eq!(single_event(transaction), Event::CpmmInitialize(CpmmInitializeEvent {
+        pool_state: address(4),
+        creator: address(1),
+        amm_config: address(2),
+        lp_mint: address(7),
+        token_mint0: address(5),
+        token_mint1: address(6),
+        token_vault0: address(11),
+        token_vault1: address(12),
+        creator_token_account0: address(8),
+        creator_token_account1: address(9),
+        init_amount0: 1_000_000_000,
+        init_amount1: 2_000_000_000,
+        open_time: 1_700_000_000,
+    }));
+}
@@ -0,0 +1,124 @@
+use test_support::{address, key, TransactionBuilder};
+use raydium_amm_substream::parse_transaction;
+use raydium_amm_substream::pb::raydium_amm::SwapEvent;
+use raydium_amm_substream::pb::raydium_amm::raydium_amm_event::Event;
+use raydium_amm_substream::raydium_amm::constants::RAYDIUM_AMM_PROGRAM_ID;
+use substreams_solana_utils::spl_token::TOKEN_PROGRAM_ID;
+
+// Account indexes: the user (fee payer), their pc (source) and coin (destination) token accounts,
+// the amm, its authority, open orders and target orders, the coin and pc vaults, the Serum market
+// accounts from the market program to the vault signer, then the Token and Raydium AMM programs.
+const USER: u8 = 0;
+const USER_SOURCE: u8 = 1;
+const USER_DESTINATION: u8 = 2;
+const AMM_AUTHORITY: u8 = 4;
+const COIN_VAULT: u8 = 7;
+const PC_VAULT: u8 = 8;
+const TOKEN_PROGRAM: u32 = 17;
+const RAYDIUM_AMM_PROGRAM: u32 = 18;
+
+const COIN_MINT: u8 = 30;
+const PC_MINT: u8 = 31;
+
+/// SwapBaseIn of 1 pc (6 decimals) for 0.5 coin (9 decimals).
+const AMOUNT_IN: u64 = 1_000_000;
+const AMOUNT_OUT: u64 = 500_000_000;
+
+fn swap_base_in_data(amount_in: u64, minimum_amount_out: u64) -> Vec<u8> {
+    [vec![9], amount_in.to_le_bytes().to_vec(), minimum_amount_out.to_le_bytes().to_vec()].concat()
+}
+
+fn transfer_data(amount: u64) -> Vec<u8> {
+    [vec![3], amount.to_le_bytes().to_vec()].concat()
+}
+
+/// A SwapBaseIn with the target orders account, before its inner transfers.
+fn swap_builder() -> TransactionBuilder {
+    swap_builder_receiving(AMOUNT_OUT)
+}
+
+/// `swap_builder` where the user's destination account ends up `received` richer.
+fn swap_builder_receiving(received: u64) -> TransactionBuilder {
+    let mut account_keys: Vec<Vec<u8>> = (1..=17).map(key).collect();
+    account_keys.push(TOKEN_PROGRAM_ID.0.to_vec());
+    account_keys.push(RAYDIUM_AMM_PROGRAM_ID.0.to_vec());
+    let swap_accounts = [17, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, USER_SOURCE, USER_DESTINATION, USER];
+    TransactionBuilder::new(account_keys)
+        .instruction(RAYDIUM_AMM_PROGRAM, &swap_accounts, swap_base_in_data(AMOUNT_IN, 1))
+        .pre_token_balance(USER_SOURCE as u32, PC_MINT, 1, 5_000_000, 6)
+        .pre_token_balance(USER_DESTINATION as u32, COIN_MINT, 1, 0, 9)
+        .pre_token_balance(COIN_VAULT as u32, COIN_MINT, 5, 1_000_000_000_000, 9)
+        .pre_token_balance(PC_VAULT as u32, PC_MINT, 5, 1_000_000_000, 6)
+        .post_token_balance(USER_SOURCE as u32, PC_MINT, 1, 5_000_000 - AMOUNT_IN, 6)
+        .post_token_balance(USER_DESTINATION as u32, COIN_MINT, 1, received, 9)
+        .post_token_balance(COIN_VAULT as u32, COIN_MINT, 5, 1_000_000_000_000 - AMOUNT_OUT, 9)
+        .post_token_balance(PC_VAULT as u32, PC_MINT, 5, 1_000_000_000 + AMOUNT_IN, 6)
+}
+
+/// The two transfers of a swap: the user pays the pc vault, then the coin vault pays the user.
+fn with_swap_transfers(builder: TransactionBuilder) -> TransactionBuilder {
+    builder
+        .inner_instruction(TOKEN_PROGRAM, &[USER_SOURCE, PC_VAULT, USER], transfer_data(AMOUNT_IN), 2)
+        .inner_instruction(TOKEN_PROGRAM, &[COIN_VAULT, USER_DESTINATION, AMM_AUTHORITY], transfer_data(AMOUNT_OUT), 2)
+}
+
+fn single_swap(builder: TransactionBuilder) -> SwapEvent {
+    let events = parse_transaction(&builder.build()).unwrap();
+    assert_eq!(events.len(), 1);
+    let Some(Event::Swap(swap)) = events[0].event.clone() else { panic!("Expected Swap") };
+    swap
+}
+
+#[test]
+fn swap_exact_amounts() {
+    let swap = single_swap(with_swap_transfers(swap_builder()));
+    assert_eq!(swap.amm, address(4));
+    assert_eq!(swap.user, address(1));
+    assert_eq!(swap.mint_in, address(PC_MINT));
+    assert_eq!(swap.mint_out, address(COIN_MINT));
+    assert_eq!(swap.amount_in, AMOUNT_IN);
+    assert_eq!(swap.amount_out, AMOUNT_OUT);
+    assert_eq!(swap.direction, "coin");
+    assert_eq!(swap.coin_vault, address(8));
+    assert_eq!(swap.pc_vault, address(9));
+    assert_eq!(swap.amount_in_exact, Some(AMOUNT_IN));
+    assert_eq!(swap.amount_out_exact, Some(AMOUNT_OUT));
+}
+
+#[test]
+fn swap_with_extra_inner_transfer() {
+    // A second transfer from the user into a vault, as a transfer-fee mint or a wrapping program
+    // could add, makes the input ambiguous. The output still has a single matching transfer.
+    let builder = swap_builder()
+        .inner_instruction(TOKEN_PROGRAM, &[USER_SOURCE, PC_VAULT, USER], transfer_data(1_000), 2);
+    let swap = single_swap(with_swap_transfers(builder));
+    assert_eq!(swap.amount_in, AMOUNT_IN);
+    assert_eq!(swap.amount_in_exact, None);
+    assert_eq!(swap.amount_out_exact, Some(AMOUNT_OUT));
+}
+
+#[test]
+fn swap_price_and_balance_check() {
+    let swap = single_swap(with_swap_transfers(swap_builder()));
+    assert_eq!((swap.decimals_in, swap.decimals_out), (Some(6), Some(9)));
+    // 1 pc for 0.5 coin.
+    assert_eq!(swap.price, Some("2.000000000000".to_string()));
+    // The source account lost 1_000_000 and the destination gained 500_000_000.
+    assert!(swap.balance_check_available);
+    assert!(swap.balances_consistent);
+}
+
+#[test]
+fn swap_balance_mismatch() {
+    // The destination account received less than the swapped amount.
+    let swap = single_swap(with_swap_transfers(swap_builder_receiving(AMOUNT_OUT - 1)));
+    assert!(swap.balance_check_available);
+    assert!(!swap.balances_consistent);
+}
+
+#[test]
+fn transaction_without_meta_is_skipped() {
+    let mut transaction = with_swap_transfers(swap_builder()).build();
+    transaction.meta = None;
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
@@ -10,18 +10,38 @@ use utils::spl_token::{TokenInstruction, TOKEN_PROGRAM_ID};
 use utils::pubkey::Pubkey;
 
 pub mod pb;
+pub mod options;
+pub mod token_balances;
+pub mod pubkey;
 use pb::spl_token::*;
 use pb::spl_token::spl_token_event::Event;
+pub use options::ParseOptions;
+pub use pubkey::{FromBase58, ParsePubkeyError};
 
 #[substreams::handlers::map]
-fn spl_token_events(block: Block) -> Result<SplTokenBlockEvents, Error> {
-    Ok(SplTokenBlockEvents { transactions: parse_block(&block)? })
+fn spl_token_events(params: String, block: Block) -> Result<SplTokenBlockEvents, Error> {
+    let options = ParseOptions::from_params(&params)?;
+    Ok(SplTokenBlockEvents { transactions: parse_block_with_options(&block, &options)? })
 }
 
 pub fn parse_block(block: &Block) -> Result<Vec<SplTokenTransactionEvents>, Error> {
+    parse_block_with_options(block, &ParseOptions::default())
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
+pub fn parse_block_with_options(block: &Block, options: &ParseOptions) -> Result<Vec<SplTokenTransactionEvents>, Error> {
     let mut transactions_events: Vec<SplTokenTransactionEvents> = Vec::new();
     for transaction in block.transactions() {
-        let events = parse_transaction(transaction)?;
+        let events = match parse_transaction_with_options(transaction, options) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
         if !events.is_empty() {
             transactions_events.push(SplTokenTransactionEvents {
                 signature: utils::transaction::get_signature(&transaction),
@@ -33,7 +53,15 @@ pub fn parse_block(block: &Block) -> Result<Vec<SplTokenTransactionEvents>, Erro
 }
 
 pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<SplTokenEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+    parse_transaction_with_options(transaction, &ParseOptions::default())
+}
+
+pub fn parse_transaction_with_options(transaction: &ConfirmedTransaction, options: &ParseOptions) -> Result<Vec<SplTokenEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
         return Ok(Vec::new())
     }
 
@@ -42,17 +70,62 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<SplTo
     let context = get_context(transaction)?;
     let instructions = get_structured_instructions(transaction)?;
 
-    for instruction in instructions.flattened().iter() {
+    for (i, instruction) in instructions.flattened().iter().enumerate() {
         if instruction.program_id() != TOKEN_PROGRAM_ID {
             continue;
         }
-        let event = parse_instruction(instruction, &context)?;
-        events.push(SplTokenEvent { event });
+        match parse_instruction(instruction, &context) {
+            Ok(event) => events.push(SplTokenEvent { event }),
+            Err(e) => {
+                let data_hex = to_hex(&instruction.data());
+                if options.emit_unknown_instructions && TokenInstruction::unpack(&instruction.data()).is_err() {
+                    let event = UnknownInstructionEvent {
+                        program_id: instruction.program_id().to_string(),
+                        data_hex,
+                        instruction_index: i as u32,
+                    };
+                    events.push(SplTokenEvent { event: Some(Event::UnknownInstruction(event)) });
+                } else {
+                    return Err(e.context(format!(
+                        "Failed to parse instruction {} of transaction {} (program {}, data {})",
+                        i, context.signature, instruction.program_id(), data_hex,
+                    )));
+                }
+            }
+        }
     }
 
+    enrich_transfer_events(&mut events, transaction);
+
     Ok(events)
 }
 
+/// Attaches mint, owners and decimals to transfer events from the transaction's token balances.
+pub fn enrich_transfer_events(events: &mut [SplTokenEvent], transaction: &ConfirmedTransaction) {
+    if !events.iter().any(|event| matches!(event.event, Some(Event::Transfer(_)))) {
+        return;
+    }
+    let infos = token_balances::token_accounts_info(transaction);
+    for event in events.iter_mut() {
+        let Some(Event::Transfer(transfer)) = event.event.as_mut() else {
+            continue;
+        };
+        let source = transfer.source.as_ref().and_then(|account| infos.get(&account.address));
+        let destination = transfer.destination.as_ref().and_then(|account| infos.get(&account.address));
+
+        transfer.source_owner = source.map(|info| info.owner.clone());
+        transfer.destination_owner = destination.map(|info| info.owner.clone());
+        transfer.mint = source.or(destination).map(|info| info.mint.clone());
+        if transfer.decimals.is_none() {
+            transfer.decimals = source.or(destination).map(|info| info.decimals);
+        }
+    }
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
@@ -165,6 +238,19 @@ pub fn parse_instruction<'a>(
     }.context("Failed to parse Token instruction")
 }
 
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, &'static str> {
+    instruction.accounts().get(index).cloned().ok_or("Instruction is missing accounts.")
+}
+
+fn _get_token_account(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    index: usize,
+) -> Result<TokenAccount, &'static str> {
+    let address = _get_account(instruction, index)?;
+    context.get_token_account(&address).map(|account| account.into()).ok_or("Token account not found in transaction.")
+}
+
 fn _parse_initialize_mint_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
@@ -172,7 +258,7 @@ fn _parse_initialize_mint_instruction(
     mint_authority: Pubkey,
     freeze_authority: Option<Pubkey>,
 ) -> Result<InitializeMintEvent, &'static str> {
-    let mint = instruction.accounts()[0].to_string();
+    let mint = _get_account(instruction, 0)?.to_string();
     let mint_authority = mint_authority.to_string();
     let freeze_authority = freeze_authority.map(|x| x.to_string());
 
@@ -189,10 +275,10 @@ fn _parse_initialize_account_instruction(
     context: &TransactionContext,
     _owner: Option<Pubkey>,
 ) -> Result<InitializeAccountEvent, &'static str> {
-    let account = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    let account = _get_token_account(instruction, context, 0)?;
 
     Ok(InitializeAccountEvent {
-        account: Some(account.into())
+        account: Some(account)
     })
 }
 
@@ -202,10 +288,10 @@ fn _parse_initialize_multisig_instruction(
     m: u8,
     rent_sysvar_account: bool,
 ) -> Result<InitializeMultisigEvent, &'static str> {
-    let multisig = instruction.accounts()[0].to_string();
+    let multisig = _get_account(instruction, 0)?.to_string();
     let mut signers: Vec<String> = Vec::new();
     let delta = if rent_sysvar_account { 2 } else { 1 };
-    for account in instruction.accounts()[delta..].iter() {
+    for account in instruction.accounts().get(delta..).ok_or("Instruction is missing accounts.")?.iter() {
         signers.push(account.to_string());
     }
 
@@ -223,15 +309,19 @@ fn _parse_transfer_instruction(
     expected_decimals: Option<u8>,
 ) -> Result<TransferEvent, &'static str> {
     let delta: usize = if expected_decimals.is_none() { 0 } else { 1 };
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let destination = context.get_token_account(&instruction.accounts()[1 + delta]).unwrap();
-    let authority = instruction.accounts()[2 + delta].to_string();
+    let source = _get_token_account(instruction, context, 0)?;
+    let destination = _get_token_account(instruction, context, 1 + delta)?;
+    let authority = _get_account(instruction, 2 + delta)?.to_string();
 
     Ok(TransferEvent {
-        source: Some(source.into()),
-        destination: Some(destination.into()),
+        source: Some(source),
+        destination: Some(destination),
         amount,
         authority,
+        mint: None,
+        source_owner: None,
+        destination_owner: None,
+        decimals: expected_decimals.map(u32::from),
     })
 }
 
@@ -242,11 +332,11 @@ fn _parse_approve_instruction(
     expected_decimals: Option<u8>,
 ) -> Result<ApproveEvent, &'static str> {
     let delta: usize = if expected_decimals.is_none() { 0 } else { 1 };
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let delegate = instruction.accounts()[1 + delta].to_string();
+    let source = _get_token_account(instruction, context, 0)?;
+    let delegate = _get_account(instruction, 1 + delta)?.to_string();
 
     Ok(ApproveEvent {
-        source: Some(source.into()),
+        source: Some(source),
         delegate,
         amount,
     })
@@ -256,10 +346,10 @@ fn _parse_revoke_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<RevokeEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    let source = _get_token_account(instruction, context, 0)?;
 
     Ok(RevokeEvent {
-        source: Some(source.into()),
+        source: Some(source),
     })
 }
 
@@ -269,8 +359,8 @@ fn _parse_set_authority_instruction(
     authority_type: utils::spl_token::AuthorityType,
     new_authority: Option<Pubkey>,
 ) -> Result<SetAuthorityEvent, &'static str> {
-    let mint = instruction.accounts()[0].to_string();
-    let authority = instruction.accounts()[1].to_string();
+    let mint = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
     let authority_type: i32 = match authority_type {
         utils::spl_token::AuthorityType::MintTokens => AuthorityType::MintTokens.into(),
         utils::spl_token::AuthorityType::FreezeAccount => AuthorityType::FreezeAccount.into(),
@@ -292,13 +382,13 @@ fn _parse_mint_to_instruction(
     context: &TransactionContext,
     amount: u64,
 ) -> Result<MintToEvent, &'static str> {
-    let mint = instruction.accounts()[0].to_string();
-    let destination = context.get_token_account(&instruction.accounts()[1]).unwrap();
-    let mint_authority = instruction.accounts()[2].to_string();
+    let mint = _get_account(instruction, 0)?.to_string();
+    let destination = _get_token_account(instruction, context, 1)?;
+    let mint_authority = _get_account(instruction, 2)?.to_string();
 
     Ok(MintToEvent {
         mint,
-        destination: Some(destination.into()),
+        destination: Some(destination),
         mint_authority,
         amount,
     })
@@ -309,12 +399,12 @@ fn _parse_burn_instruction(
     context: &TransactionContext,
     amount: u64,
 ) -> Result<BurnEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let _mint = instruction.accounts()[1].to_string();
-    let authority = instruction.accounts()[2].to_string();
+    let source = _get_token_account(instruction, context, 0)?;
+    let _mint = _get_account(instruction, 1)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
 
     Ok(BurnEvent {
-        source: Some(source.into()),
+        source: Some(source),
         authority,
         amount,
     })
@@ -324,12 +414,14 @@ fn _parse_close_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<CloseAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let destination = instruction.accounts()[1].to_string();
+    let source = _get_token_account(instruction, context, 0)?;
+    let destination = _get_account(instruction, 1)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
 
     Ok(CloseAccountEvent {
-        source: Some(source.into()),
+        source: Some(source),
         destination,
+        authority,
     })
 }
 
@@ -337,11 +429,11 @@ fn _parse_freeze_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<FreezeAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let freeze_authority = instruction.accounts()[1].to_string();
+    let source = _get_token_account(instruction, context, 0)?;
+    let freeze_authority = _get_account(instruction, 1)?.to_string();
 
     Ok(FreezeAccountEvent {
-        source: Some(source.into()),
+        source: Some(source),
         freeze_authority,
     })
 }
@@ -350,11 +442,11 @@ fn _parse_thaw_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<ThawAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let freeze_authority = instruction.accounts()[1].to_string();
+    let source = _get_token_account(instruction, context, 0)?;
+    let freeze_authority = _get_account(instruction, 1)?.to_string();
 
     Ok(ThawAccountEvent {
-        source: Some(source.into()),
+        source: Some(source),
         freeze_authority,
     })
 }
@@ -363,10 +455,10 @@ fn _parse_initialize_immutable_owner_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<InitializeImmutableOwnerEvent, &'static str> {
-    let account = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    let account = _get_token_account(instruction, context, 0)?;
 
     Ok(InitializeImmutableOwnerEvent {
-        account: Some(account.into()),
+        account: Some(account),
     })
 }
 
@@ -374,10 +466,10 @@ fn _parse_sync_native_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<SyncNativeEvent, &'static str> {
-    let account = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    let account = _get_token_account(instruction, context, 0)?;
 
     Ok(SyncNativeEvent {
-        account: Some(account.into())
+        account: Some(account)
     })
 }
 
@@ -522,3 +614,55 @@ impl<'a> From<&'a utils::spl_token::TokenAccount<'a>> for TokenAccount {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use substreams_solana::pb::sf::solana::r#type::v1::{Message, TokenBalance, Transaction, TransactionStatusMeta, UiTokenAmount};
+
+    fn address(n: u8) -> String {
+        bs58::encode([n; 32]).into_string()
+    }
+
+    fn token_account(n: u8, owner: u8) -> Option<TokenAccount> {
+        Some(TokenAccount { address: address(n), owner: address(owner), mint: address(4) })
+    }
+
+    #[test]
+    fn enrich_transfer_with_destination_only_in_token_balances() {
+        // The source, created and closed within the transaction, has no token balance.
+        let transaction = ConfirmedTransaction {
+            transaction: Some(Transaction {
+                signatures: vec![vec![1; 64]],
+                message: Some(Message { account_keys: vec![vec![1; 32], vec![2; 32], vec![3; 32]], ..Default::default() }),
+            }),
+            meta: Some(TransactionStatusMeta {
+                post_token_balances: vec![TokenBalance {
+                    account_index: 2,
+                    mint: address(4),
+                    owner: address(5),
+                    ui_token_amount: Some(UiTokenAmount { decimals: 9, amount: "250000".to_string(), ..Default::default() }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+        };
+        let mut events = vec![SplTokenEvent {
+            event: Some(Event::Transfer(TransferEvent {
+                source: token_account(2, 1),
+                destination: token_account(3, 5),
+                authority: address(1),
+                amount: 250_000,
+                ..Default::default()
+            })),
+        }];
+
+        enrich_transfer_events(&mut events, &transaction);
+
+        let Some(Event::Transfer(transfer)) = &events[0].event else { panic!("Expected Transfer") };
+        assert_eq!(transfer.source_owner, None);
+        assert_eq!(transfer.destination_owner, Some(address(5)));
+        assert_eq!(transfer.mint, Some(address(4)));
+        assert_eq!(transfer.decimals, Some(9));
+    }
+}
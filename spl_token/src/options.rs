@@ -0,0 +1,25 @@
+use anyhow::{anyhow, Error};
+
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// Emit an `UnknownInstructionEvent` for Token instructions that fail to unpack, instead of failing.
+    pub emit_unknown_instructions: bool,
+}
+
+impl ParseOptions {
+    /// Parses module params of the form `key=value&key=value`. Empty params yield the defaults.
+    pub fn from_params(params: &str) -> Result<Self, Error> {
+        let mut options = ParseOptions::default();
+        for param in params.split('&').map(str::trim).filter(|param| !param.is_empty()) {
+            let (key, value) = param.split_once('=').ok_or_else(|| anyhow!("Invalid param {}.", param))?;
+            match key.trim() {
+                "emit_unknown_instructions" => {
+                    options.emit_unknown_instructions = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid emit_unknown_instructions {}.", value))?;
+                },
+                _ => return Err(anyhow!("Unknown param {}.", key)),
+            }
+        }
+        Ok(options)
+    }
+}
@@ -16,7 +16,7 @@ pub struct SplTokenTransactionEvents {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SplTokenEvent {
-    #[prost(oneof="spl_token_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
+    #[prost(oneof="spl_token_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15")]
     pub event: ::core::option::Option<spl_token_event::Event>,
 }
 /// Nested message and enum types in `SplTokenEvent`.
@@ -52,6 +52,8 @@ pub mod spl_token_event {
         ThawAccount(super::ThawAccountEvent),
         #[prost(message, tag="14")]
         SyncNative(super::SyncNativeEvent),
+        #[prost(message, tag="15")]
+        UnknownInstruction(super::UnknownInstructionEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -93,6 +95,15 @@ pub struct TransferEvent {
     pub authority: ::prost::alloc::string::String,
     #[prost(uint64, tag="4")]
     pub amount: u64,
+    /// Resolved from the transaction's token balances; unset when the account isn't listed there.
+    #[prost(string, optional, tag="5")]
+    pub mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub source_owner: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub destination_owner: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag="8")]
+    pub decimals: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -152,6 +163,9 @@ pub struct CloseAccountEvent {
     /// TODO: amount
     #[prost(string, tag="2")]
     pub destination: ::prost::alloc::string::String,
+    /// Owner or close authority of the closed account.
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -182,6 +196,17 @@ pub struct SyncNativeEvent {
     #[prost(message, optional, tag="1")]
     pub account: ::core::option::Option<TokenAccount>,
 }
+/// Emitted instead of failing when `emit_unknown_instructions` is set and the instruction doesn't unpack.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnknownInstructionEvent {
+    #[prost(string, tag="1")]
+    pub program_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub data_hex: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub instruction_index: u32,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TokenAccount {
@@ -0,0 +1,40 @@
+use std::fmt;
+
+use substreams_solana_utils::pubkey::Pubkey;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePubkeyError {
+    /// The string contains characters outside the base58 alphabet.
+    InvalidBase58,
+    /// The string decodes to this many bytes instead of 32.
+    WrongSize(usize),
+}
+
+impl fmt::Display for ParsePubkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePubkeyError::InvalidBase58 => write!(f, "Invalid base58 string."),
+            ParsePubkeyError::WrongSize(size) => write!(f, "Invalid pubkey length {}, expected 32 bytes.", size),
+        }
+    }
+}
+
+impl std::error::Error for ParsePubkeyError {}
+
+/// Parsing of base58 pubkeys, such as those passed in params. Every crate parsing pubkeys goes
+/// through this one.
+///
+/// `Pubkey` comes from `substreams-solana-utils`, which already displays it as base58 but has no
+/// way back, and the orphan rule keeps `FromStr` from being implemented here.
+pub trait FromBase58: Sized {
+    fn from_base58(text: &str) -> Result<Self, ParsePubkeyError>;
+}
+
+impl FromBase58 for Pubkey {
+    fn from_base58(text: &str) -> Result<Pubkey, ParsePubkeyError> {
+        let bytes = bs58::decode(text).into_vec().map_err(|_| ParsePubkeyError::InvalidBase58)?;
+        let size = bytes.len();
+        let bytes: [u8; 32] = bytes.try_into().map_err(|_| ParsePubkeyError::WrongSize(size))?;
+        Ok(Pubkey(bytes))
+    }
+}
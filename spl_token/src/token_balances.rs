@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use substreams_solana::pb::sf::solana::r#type::v1::{ConfirmedTransaction, TokenBalance};
+
+/// Mint, owner and decimals of a token account, as recorded in the transaction's token balances.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenAccountInfo {
+    pub mint: String,
+    pub owner: String,
+    pub decimals: u32,
+}
+
+impl From<&TokenBalance> for TokenAccountInfo {
+    fn from(balance: &TokenBalance) -> Self {
+        Self {
+            mint: balance.mint.clone(),
+            owner: balance.owner.clone(),
+            decimals: balance.ui_token_amount.as_ref().map_or(0, |amount| amount.decimals),
+        }
+    }
+}
+
+/// All account keys of `transaction`, in account index order: static keys first, then the
+/// writable and readonly addresses loaded from lookup tables.
+pub fn account_keys(transaction: &ConfirmedTransaction) -> Vec<&Vec<u8>> {
+    let mut keys: Vec<&Vec<u8>> = Vec::new();
+    if let Some(message) = transaction.transaction.as_ref().and_then(|transaction| transaction.message.as_ref()) {
+        keys.extend(message.account_keys.iter());
+    }
+    if let Some(meta) = transaction.meta.as_ref() {
+        keys.extend(meta.loaded_writable_addresses.iter());
+        keys.extend(meta.loaded_readonly_addresses.iter());
+    }
+    keys
+}
+
+/// Token account info keyed by base58 address, built in a single pass over the pre and post
+/// token balances. Post balances take precedence; accounts missing from both are absent.
+pub fn token_accounts_info(transaction: &ConfirmedTransaction) -> HashMap<String, TokenAccountInfo> {
+    let mut infos: HashMap<String, TokenAccountInfo> = HashMap::new();
+    let Some(meta) = transaction.meta.as_ref() else {
+        return infos;
+    };
+    let keys = account_keys(transaction);
+    for balance in meta.pre_token_balances.iter().chain(meta.post_token_balances.iter()) {
+        if let Some(key) = keys.get(balance.account_index as usize) {
+            infos.insert(bs58::encode(key).into_string(), balance.into());
+        }
+    }
+    infos
+}
+
+/// Token account info by account index.
+///
+/// `TransactionContext` doesn't carry the token balances, so this is looked up on the
+/// transaction itself. Post balances take precedence over pre balances.
+pub trait TokenAccountInfoLookup {
+    fn token_account_info(&self, index: usize) -> Option<TokenAccountInfo>;
+}
+
+impl TokenAccountInfoLookup for ConfirmedTransaction {
+    fn token_account_info(&self, index: usize) -> Option<TokenAccountInfo> {
+        let meta = self.meta.as_ref()?;
+        meta.post_token_balances.iter()
+            .chain(meta.pre_token_balances.iter())
+            .find(|balance| balance.account_index as usize == index)
+            .map(TokenAccountInfo::from)
+    }
+}
+
+/// Decimals of every mint appearing in the transaction's token balances.
+pub fn mint_decimals(transaction: &ConfirmedTransaction) -> HashMap<String, u32> {
+    let mut decimals: HashMap<String, u32> = HashMap::new();
+    if let Some(meta) = transaction.meta.as_ref() {
+        for balance in meta.pre_token_balances.iter().chain(meta.post_token_balances.iter()) {
+            if let Some(amount) = balance.ui_token_amount.as_ref() {
+                decimals.insert(balance.mint.clone(), amount.decimals);
+            }
+        }
+    }
+    decimals
+}
+
+/// Net change of each token account's raw amount over the transaction, keyed by base58
+/// address. An account missing from the pre or post balances, i.e. created or closed by the
+/// transaction, counts as holding 0 on that side. Accounts in neither are absent.
+pub fn token_balance_deltas(transaction: &ConfirmedTransaction) -> HashMap<String, i128> {
+    let mut deltas: HashMap<String, i128> = HashMap::new();
+    let Some(meta) = transaction.meta.as_ref() else {
+        return deltas;
+    };
+    let keys = account_keys(transaction);
+    let amount = |balance: &TokenBalance| -> i128 {
+        balance.ui_token_amount.as_ref().and_then(|amount| amount.amount.parse::<u64>().ok()).unwrap_or(0) as i128
+    };
+    let balances = meta.pre_token_balances.iter().map(|balance| (balance, -1))
+        .chain(meta.post_token_balances.iter().map(|balance| (balance, 1)));
+    for (balance, sign) in balances {
+        if let Some(key) = keys.get(balance.account_index as usize) {
+            *deltas.entry(bs58::encode(key).into_string()).or_default() += sign * amount(balance);
+        }
+    }
+    deltas
+}
@@ -0,0 +1,99 @@
+use test_support::{address, key, TransactionBuilder};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+use substreams_solana_utils::spl_token::TOKEN_PROGRAM_ID;
+use spl_token_substream::pb::spl_token::TokenAccount;
+use spl_token_substream::pb::spl_token::spl_token_event::Event;
+use spl_token_substream::{parse_block, parse_transaction};
+
+// Account indexes of the fixtures: the authority (fee payer), the source and destination token
+// accounts, the mint and the Token program.
+const AUTHORITY: u8 = 0;
+const SOURCE: u8 = 1;
+const DESTINATION: u8 = 2;
+const MINT: u8 = 3;
+const TOKEN_PROGRAM: u32 = 4;
+
+fn builder() -> TransactionBuilder {
+    TransactionBuilder::new(vec![key(1), key(2), key(3), key(4), TOKEN_PROGRAM_ID.0.to_vec()])
+        .pre_token_balance(SOURCE as u32, 4, 1, 1_000_000, 6)
+        .pre_token_balance(DESTINATION as u32, 4, 5, 0, 6)
+        .post_token_balance(SOURCE as u32, 4, 1, 750_000, 6)
+        .post_token_balance(DESTINATION as u32, 4, 5, 250_000, 6)
+}
+
+fn transfer_data(amount: u64) -> Vec<u8> {
+    [vec![3], amount.to_le_bytes().to_vec()].concat()
+}
+
+fn transfer_transaction() -> ConfirmedTransaction {
+    builder().instruction(TOKEN_PROGRAM, &[SOURCE, DESTINATION, AUTHORITY], transfer_data(250_000)).build()
+}
+
+fn single_event(transaction: &ConfirmedTransaction) -> Event {
+    let events = parse_transaction(transaction).unwrap();
+    assert_eq!(events.len(), 1);
+    events[0].event.clone().unwrap()
+}
+
+fn token_account(address_key: u8, owner: u8) -> Option<TokenAccount> {
+    Some(TokenAccount { address: address(address_key), owner: address(owner), mint: address(4) })
+}
+
+#[test]
+fn transfer() {
+    let Event::Transfer(event) = single_event(&transfer_transaction()) else { panic!("Expected Transfer") };
+    assert_eq!(event.source, token_account(2, 1));
+    assert_eq!(event.destination, token_account(3, 5));
+    assert_eq!(event.authority, address(1));
+    assert_eq!(event.amount, 250_000);
+    assert_eq!(event.mint, Some(address(4)));
+    assert_eq!(event.source_owner, Some(address(1)));
+    assert_eq!(event.destination_owner, Some(address(5)));
+    assert_eq!(event.decimals, Some(6));
+}
+
+#[test]
+fn transfer_checked() {
+    let data = [vec![12], 250_000u64.to_le_bytes().to_vec(), vec![6]].concat();
+    let transaction = builder().instruction(TOKEN_PROGRAM, &[SOURCE, MINT, DESTINATION, AUTHORITY], data).build();
+    let Event::Transfer(event) = single_event(&transaction) else { panic!("Expected Transfer") };
+    assert_eq!(event.source, token_account(2, 1));
+    assert_eq!(event.destination, token_account(3, 5));
+    assert_eq!(event.authority, address(1));
+    assert_eq!(event.amount, 250_000);
+    assert_eq!(event.decimals, Some(6));
+}
+
+#[test]
+fn initialize_account_3() {
+    let data = [vec![18], key(5)].concat();
+    let transaction = TransactionBuilder::new(vec![key(1), key(3), key(4), TOKEN_PROGRAM_ID.0.to_vec()])
+        .instruction(3, &[1, 2], data)
+        .post_token_balance(1, 4, 5, 0, 6)
+        .build();
+    let Event::InitializeAccount(event) = single_event(&transaction) else { panic!("Expected InitializeAccount") };
+    assert_eq!(event.account, token_account(3, 5));
+}
+
+#[test]
+fn transfer_missing_accounts_fails() {
+    // The authority, third account of a Transfer, is missing.
+    let transaction = builder().instruction(TOKEN_PROGRAM, &[SOURCE, DESTINATION], transfer_data(250_000)).build();
+    assert!(parse_transaction(&transaction).is_err());
+}
+
+#[test]
+fn block_skips_failing_transactions() {
+    let malformed = builder().instruction(TOKEN_PROGRAM, &[SOURCE, DESTINATION], transfer_data(250_000)).build();
+    let block = Block { transactions: vec![malformed, transfer_transaction()], ..Default::default() };
+    let transactions = parse_block(&block).unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].events.len(), 1);
+}
+
+#[test]
+fn transaction_without_meta_is_skipped() {
+    let mut transaction = transfer_transaction();
+    transaction.meta = None;
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
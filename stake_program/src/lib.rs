@@ -0,0 +1,287 @@
+use anyhow::{anyhow, Context, Error};
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::transaction::{get_context, TransactionContext};
+use utils::pubkey::Pubkey;
+
+pub mod stake_program;
+use stake_program::STAKE_PROGRAM_ID;
+use stake_program::instruction::{StakeInstruction, LockupArgs, AuthorizeWithSeedArgs};
+
+pub mod pb;
+use pb::stake_program::*;
+use pb::stake_program::stake_program_event::Event;
+
+#[substreams::handlers::map]
+fn stake_program_events(block: Block) -> Result<StakeProgramBlockEvents, Error> {
+    Ok(StakeProgramBlockEvents { transactions: parse_block(&block)? })
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
+pub fn parse_block(block: &Block) -> Result<Vec<StakeProgramTransactionEvents>, Error> {
+    let mut transactions_events: Vec<StakeProgramTransactionEvents> = Vec::new();
+    for transaction in block.transactions() {
+        let events = match parse_transaction(transaction) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        if !events.is_empty() {
+            transactions_events.push(StakeProgramTransactionEvents {
+                signature: utils::transaction::get_signature(&transaction),
+                events
+            })
+        }
+    }
+    Ok(transactions_events)
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<StakeProgramEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new())
+    }
+
+    let mut events: Vec<StakeProgramEvent> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened().iter().enumerate() {
+        if instruction.program_id() != STAKE_PROGRAM_ID {
+            continue;
+        }
+        let event = parse_instruction(instruction, &context)
+            .with_context(|| format!("Failed to parse transaction {}", context.signature))?;
+        if event.is_some() {
+            events.push(StakeProgramEvent { instruction_index: i as u32, event });
+        }
+    }
+
+    Ok(events)
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Option<Event>, Error> {
+    if instruction.program_id() != STAKE_PROGRAM_ID {
+        return Err(anyhow!("Not a Stake program instruction"));
+    }
+
+    let unpacked = StakeInstruction::unpack(&instruction.data()).map_err(|x| anyhow!(x))?;
+    match unpacked {
+        StakeInstruction::Initialize { staker, withdrawer, lockup } => {
+            _parse_initialize_instruction(instruction, context, staker, withdrawer, lockup).map(|x| Some(Event::Initialize(x)))
+        },
+        StakeInstruction::Authorize { new_authority, stake_authorize } => {
+            _parse_authorize_instruction(instruction, context, new_authority, stake_authorize).map(|x| Some(Event::Authorize(x)))
+        },
+        StakeInstruction::DelegateStake => {
+            _parse_delegate_stake_instruction(instruction, context).map(|x| Some(Event::DelegateStake(x)))
+        },
+        StakeInstruction::Split { lamports } => {
+            _parse_split_instruction(instruction, context, lamports).map(|x| Some(Event::Split(x)))
+        },
+        StakeInstruction::Withdraw { lamports } => {
+            _parse_withdraw_instruction(instruction, context, lamports).map(|x| Some(Event::Withdraw(x)))
+        },
+        StakeInstruction::Deactivate => {
+            _parse_deactivate_instruction(instruction, context).map(|x| Some(Event::Deactivate(x)))
+        },
+        StakeInstruction::SetLockup(lockup_args) => {
+            _parse_set_lockup_instruction(instruction, context, lockup_args).map(|x| Some(Event::SetLockup(x)))
+        },
+        StakeInstruction::Merge => {
+            _parse_merge_instruction(instruction, context).map(|x| Some(Event::Merge(x)))
+        },
+        StakeInstruction::AuthorizeWithSeed(args) => {
+            _parse_authorize_with_seed_instruction(instruction, context, args).map(|x| Some(Event::AuthorizeWithSeed(x)))
+        },
+        StakeInstruction::Unsupported(_) => Ok(None),
+    }.map_err(|x| anyhow!(x)).context("Failed to parse Stake instruction")
+}
+
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, &'static str> {
+    instruction.accounts().get(index).cloned().ok_or("Instruction is missing accounts.")
+}
+
+fn _get_optional_account(instruction: &StructuredInstruction, index: usize) -> Option<String> {
+    instruction.accounts().get(index).map(|account| account.to_string())
+}
+
+fn _stake_authorize(stake_authorize: stake_program::instruction::StakeAuthorize) -> i32 {
+    use stake_program::instruction::StakeAuthorize as Instruction;
+    match stake_authorize {
+        Instruction::Staker => StakeAuthorize::Staker.into(),
+        Instruction::Withdrawer => StakeAuthorize::Withdrawer.into(),
+    }
+}
+
+fn _parse_initialize_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    staker: Pubkey,
+    withdrawer: Pubkey,
+    lockup: stake_program::instruction::Lockup,
+) -> Result<InitializeEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+
+    Ok(InitializeEvent {
+        stake_account,
+        staker: staker.to_string(),
+        withdrawer: withdrawer.to_string(),
+        lockup: Some(Lockup {
+            unix_timestamp: lockup.unix_timestamp,
+            epoch: lockup.epoch,
+            custodian: lockup.custodian.to_string(),
+        }),
+    })
+}
+
+fn _parse_authorize_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    new_authority: Pubkey,
+    stake_authorize: stake_program::instruction::StakeAuthorize,
+) -> Result<AuthorizeEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
+    let custodian = _get_optional_account(instruction, 3);
+
+    Ok(AuthorizeEvent {
+        stake_account,
+        authority,
+        new_authority: new_authority.to_string(),
+        stake_authorize: _stake_authorize(stake_authorize),
+        custodian,
+    })
+}
+
+fn _parse_delegate_stake_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<DelegateStakeEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+    let vote_account = _get_account(instruction, 1)?.to_string();
+    let stake_authority = _get_account(instruction, 5)?.to_string();
+
+    Ok(DelegateStakeEvent {
+        stake_account,
+        vote_account,
+        stake_authority,
+    })
+}
+
+fn _parse_split_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    lamports: u64,
+) -> Result<SplitEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+    let split_stake_account = _get_account(instruction, 1)?.to_string();
+    let stake_authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(SplitEvent {
+        stake_account,
+        split_stake_account,
+        stake_authority,
+        lamports,
+    })
+}
+
+fn _parse_withdraw_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    lamports: u64,
+) -> Result<WithdrawEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+    let destination = _get_account(instruction, 1)?.to_string();
+    let withdraw_authority = _get_account(instruction, 4)?.to_string();
+    let custodian = _get_optional_account(instruction, 5);
+
+    Ok(WithdrawEvent {
+        stake_account,
+        destination,
+        withdraw_authority,
+        lamports,
+        custodian,
+    })
+}
+
+fn _parse_deactivate_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<DeactivateEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+    let stake_authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(DeactivateEvent {
+        stake_account,
+        stake_authority,
+    })
+}
+
+fn _parse_set_lockup_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    lockup_args: LockupArgs,
+) -> Result<SetLockupEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+
+    Ok(SetLockupEvent {
+        stake_account,
+        authority,
+        unix_timestamp: lockup_args.unix_timestamp,
+        epoch: lockup_args.epoch,
+        custodian: lockup_args.custodian.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_merge_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<MergeEvent, &'static str> {
+    let destination_stake_account = _get_account(instruction, 0)?.to_string();
+    let source_stake_account = _get_account(instruction, 1)?.to_string();
+    let stake_authority = _get_account(instruction, 4)?.to_string();
+
+    Ok(MergeEvent {
+        destination_stake_account,
+        source_stake_account,
+        stake_authority,
+    })
+}
+
+fn _parse_authorize_with_seed_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    args: AuthorizeWithSeedArgs,
+) -> Result<AuthorizeWithSeedEvent, &'static str> {
+    let stake_account = _get_account(instruction, 0)?.to_string();
+    let authority_base = _get_account(instruction, 1)?.to_string();
+    let custodian = _get_optional_account(instruction, 3);
+
+    Ok(AuthorizeWithSeedEvent {
+        stake_account,
+        authority_base,
+        new_authority: args.new_authorized_pubkey.to_string(),
+        stake_authorize: _stake_authorize(args.stake_authorize),
+        authority_seed: args.authority_seed,
+        authority_owner: args.authority_owner.to_string(),
+        custodian,
+    })
+}
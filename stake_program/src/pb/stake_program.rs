@@ -0,0 +1,197 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeProgramBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<StakeProgramTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeProgramTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<StakeProgramEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeProgramEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(oneof="stake_program_event::Event", tags="2, 3, 4, 5, 6, 7, 8, 9, 10")]
+    pub event: ::core::option::Option<stake_program_event::Event>,
+}
+/// Nested message and enum types in `StakeProgramEvent`.
+pub mod stake_program_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="2")]
+        Initialize(super::InitializeEvent),
+        #[prost(message, tag="3")]
+        Authorize(super::AuthorizeEvent),
+        #[prost(message, tag="4")]
+        DelegateStake(super::DelegateStakeEvent),
+        #[prost(message, tag="5")]
+        Split(super::SplitEvent),
+        #[prost(message, tag="6")]
+        Withdraw(super::WithdrawEvent),
+        #[prost(message, tag="7")]
+        Deactivate(super::DeactivateEvent),
+        #[prost(message, tag="8")]
+        SetLockup(super::SetLockupEvent),
+        #[prost(message, tag="9")]
+        Merge(super::MergeEvent),
+        #[prost(message, tag="10")]
+        AuthorizeWithSeed(super::AuthorizeWithSeedEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Lockup {
+    #[prost(int64, tag="1")]
+    pub unix_timestamp: i64,
+    #[prost(uint64, tag="2")]
+    pub epoch: u64,
+    #[prost(string, tag="3")]
+    pub custodian: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub staker: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub withdrawer: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="4")]
+    pub lockup: ::core::option::Option<Lockup>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub new_authority: ::prost::alloc::string::String,
+    #[prost(enumeration="StakeAuthorize", tag="4")]
+    pub stake_authorize: i32,
+    #[prost(string, optional, tag="5")]
+    pub custodian: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegateStakeEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub vote_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub stake_authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub split_stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub stake_authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub lamports: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub withdraw_authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub lamports: u64,
+    #[prost(string, optional, tag="5")]
+    pub custodian: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeactivateEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub stake_authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLockupEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(int64, optional, tag="3")]
+    pub unix_timestamp: ::core::option::Option<i64>,
+    #[prost(uint64, optional, tag="4")]
+    pub epoch: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="5")]
+    pub custodian: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeEvent {
+    #[prost(string, tag="1")]
+    pub destination_stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub source_stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub stake_authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeWithSeedEvent {
+    #[prost(string, tag="1")]
+    pub stake_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority_base: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub new_authority: ::prost::alloc::string::String,
+    #[prost(enumeration="StakeAuthorize", tag="4")]
+    pub stake_authorize: i32,
+    #[prost(string, tag="5")]
+    pub authority_seed: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub authority_owner: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub custodian: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StakeAuthorize {
+    Staker = 0,
+    Withdrawer = 1,
+}
+impl StakeAuthorize {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StakeAuthorize::Staker => "STAKER",
+            StakeAuthorize::Withdrawer => "WITHDRAWER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STAKER" => Some(Self::Staker),
+            "WITHDRAWER" => Some(Self::Withdrawer),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
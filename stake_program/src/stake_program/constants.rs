@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const STAKE_PROGRAM_ID: Pubkey = Pubkey(b58!("Stake11111111111111111111111111111111111111"));
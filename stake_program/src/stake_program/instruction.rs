@@ -0,0 +1,143 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakeAuthorize {
+    Staker,
+    Withdrawer,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lockup {
+    pub unix_timestamp: i64,
+    pub epoch: u64,
+    pub custodian: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockupArgs {
+    pub unix_timestamp: Option<i64>,
+    pub epoch: Option<u64>,
+    pub custodian: Option<Pubkey>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorizeWithSeedArgs {
+    pub new_authorized_pubkey: Pubkey,
+    pub stake_authorize: StakeAuthorize,
+    pub authority_seed: String,
+    pub authority_owner: Pubkey,
+}
+
+/// Stake instructions, bincode encoded with a little-endian `u32` tag.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StakeInstruction {
+    Initialize { staker: Pubkey, withdrawer: Pubkey, lockup: Lockup },
+    Authorize { new_authority: Pubkey, stake_authorize: StakeAuthorize },
+    DelegateStake,
+    Split { lamports: u64 },
+    Withdraw { lamports: u64 },
+    Deactivate,
+    SetLockup(LockupArgs),
+    Merge,
+    AuthorizeWithSeed(AuthorizeWithSeedArgs),
+    /// Checked variants and instructions added after AuthorizeWithSeed, not decoded.
+    Unsupported(u32),
+}
+
+impl StakeInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = Self::unpack_u32(data)?;
+        Ok(match tag {
+            0 => {
+                let (staker, rest) = Self::unpack_pubkey(rest)?;
+                let (withdrawer, rest) = Self::unpack_pubkey(rest)?;
+                let (unix_timestamp, rest) = Self::unpack_i64(rest)?;
+                let (epoch, rest) = Self::unpack_u64(rest)?;
+                let (custodian, _rest) = Self::unpack_pubkey(rest)?;
+                Self::Initialize { staker, withdrawer, lockup: Lockup { unix_timestamp, epoch, custodian } }
+            },
+            1 => {
+                let (new_authority, rest) = Self::unpack_pubkey(rest)?;
+                let (stake_authorize, _rest) = Self::unpack_stake_authorize(rest)?;
+                Self::Authorize { new_authority, stake_authorize }
+            },
+            2 => Self::DelegateStake,
+            3 => Self::Split { lamports: Self::unpack_u64(rest)?.0 },
+            4 => Self::Withdraw { lamports: Self::unpack_u64(rest)?.0 },
+            5 => Self::Deactivate,
+            6 => {
+                let (unix_timestamp, rest) = Self::unpack_option(rest, Self::unpack_i64)?;
+                let (epoch, rest) = Self::unpack_option(rest, Self::unpack_u64)?;
+                let (custodian, _rest) = Self::unpack_option(rest, Self::unpack_pubkey)?;
+                Self::SetLockup(LockupArgs { unix_timestamp, epoch, custodian })
+            },
+            7 => Self::Merge,
+            8 => {
+                let (new_authorized_pubkey, rest) = Self::unpack_pubkey(rest)?;
+                let (stake_authorize, rest) = Self::unpack_stake_authorize(rest)?;
+                let (authority_seed, rest) = Self::unpack_string(rest)?;
+                let (authority_owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::AuthorizeWithSeed(AuthorizeWithSeedArgs {
+                    new_authorized_pubkey,
+                    stake_authorize,
+                    authority_seed,
+                    authority_owner,
+                })
+            },
+            _ => Self::Unsupported(tag),
+        })
+    }
+
+    fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), &'static str> {
+        let value = input.get(..4).ok_or("Stake instruction is truncated.")?;
+        Ok((u32::from_le_bytes(value.try_into().unwrap()), &input[4..]))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        let value = input.get(..8).ok_or("Stake instruction is truncated.")?;
+        Ok((u64::from_le_bytes(value.try_into().unwrap()), &input[8..]))
+    }
+
+    fn unpack_i64(input: &[u8]) -> Result<(i64, &[u8]), &'static str> {
+        let value = input.get(..8).ok_or("Stake instruction is truncated.")?;
+        Ok((i64::from_le_bytes(value.try_into().unwrap()), &input[8..]))
+    }
+
+    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), &'static str> {
+        let key = input.get(..32).ok_or("Stake instruction is truncated.")?;
+        Ok((Pubkey(key.try_into().unwrap()), &input[32..]))
+    }
+
+    fn unpack_stake_authorize(input: &[u8]) -> Result<(StakeAuthorize, &[u8]), &'static str> {
+        let (value, rest) = Self::unpack_u32(input)?;
+        match value {
+            0 => Ok((StakeAuthorize::Staker, rest)),
+            1 => Ok((StakeAuthorize::Withdrawer, rest)),
+            _ => Err("Invalid stake authorize."),
+        }
+    }
+
+    /// bincode strings are prefixed by their length as a `u64`.
+    fn unpack_string(input: &[u8]) -> Result<(String, &[u8]), &'static str> {
+        let (len, rest) = Self::unpack_u64(input)?;
+        let len = usize::try_from(len).map_err(|_| "Stake instruction is truncated.")?;
+        let bytes = rest.get(..len).ok_or("Stake instruction is truncated.")?;
+        let value = String::from_utf8(bytes.to_vec()).map_err(|_| "Invalid UTF-8 seed.")?;
+        Ok((value, &rest[len..]))
+    }
+
+    /// bincode options are prefixed by a one-byte tag.
+    fn unpack_option<T>(
+        input: &[u8],
+        unpack: fn(&[u8]) -> Result<(T, &[u8]), &'static str>,
+    ) -> Result<(Option<T>, &[u8]), &'static str> {
+        match input.split_first() {
+            Some((&0, rest)) => Ok((None, rest)),
+            Some((&1, rest)) => {
+                let (value, rest) = unpack(rest)?;
+                Ok((Some(value), rest))
+            },
+            _ => Err("Invalid option."),
+        }
+    }
+}
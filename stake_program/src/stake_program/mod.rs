@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod constants;
+pub use constants::STAKE_PROGRAM_ID;
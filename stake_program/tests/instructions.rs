@@ -0,0 +1,87 @@
+use test_support::{address, key, TransactionBuilder};
+use stake_program_substream::parse_transaction;
+use stake_program_substream::pb::stake_program::{DelegateStakeEvent, WithdrawEvent};
+use stake_program_substream::pb::stake_program::stake_program_event::Event;
+use stake_program_substream::stake_program::STAKE_PROGRAM_ID;
+
+// Account indexes: the stake authority (fee payer), the stake account, the vote account, the
+// Clock and StakeHistory sysvars, the stake config, a custodian and the Stake program.
+const AUTHORITY: u8 = 0;
+const STAKE_ACCOUNT: u8 = 1;
+const VOTE_ACCOUNT: u8 = 2;
+const CLOCK: u8 = 3;
+const STAKE_HISTORY: u8 = 4;
+const STAKE_CONFIG: u8 = 5;
+const CUSTODIAN: u8 = 6;
+const STAKE_PROGRAM: u32 = 7;
+
+fn builder() -> TransactionBuilder {
+    TransactionBuilder::new(vec![
+        key(1), key(2), key(3), key(4), key(5), key(6), key(7), STAKE_PROGRAM_ID.0.to_vec(),
+    ])
+}
+
+fn withdraw_data(lamports: u64) -> Vec<u8> {
+    [4u32.to_le_bytes().to_vec(), lamports.to_le_bytes().to_vec()].concat()
+}
+
+fn single_event(transaction: TransactionBuilder) -> Event {
+    let events = parse_transaction(&transaction.build()).unwrap();
+    assert_eq!(events.len(), 1);
+    events[0].event.clone().unwrap()
+}
+
+#[test]
+fn delegate_stake() {
+    let accounts = [STAKE_ACCOUNT, VOTE_ACCOUNT, CLOCK, STAKE_HISTORY, STAKE_CONFIG, AUTHORITY];
+    let transaction = builder().instruction(STAKE_PROGRAM, &accounts, 2u32.to_le_bytes().to_vec());
+    assert_eq!(single_event(transaction), Event::DelegateStake(DelegateStakeEvent {
+        stake_account: address(2),
+        vote_account: address(3),
+        stake_authority: address(1),
+    }));
+}
+
+#[test]
+fn delegate_stake_missing_authority_fails() {
+    let accounts = [STAKE_ACCOUNT, VOTE_ACCOUNT, CLOCK, STAKE_HISTORY, STAKE_CONFIG];
+    let transaction = builder().instruction(STAKE_PROGRAM, &accounts, 2u32.to_le_bytes().to_vec());
+    assert!(parse_transaction(&transaction.build()).is_err());
+}
+
+#[test]
+fn withdraw() {
+    // The authority withdraws to itself.
+    let accounts = [STAKE_ACCOUNT, AUTHORITY, CLOCK, STAKE_HISTORY, AUTHORITY];
+    let transaction = builder().instruction(STAKE_PROGRAM, &accounts, withdraw_data(1_000_000_000));
+    assert_eq!(single_event(transaction), Event::Withdraw(WithdrawEvent {
+        stake_account: address(2),
+        destination: address(1),
+        withdraw_authority: address(1),
+        lamports: 1_000_000_000,
+        custodian: None,
+    }));
+}
+
+#[test]
+fn withdraw_with_custodian() {
+    let accounts = [STAKE_ACCOUNT, AUTHORITY, CLOCK, STAKE_HISTORY, AUTHORITY, CUSTODIAN];
+    let transaction = builder().instruction(STAKE_PROGRAM, &accounts, withdraw_data(5_000));
+    let Event::Withdraw(event) = single_event(transaction) else { panic!("Expected Withdraw") };
+    assert_eq!(event.lamports, 5_000);
+    assert_eq!(event.custodian, Some(address(7)));
+}
+
+#[test]
+fn truncated_withdraw_fails() {
+    let transaction = builder().instruction(STAKE_PROGRAM, &[STAKE_ACCOUNT, AUTHORITY, CLOCK, STAKE_HISTORY, AUTHORITY], vec![4, 0, 0, 0, 1]);
+    assert!(parse_transaction(&transaction.build()).is_err());
+}
+
+#[test]
+fn transaction_without_meta_is_skipped() {
+    let accounts = [STAKE_ACCOUNT, AUTHORITY, CLOCK, STAKE_HISTORY, AUTHORITY];
+    let mut transaction = builder().instruction(STAKE_PROGRAM, &accounts, withdraw_data(1_000_000_000)).build();
+    transaction.meta = None;
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
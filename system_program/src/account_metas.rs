@@ -0,0 +1,78 @@
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use crate::pb::system_program::*;
+use crate::parser::get_account_from_index;
+use crate::options::{format_pubkey, PubkeyEncoding};
+
+/// Fills `accounts` on each event with the accounts of its instruction, flagged as signer and
+/// writable the way the transaction message declares them.
+pub fn annotate_account_metas(
+    transaction: &ConfirmedTransaction,
+    events: &mut [SystemProgramEvent],
+    pubkey_encoding: PubkeyEncoding,
+) {
+    let instructions = flattened_account_indexes(transaction);
+    for event in events.iter_mut() {
+        let Some(indexes) = instructions.get(event.instruction_index as usize) else {
+            continue;
+        };
+        event.accounts = account_metas(transaction, indexes, pubkey_encoding);
+    }
+}
+
+/// The accounts at `indexes` of the resolved account list, e.g. an entry of
+/// `flattened_account_indexes`, flagged as signer and writable. Indexes out of range are left out.
+pub fn account_metas(transaction: &ConfirmedTransaction, indexes: &[u8], pubkey_encoding: PubkeyEncoding) -> Vec<AccountMeta> {
+    indexes.iter()
+        .filter_map(|index| {
+            let pubkey = get_account_from_index(transaction, *index as usize)?;
+            let (is_signer, is_writable) = account_flags(transaction, *index as usize);
+            Some(AccountMeta { pubkey: format_pubkey(pubkey, pubkey_encoding), is_signer, is_writable, pubkey_index: None })
+        })
+        .collect()
+}
+
+/// Account indexes of every instruction of `transaction`, in the order of `instruction_index`:
+/// each top-level instruction followed by the instructions it invoked.
+pub fn flattened_account_indexes(transaction: &ConfirmedTransaction) -> Vec<&[u8]> {
+    let Some(message) = transaction.transaction.as_ref().and_then(|transaction| transaction.message.as_ref()) else {
+        return Vec::new();
+    };
+    let inner_instructions = transaction.meta.as_ref().map_or(&[][..], |meta| meta.inner_instructions.as_slice());
+
+    let mut indexes: Vec<&[u8]> = Vec::new();
+    for (i, instruction) in message.instructions.iter().enumerate() {
+        indexes.push(&instruction.accounts);
+        let inner = inner_instructions.iter().filter(|inner| inner.index as usize == i);
+        indexes.extend(inner.flat_map(|inner| inner.instructions.iter().map(|instruction| instruction.accounts.as_slice())));
+    }
+    indexes
+}
+
+/// Whether the account at `index` of the resolved account list is a signer and writable.
+///
+/// Static keys are ordered writable signers, readonly signers, writable non-signers and readonly
+/// non-signers, with the header giving the size of each group. Keys loaded from lookup tables
+/// are never signers and are writable when loaded as such. The runtime may still demote a
+/// writable account, e.g. a program id, which isn't reflected here.
+pub fn account_flags(transaction: &ConfirmedTransaction, index: usize) -> (bool, bool) {
+    let Some(message) = transaction.transaction.as_ref().and_then(|transaction| transaction.message.as_ref()) else {
+        return (false, false);
+    };
+    let (num_required_signatures, num_readonly_signed, num_readonly_unsigned) = message.header.as_ref()
+        .map_or((0, 0, 0), |header| (
+            header.num_required_signatures as usize,
+            header.num_readonly_signed_accounts as usize,
+            header.num_readonly_unsigned_accounts as usize,
+        ));
+    let num_static = message.account_keys.len();
+    let num_loaded_writable = transaction.meta.as_ref().map_or(0, |meta| meta.loaded_writable_addresses.len());
+
+    if index < num_required_signatures {
+        (true, index < num_required_signatures.saturating_sub(num_readonly_signed))
+    } else if index < num_static {
+        (false, index < num_static.saturating_sub(num_readonly_unsigned))
+    } else {
+        (false, index < num_static + num_loaded_writable)
+    }
+}
@@ -0,0 +1,131 @@
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction, Message};
+
+use substreams_solana_utils as utils;
+use utils::transaction::TransactionContext;
+use utils::instruction::StructuredInstruction;
+use utils::pubkey::Pubkey;
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::compute_budget_event::Event;
+use crate::constants::COMPUTE_BUDGET_PROGRAM_ID;
+use crate::parser::{InstructionParser, ParseError, parse_transaction_multi, skip_missing_meta};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeBudgetInstruction {
+    /// Replaced by SetComputeUnitLimit and SetComputeUnitPrice, still accepted by older runtimes.
+    RequestUnitsDeprecated { units: u32, additional_fee: u32 },
+    RequestHeapFrame(u32),
+    SetComputeUnitLimit(u32),
+    SetComputeUnitPrice(u64),
+    SetLoadedAccountsDataSizeLimit(u32),
+}
+
+impl ComputeBudgetInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        let (&tag, rest) = data.split_first().ok_or("Invalid Compute Budget instruction.")?;
+        Ok(match tag {
+            0 => {
+                let (units, rest) = Self::unpack_u32(rest)?;
+                let (additional_fee, _rest) = Self::unpack_u32(rest)?;
+                Self::RequestUnitsDeprecated { units, additional_fee }
+            },
+            1 => Self::RequestHeapFrame(Self::unpack_u32(rest)?.0),
+            2 => Self::SetComputeUnitLimit(Self::unpack_u32(rest)?.0),
+            3 => Self::SetComputeUnitPrice(Self::unpack_u64(rest)?.0),
+            4 => Self::SetLoadedAccountsDataSizeLimit(Self::unpack_u32(rest)?.0),
+            _ => return Err("Invalid Compute Budget instruction."),
+        })
+    }
+
+    fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), &'static str> {
+        let value = input.get(..4).ok_or("Compute Budget instruction is truncated.")?;
+        Ok((u32::from_le_bytes(value.try_into().unwrap()), &input[4..]))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        let value = input.get(..8).ok_or("Compute Budget instruction is truncated.")?;
+        Ok((u64::from_le_bytes(value.try_into().unwrap()), &input[8..]))
+    }
+}
+
+pub struct ComputeBudgetParser;
+
+impl InstructionParser<Event> for ComputeBudgetParser {
+    fn program_id(&self) -> Pubkey {
+        COMPUTE_BUDGET_PROGRAM_ID
+    }
+
+    fn parse(
+        &self,
+        instruction: &StructuredInstruction,
+        _context: &TransactionContext,
+    ) -> Result<Option<Event>, ParseError> {
+        let unpacked = ComputeBudgetInstruction::unpack(&instruction.data()).map_err(|x| ParseError::InvalidInstruction(x.to_string()))?;
+        Ok(match unpacked {
+            ComputeBudgetInstruction::RequestUnitsDeprecated { .. } => None,
+            ComputeBudgetInstruction::RequestHeapFrame(bytes) => {
+                Some(Event::RequestHeapFrame(RequestHeapFrameEvent { bytes }))
+            },
+            ComputeBudgetInstruction::SetComputeUnitLimit(units) => {
+                Some(Event::SetComputeUnitLimit(SetComputeUnitLimitEvent { units }))
+            },
+            ComputeBudgetInstruction::SetComputeUnitPrice(micro_lamports) => {
+                Some(Event::SetComputeUnitPrice(SetComputeUnitPriceEvent { micro_lamports }))
+            },
+            ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit(bytes) => {
+                Some(Event::SetLoadedAccountsDataSizeLimit(SetLoadedAccountsDataSizeLimitEvent { bytes }))
+            },
+        })
+    }
+}
+
+pub fn parse_block(block: &Block) -> Result<ComputeBudgetBlockEvents, Error> {
+    let mut transactions: Vec<ComputeBudgetTransactionEvents> = Vec::new();
+    for (i, transaction) in block.transactions.iter().enumerate() {
+        if skip_missing_meta(transaction) {
+            continue;
+        }
+        let events = parse_transaction(transaction)?;
+        if !events.is_empty() {
+            transactions.push(ComputeBudgetTransactionEvents {
+                signature: utils::transaction::get_signature(transaction),
+                transaction_index: i as u32,
+                events,
+            });
+        }
+    }
+    Ok(ComputeBudgetBlockEvents { slot: block.slot, transactions })
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<ComputeBudgetEvent>, Error> {
+    let events = parse_transaction_multi(transaction, &[&ComputeBudgetParser])?;
+    Ok(events.into_iter().map(|(instruction_index, event)| ComputeBudgetEvent {
+        instruction_index,
+        event: Some(event),
+    }).collect())
+}
+
+/// Compute unit limit and price requested by the message.
+///
+/// Compute Budget instructions only take effect at the top level and the runtime rejects
+/// duplicates, so the raw message instructions are enough. Malformed instructions are ignored.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ComputeBudget {
+    pub compute_unit_limit: Option<u32>,
+    pub compute_unit_price_micro_lamports: Option<u64>,
+}
+
+pub fn compute_budget(message: &Message) -> ComputeBudget {
+    let mut budget = ComputeBudget::default();
+    let is_compute_budget = |index: u32| message.account_keys.get(index as usize)
+        .map_or(false, |key| key.as_slice() == COMPUTE_BUDGET_PROGRAM_ID.0.as_slice());
+    for instruction in message.instructions.iter().filter(|instruction| is_compute_budget(instruction.program_id_index)) {
+        match ComputeBudgetInstruction::unpack(&instruction.data) {
+            Ok(ComputeBudgetInstruction::SetComputeUnitLimit(units)) => budget.compute_unit_limit = Some(units),
+            Ok(ComputeBudgetInstruction::SetComputeUnitPrice(micro_lamports)) => budget.compute_unit_price_micro_lamports = Some(micro_lamports),
+            _ => (),
+        }
+    }
+    budget
+}
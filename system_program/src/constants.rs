@@ -0,0 +1,14 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey(b58!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"));
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = Pubkey(b58!("ATokenGPvbdGVxr1b2hvZbsiqW1xWH25efTNsLJA8knL"));
+pub const MPL_TOKEN_METADATA_PROGRAM_ID: Pubkey = Pubkey(b58!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"));
+pub const MEMO_V1_PROGRAM_ID: Pubkey = Pubkey(b58!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo"));
+pub const MEMO_PROGRAM_ID: Pubkey = Pubkey(b58!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"));
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = Pubkey(b58!("ComputeBudget111111111111111111111111111111"));
+pub const ED25519_PROGRAM_ID: Pubkey = Pubkey(b58!("Ed25519SigVerify111111111111111111111111111"));
+pub const SECP256K1_PROGRAM_ID: Pubkey = Pubkey(b58!("KeccakSecp256k11111111111111111111111111111"));
+pub const VOTE_PROGRAM_ID: Pubkey = Pubkey(b58!("Vote111111111111111111111111111111111111111"));
+pub const RECENT_BLOCKHASHES_SYSVAR_ID: Pubkey = Pubkey(b58!("SysvarRecentB1ockHashes11111111111111111111"));
+pub const RENT_SYSVAR_ID: Pubkey = Pubkey(b58!("SysvarRent111111111111111111111111111111111"));
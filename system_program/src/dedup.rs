@@ -0,0 +1,14 @@
+use crate::pb::system_program::*;
+
+/// Removes events whose decoded payload equals that of an earlier event of the transaction,
+/// keeping the first occurrence and its `instruction_index`. Unlike `merge_consecutive_transfers`
+/// nothing is summed, and duplicates don't need to be adjacent.
+pub fn dedup_identical_events(events: Vec<SystemProgramEvent>) -> Vec<SystemProgramEvent> {
+    let mut deduped: Vec<SystemProgramEvent> = Vec::with_capacity(events.len());
+    for event in events {
+        if !deduped.iter().any(|kept| kept.event == event.event) {
+            deduped.push(event);
+        }
+    }
+    deduped
+}
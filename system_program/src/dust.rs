@@ -0,0 +1,18 @@
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+/// Drops Transfer and TransferWithSeed events moving fewer than `min_lamports`, and CreateAccount
+/// and CreateAccountWithSeed events funded with fewer than `min_lamports` when
+/// `include_create_account` is set. Other events are kept as is.
+pub fn filter_dust(events: Vec<SystemProgramEvent>, min_lamports: u64, include_create_account: bool) -> Vec<SystemProgramEvent> {
+    if min_lamports == 0 {
+        return events;
+    }
+    events.into_iter().filter(|event| match event.event.as_ref() {
+        Some(Event::Transfer(transfer)) => transfer.lamports >= min_lamports,
+        Some(Event::TransferWithSeed(transfer)) => transfer.lamports >= min_lamports,
+        Some(Event::CreateAccount(create)) if include_create_account => create.lamports >= min_lamports,
+        Some(Event::CreateAccountWithSeed(create)) if include_create_account => create.lamports >= min_lamports,
+        _ => true,
+    }).collect()
+}
@@ -0,0 +1,33 @@
+//! Restriction of the output to transactions involving given accounts, for targeted indexing.
+
+use anyhow::anyhow;
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use substreams_solana_utils::pubkey::Pubkey;
+use spl_token_substream::FromBase58;
+
+/// Parses the `filtered_events` params: a comma-separated list of base58 pubkeys.
+pub fn parse_filter_params(params: &str) -> Result<Vec<Pubkey>, Error> {
+    params.split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| Pubkey::from_base58(param).map_err(|e| anyhow!("Invalid pubkey {}: {}", param, e)))
+        .collect()
+}
+
+/// Whether any of the transaction's account keys, including those loaded from address lookup
+/// tables, is in `pubkeys`. Only reads the raw message and meta, so it is cheap enough to run
+/// before building the structured instructions.
+pub fn involves_any(transaction: &ConfirmedTransaction, pubkeys: &[Pubkey]) -> bool {
+    let static_keys = transaction.transaction.as_ref()
+        .and_then(|transaction| transaction.message.as_ref())
+        .map(|message| message.account_keys.as_slice())
+        .unwrap_or_default();
+    let loaded_keys = transaction.meta.as_ref()
+        .map(|meta| meta.loaded_writable_addresses.iter().chain(meta.loaded_readonly_addresses.iter()));
+
+    static_keys.iter()
+        .chain(loaded_keys.into_iter().flatten())
+        .any(|key| pubkeys.iter().any(|pubkey| pubkey.0.as_slice() == key.as_slice()))
+}
@@ -0,0 +1,49 @@
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+/// Denormalizes the events of a block into one row per event, in block order.
+pub fn flatten_block(block_events: &SystemProgramBlockEvents) -> SystemProgramEventRows {
+    let mut rows: Vec<SystemProgramEventRow> = Vec::new();
+    for transaction in block_events.transactions.iter() {
+        for event in transaction.events.iter() {
+            let Some(inner) = event.event.as_ref() else {
+                continue;
+            };
+            let mut row = flatten_event(inner);
+            row.slot = block_events.slot;
+            row.signature = transaction.signature.clone();
+            row.transaction_index = transaction.transaction_index;
+            row.instruction_index = event.instruction_index;
+            rows.push(row);
+        }
+    }
+    SystemProgramEventRows { rows }
+}
+
+/// The event type and common fields of `event`. The transaction and instruction fields are left empty.
+pub fn flatten_event(event: &Event) -> SystemProgramEventRow {
+    let row = |event_type: &str, lamports: Option<u64>, from: Option<&String>, to: Option<&String>, owner: Option<&String>| SystemProgramEventRow {
+        event_type: event_type.to_string(),
+        lamports,
+        from: from.cloned(),
+        to: to.cloned(),
+        owner: owner.cloned(),
+        ..Default::default()
+    };
+    match event {
+        Event::CreateAccount(e) => row("create_account", Some(e.lamports), Some(&e.funding_account), Some(&e.new_account), Some(&e.owner)),
+        Event::Assign(e) => row("assign", None, None, Some(&e.assigned_account), Some(&e.owner)),
+        Event::Transfer(e) => row("transfer", Some(e.lamports), Some(&e.funding_account), Some(&e.recipient_account), None),
+        Event::CreateAccountWithSeed(e) => row("create_account_with_seed", Some(e.lamports), Some(&e.funding_account), Some(&e.created_account), Some(&e.owner)),
+        Event::AdvanceNonceAccount(e) => row("advance_nonce_account", None, None, Some(&e.nonce_account), None),
+        Event::WithdrawNonceAccount(e) => row("withdraw_nonce_account", Some(e.lamports), Some(&e.nonce_account), Some(&e.recipient_account), None),
+        Event::InitializeNonceAccount(e) => row("initialize_nonce_account", None, None, Some(&e.nonce_account), None),
+        Event::AuthorizeNonceAccount(e) => row("authorize_nonce_account", None, None, Some(&e.nonce_account), None),
+        Event::Allocate(e) => row("allocate", None, None, Some(&e.account), None),
+        Event::AllocateWithSeed(e) => row("allocate_with_seed", None, None, Some(&e.allocated_account), Some(&e.owner)),
+        Event::AssignWithSeed(e) => row("assign_with_seed", None, None, Some(&e.assigned_account), Some(&e.owner)),
+        Event::TransferWithSeed(e) => row("transfer_with_seed", Some(e.lamports), Some(&e.funding_account), Some(&e.recipient_account), None),
+        Event::UpgradeNonceAccount(e) => row("upgrade_nonce_account", None, None, Some(&e.nonce_account), None),
+        Event::Unknown(_) => row("unknown", None, None, None, None),
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use crate::pb::system_program::system_program_event::Event;
+use crate::parse_transaction;
+use crate::parser::get_account_from_index;
+
+/// Net lamports moved between accounts by the System Program in `transaction`, as
+/// `(from, to, lamports)` edges with base58 addresses.
+///
+/// Transfers, transfers with seed, nonce withdrawals and the funding of created accounts are
+/// summed per pair of accounts, and opposite flows cancel out, so each pair yields at most one
+/// edge. Self-transfers and pairs netting to zero are dropped. Edges are sorted by `(from, to)`.
+pub fn sol_flow(transaction: &ConfirmedTransaction) -> Result<Vec<(String, String, u64)>, Error> {
+    let mut balances: BTreeMap<(String, String), i128> = BTreeMap::new();
+    for event in parse_transaction(transaction)?.into_iter().filter_map(|event| event.event) {
+        let (from, to, lamports) = match event {
+            Event::Transfer(transfer) => (transfer.funding_account, transfer.recipient_account, transfer.lamports),
+            Event::TransferWithSeed(transfer) => (transfer.funding_account, transfer.recipient_account, transfer.lamports),
+            Event::WithdrawNonceAccount(withdraw) => (withdraw.nonce_account, withdraw.recipient_account, withdraw.lamports),
+            Event::CreateAccount(create) => (create.funding_account, create.new_account, create.lamports),
+            Event::CreateAccountWithSeed(create) => (create.funding_account, create.created_account, create.lamports),
+            _ => continue,
+        };
+        if from == to || lamports == 0 {
+            continue;
+        }
+        // Each pair is keyed in sorted order, with the balance counted from the smaller address.
+        if from < to {
+            *balances.entry((from, to)).or_default() += lamports as i128;
+        } else {
+            *balances.entry((to, from)).or_default() -= lamports as i128;
+        }
+    }
+
+    let mut edges: Vec<(String, String, u64)> = balances.into_iter()
+        .filter(|(_, balance)| *balance != 0)
+        .map(|((a, b), balance)| {
+            let lamports = balance.unsigned_abs() as u64;
+            if balance > 0 { (a, b, lamports) } else { (b, a, lamports) }
+        })
+        .collect();
+    edges.sort();
+    Ok(edges)
+}
+
+/// Accounts holding lamports before `transaction` and none after it, as base58 addresses in
+/// account key order. The runtime deletes accounts left without lamports, so these are the
+/// accounts the transaction closed, whatever their owner: System accounts drained by a transfer
+/// as well as token accounts closed through the Token program. Failed transactions only move
+/// the fee, so they may close their fee payer at most.
+pub fn closed_accounts(transaction: &ConfirmedTransaction) -> Vec<String> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        return Vec::new();
+    };
+    meta.pre_balances.iter()
+        .zip(meta.post_balances.iter())
+        .enumerate()
+        .filter(|(_, (pre, post))| **pre > 0 && **post == 0)
+        .filter_map(|(i, _)| get_account_from_index(transaction, i))
+        .map(|account| bs58::encode(account).into_string())
+        .collect()
+}
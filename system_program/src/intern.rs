@@ -0,0 +1,185 @@
+//! Pubkey interning for `SystemProgramBlockEvents`.
+//!
+//! With `intern_pubkeys=true`, each distinct pubkey string of the block is stored once in
+//! `pubkey_table`. The string fields of the events are cleared, and the `*_index` field next to
+//! each one holds the index of its pubkey there. `resolve_pubkeys` restores the inline strings on
+//! the consumer side.
+
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use substreams::errors::Error;
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+/// A pubkey string field along with its index field.
+trait PubkeyVisitor {
+    fn pubkey(&mut self, pubkey: &mut String, index: &mut Option<u32>) -> Result<(), Error>;
+
+    fn pubkeys(&mut self, pubkeys: &mut Vec<String>, indexes: &mut Vec<u32>) -> Result<(), Error>;
+}
+
+#[derive(Default)]
+struct PubkeyTable {
+    pubkeys: Vec<String>,
+    indexes: HashMap<String, u32>,
+}
+
+impl PubkeyTable {
+    fn index(&mut self, pubkey: String) -> u32 {
+        match self.indexes.get(pubkey.as_str()) {
+            Some(index) => *index,
+            None => {
+                let index = self.pubkeys.len() as u32;
+                self.indexes.insert(pubkey.clone(), index);
+                self.pubkeys.push(pubkey);
+                index
+            }
+        }
+    }
+}
+
+impl PubkeyVisitor for PubkeyTable {
+    fn pubkey(&mut self, pubkey: &mut String, index: &mut Option<u32>) -> Result<(), Error> {
+        if !pubkey.is_empty() {
+            *index = Some(self.index(std::mem::take(pubkey)));
+        }
+        Ok(())
+    }
+
+    fn pubkeys(&mut self, pubkeys: &mut Vec<String>, indexes: &mut Vec<u32>) -> Result<(), Error> {
+        *indexes = pubkeys.drain(..).map(|pubkey| self.index(pubkey)).collect();
+        Ok(())
+    }
+}
+
+/// Moves the pubkeys of `block_events` into `pubkey_table`, filled in order of first appearance,
+/// and sets the index fields of the pubkeys. Empty fields, left by `encoding=bytes`, stay empty
+/// without an index.
+pub fn intern_pubkeys(block_events: &mut SystemProgramBlockEvents) {
+    let mut table = PubkeyTable::default();
+    for transaction in block_events.transactions.iter_mut() {
+        let _ = visit_transaction_pubkeys(transaction, &mut table);
+    }
+    block_events.pubkey_table = table.pubkeys;
+}
+
+struct PubkeyResolver<'a> {
+    table: &'a [String],
+}
+
+impl PubkeyResolver<'_> {
+    fn resolve(&self, index: u32) -> Result<String, Error> {
+        self.table.get(index as usize).cloned().ok_or_else(|| anyhow!("Invalid pubkey index {}.", index))
+    }
+}
+
+impl PubkeyVisitor for PubkeyResolver<'_> {
+    fn pubkey(&mut self, pubkey: &mut String, index: &mut Option<u32>) -> Result<(), Error> {
+        if let Some(index) = index.take() {
+            *pubkey = self.resolve(index)?;
+        }
+        Ok(())
+    }
+
+    fn pubkeys(&mut self, pubkeys: &mut Vec<String>, indexes: &mut Vec<u32>) -> Result<(), Error> {
+        if !indexes.is_empty() {
+            *pubkeys = indexes.drain(..).map(|index| self.resolve(index)).collect::<Result<_, _>>()?;
+        }
+        Ok(())
+    }
+}
+
+/// Inverse of `intern_pubkeys`: writes the pubkeys of `pubkey_table` back into the events,
+/// clearing the index fields and the table. Does nothing when the table is empty, i.e. the
+/// pubkeys are already inline.
+pub fn resolve_pubkeys(block_events: &mut SystemProgramBlockEvents) -> Result<(), Error> {
+    if block_events.pubkey_table.is_empty() {
+        return Ok(());
+    }
+    let table = std::mem::take(&mut block_events.pubkey_table);
+    let mut resolver = PubkeyResolver { table: &table };
+    for transaction in block_events.transactions.iter_mut() {
+        visit_transaction_pubkeys(transaction, &mut resolver)?;
+    }
+    Ok(())
+}
+
+fn visit_transaction_pubkeys<V: PubkeyVisitor>(transaction: &mut SystemProgramTransactionEvents, visitor: &mut V) -> Result<(), Error> {
+    visitor.pubkeys(&mut transaction.signers, &mut transaction.signer_indexes)?;
+    visitor.pubkey(&mut transaction.fee_payer, &mut transaction.fee_payer_index)?;
+    for event in transaction.events.iter_mut() {
+        visit_pubkeys(event, visitor)?;
+    }
+    Ok(())
+}
+
+/// Hands every pubkey string field of `event` to `visitor`. Seeds are not pubkeys and are
+/// skipped.
+fn visit_pubkeys<V: PubkeyVisitor>(event: &mut SystemProgramEvent, visitor: &mut V) -> Result<(), Error> {
+    visitor.pubkey(&mut event.invoking_program, &mut event.invoking_program_index)?;
+    for account in event.accounts.iter_mut() {
+        visitor.pubkey(&mut account.pubkey, &mut account.pubkey_index)?;
+    }
+    match event.event.as_mut() {
+        Some(Event::CreateAccount(event)) => {
+            visitor.pubkey(&mut event.funding_account, &mut event.funding_account_index)?;
+            visitor.pubkey(&mut event.new_account, &mut event.new_account_index)?;
+            visitor.pubkey(&mut event.owner, &mut event.owner_index)
+        },
+        Some(Event::Assign(event)) => {
+            visitor.pubkey(&mut event.assigned_account, &mut event.assigned_account_index)?;
+            visitor.pubkey(&mut event.owner, &mut event.owner_index)
+        },
+        Some(Event::Transfer(event)) => {
+            visitor.pubkey(&mut event.funding_account, &mut event.funding_account_index)?;
+            visitor.pubkey(&mut event.recipient_account, &mut event.recipient_account_index)
+        },
+        Some(Event::CreateAccountWithSeed(event)) => {
+            visitor.pubkey(&mut event.funding_account, &mut event.funding_account_index)?;
+            visitor.pubkey(&mut event.created_account, &mut event.created_account_index)?;
+            visitor.pubkey(&mut event.base_account, &mut event.base_account_index)?;
+            visitor.pubkey(&mut event.owner, &mut event.owner_index)
+        },
+        Some(Event::AdvanceNonceAccount(event)) => {
+            visitor.pubkey(&mut event.nonce_account, &mut event.nonce_account_index)?;
+            visitor.pubkey(&mut event.nonce_authority, &mut event.nonce_authority_index)
+        },
+        Some(Event::WithdrawNonceAccount(event)) => {
+            visitor.pubkey(&mut event.nonce_account, &mut event.nonce_account_index)?;
+            visitor.pubkey(&mut event.recipient_account, &mut event.recipient_account_index)?;
+            visitor.pubkey(&mut event.nonce_authority, &mut event.nonce_authority_index)
+        },
+        Some(Event::InitializeNonceAccount(event)) => {
+            visitor.pubkey(&mut event.nonce_account, &mut event.nonce_account_index)?;
+            visitor.pubkey(&mut event.nonce_authority, &mut event.nonce_authority_index)?;
+            visitor.pubkey(&mut event.rent_payer, &mut event.rent_payer_index)
+        },
+        Some(Event::AuthorizeNonceAccount(event)) => {
+            visitor.pubkey(&mut event.nonce_account, &mut event.nonce_account_index)?;
+            visitor.pubkey(&mut event.nonce_authority, &mut event.nonce_authority_index)?;
+            visitor.pubkey(&mut event.new_nonce_authority, &mut event.new_nonce_authority_index)
+        },
+        Some(Event::Allocate(event)) => visitor.pubkey(&mut event.account, &mut event.account_index),
+        Some(Event::AllocateWithSeed(event)) => {
+            visitor.pubkey(&mut event.allocated_account, &mut event.allocated_account_index)?;
+            visitor.pubkey(&mut event.base_account, &mut event.base_account_index)?;
+            visitor.pubkey(&mut event.owner, &mut event.owner_index)
+        },
+        Some(Event::AssignWithSeed(event)) => {
+            visitor.pubkey(&mut event.assigned_account, &mut event.assigned_account_index)?;
+            visitor.pubkey(&mut event.base_account, &mut event.base_account_index)?;
+            visitor.pubkey(&mut event.owner, &mut event.owner_index)
+        },
+        Some(Event::TransferWithSeed(event)) => {
+            visitor.pubkey(&mut event.funding_account, &mut event.funding_account_index)?;
+            visitor.pubkey(&mut event.base_account, &mut event.base_account_index)?;
+            visitor.pubkey(&mut event.recipient_account, &mut event.recipient_account_index)?;
+            visitor.pubkey(&mut event.from_owner, &mut event.from_owner_index)
+        },
+        Some(Event::UpgradeNonceAccount(event)) => visitor.pubkey(&mut event.nonce_account, &mut event.nonce_account_index),
+        Some(Event::Unknown(event)) => visitor.pubkeys(&mut event.accounts, &mut event.account_indexes),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,19 @@
+use substreams_solana::b58;
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// Tip payment accounts of the Jito block engine. Bundles pay their tip with a plain System
+/// Program transfer to one of them.
+pub const JITO_TIP_ACCOUNTS: [Pubkey; 8] = [
+    Pubkey(b58!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5")),
+    Pubkey(b58!("HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe")),
+    Pubkey(b58!("Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY")),
+    Pubkey(b58!("ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49")),
+    Pubkey(b58!("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh")),
+    Pubkey(b58!("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt")),
+    Pubkey(b58!("DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL")),
+    Pubkey(b58!("3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT")),
+];
+
+pub fn is_jito_tip_account(pubkey: &Pubkey) -> bool {
+    JITO_TIP_ACCOUNTS.contains(pubkey)
+}
@@ -1,215 +1,643 @@
 use anyhow::anyhow;
 use anyhow::Context;
 use substreams::errors::Error;
-use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::{ConfirmedTransaction, Message};
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
 
 use substreams_solana_utils as utils;
-use utils::transaction::{get_context, TransactionContext};
-use utils::instruction::{get_structured_instructions, StructuredInstructions, StructuredInstruction};
-use utils::system_program::{self, SystemInstruction, SYSTEM_PROGRAM_ID};
+use utils::transaction::TransactionContext;
+use utils::instruction::StructuredInstruction;
+use utils::system_program::SYSTEM_PROGRAM_ID;
+use utils::spl_token::TOKEN_PROGRAM_ID;
 use utils::pubkey::Pubkey;
 
 pub mod pb;
+pub mod parser;
+pub mod constants;
+pub mod options;
+pub mod rent;
+pub mod tree;
+pub mod memo_program;
+pub mod compute_budget;
+pub mod precompiles;
+pub mod wsol;
+pub mod merge;
+pub mod flow;
+pub mod flat;
+pub mod account_metas;
+pub mod intern;
+pub mod nonce;
+pub mod jito;
+pub mod dust;
+pub mod dedup;
+pub mod filter;
+pub mod token;
+pub use system_program_decode as decode;
+use decode::SystemInstruction;
+use constants::*;
 use pb::system_program::*;
 use pb::system_program::system_program_event::Event;
+pub use parser::{InstructionParser, ParserRegistry, ParseError, FlattenedInstructions, parse_transaction_multi, parse_transaction_multi_isolated, instruction_count, get_account_from_index, is_vote_transaction, decode_transaction_base64};
+pub use flow::{sol_flow, closed_accounts};
+pub use intern::{intern_pubkeys, resolve_pubkeys};
+pub use options::{ParseOptions, Encoding, PubkeyEncoding, encode_pubkey, format_pubkey};
 
 #[substreams::handlers::map]
-fn system_program_events(block: Block) -> Result<SystemProgramBlockEvents, Error> {
-    let transactions = parse_block(&block)?;
-    Ok(SystemProgramBlockEvents { slot: block.slot, transactions })
+fn system_program_events(params: String, block: Block) -> Result<SystemProgramBlockEvents, Error> {
+    let options = ParseOptions::from_params(&params)?;
+    parse_block_with_options(&block, &options)
+}
+
+/// Same events as `system_program_events`, one flat row per event. Account fields are always
+/// strings here, whatever the `encoding` and `intern_pubkeys` params.
+#[substreams::handlers::map]
+fn system_program_events_flat(params: String, block: Block) -> Result<SystemProgramEventRows, Error> {
+    let options = ParseOptions { encoding: Encoding::Base58, intern_pubkeys: false, ..ParseOptions::from_params(&params)? };
+    Ok(flat::flatten_block(&parse_block_with_options(&block, &options)?))
+}
+
+#[substreams::handlers::map]
+fn system_program_event_tree(params: String, block: Block) -> Result<SystemProgramBlockEventTrees, Error> {
+    let options = ParseOptions::from_params(&params)?;
+    tree::parse_block_tree(&block, &options)
+}
+
+/// Same events as `system_program_events` with the default options, restricted to transactions
+/// involving one of the comma-separated base58 pubkeys of `params`.
+#[substreams::handlers::map]
+fn filtered_events(params: String, block: Block) -> Result<SystemProgramBlockEvents, Error> {
+    let pubkeys = filter::parse_filter_params(&params)?;
+    parse_block_filtered(&block, &ParseOptions::default(), &pubkeys)
+}
+
+#[substreams::handlers::map]
+fn compute_budget_events(block: Block) -> Result<ComputeBudgetBlockEvents, Error> {
+    compute_budget::parse_block(&block)
+}
+
+#[substreams::handlers::map]
+fn memo_events(block: Block) -> Result<MemoBlockEvents, Error> {
+    memo_program::parse_block(&block)
+}
+
+#[substreams::handlers::map]
+fn precompile_events(block: Block) -> Result<PrecompileBlockEvents, Error> {
+    precompiles::parse_block(&block)
 }
 
 pub fn parse_block(block: &Block) -> Result<Vec<SystemProgramTransactionEvents>, Error> {
+    parse_block_iter(block, &ParseOptions::default()).collect()
+}
+
+/// Lazily parses the transactions of `block`, yielding those with events one at a time so the
+/// caller can process and drop them as it goes. Vote transactions, transactions without meta and
+/// transactions over the instruction limit are skipped, as in `parse_block_with_options`.
+pub fn parse_block_iter<'a>(
+    block: &'a Block,
+    options: &'a ParseOptions,
+) -> impl Iterator<Item = Result<SystemProgramTransactionEvents, Error>> + 'a {
+    block.transactions.iter().enumerate()
+        .filter(|(_, transaction)| !is_vote_transaction(transaction) && !parser::skip_missing_meta(transaction))
+        .filter(|(_, transaction)| !exceeds_instruction_limit(transaction, options))
+        .filter_map(|(i, transaction)| parse_transaction_full_with_options(transaction, i as u32, options).transpose())
+}
+
+/// Parses `block` in chunks of at most `chunk_size` transactions with events, handing each chunk
+/// to `f` before parsing the next. Stops at the first error, from parsing or from `f`.
+pub fn parse_block_chunked<F>(block: &Block, options: &ParseOptions, chunk_size: usize, mut f: F) -> Result<(), Error>
+where
+    F: FnMut(Vec<SystemProgramTransactionEvents>) -> Result<(), Error>,
+{
+    let chunk_size = chunk_size.max(1);
+    let mut chunk: Vec<SystemProgramTransactionEvents> = Vec::with_capacity(chunk_size);
+    for transaction_events in parse_block_iter(block, options) {
+        chunk.push(transaction_events?);
+        if chunk.len() == chunk_size {
+            f(std::mem::replace(&mut chunk, Vec::with_capacity(chunk_size)))?;
+        }
+    }
+    if !chunk.is_empty() {
+        f(chunk)?;
+    }
+    Ok(())
+}
+
+pub fn parse_block_with_options(block: &Block, options: &ParseOptions) -> Result<SystemProgramBlockEvents, Error> {
+    parse_block_where(block, options, |_| true)
+}
+
+/// Like `parse_block_with_options`, restricted to transactions whose account keys contain at
+/// least one of `pubkeys`. The check runs on the raw account keys, before any parsing.
+pub fn parse_block_filtered(block: &Block, options: &ParseOptions, pubkeys: &[Pubkey]) -> Result<SystemProgramBlockEvents, Error> {
+    parse_block_where(block, options, |transaction| filter::involves_any(transaction, pubkeys))
+}
+
+fn parse_block_where<F>(block: &Block, options: &ParseOptions, predicate: F) -> Result<SystemProgramBlockEvents, Error>
+where
+    F: Fn(&ConfirmedTransaction) -> bool,
+{
     let mut block_events: Vec<SystemProgramTransactionEvents> = Vec::new();
+    let mut truncated_transactions: u32 = 0;
+    let mut skipped_vote_transactions: u32 = 0;
+    let mut event_counts = EventCounts::default();
     for (i, transaction) in block.transactions.iter().enumerate() {
-        let events = parse_transaction(transaction)?;
-        if !events.is_empty() {
-            block_events.push(SystemProgramTransactionEvents {
-                signature: utils::transaction::get_signature(transaction),
-                transaction_index: i as u32,
-                events,
-            });
+        if !predicate(transaction) {
+            continue;
+        }
+        if is_vote_transaction(transaction) {
+            skipped_vote_transactions += 1;
+            continue;
+        }
+        if parser::skip_missing_meta(transaction) {
+            continue;
+        }
+        if exceeds_instruction_limit(transaction, options) {
+            truncated_transactions += 1;
+            continue;
+        }
+        if let Some(transaction_events) = parse_transaction_full_with_options(transaction, i as u32, options)? {
+            transaction_events.events.iter().filter_map(|event| event.event.as_ref()).for_each(|event| count_event(&mut event_counts, event));
+            block_events.push(transaction_events);
         }
     }
-    Ok(block_events)
+    let mut output = SystemProgramBlockEvents {
+        slot: block.slot,
+        transactions_scanned: block.transactions.len() as u32,
+        transactions_with_events: block_events.len() as u32,
+        transactions: block_events,
+        truncated_transactions,
+        event_counts: Some(event_counts),
+        skipped_vote_transactions,
+        pubkey_table: Vec::new(),
+        parent_slot: block.parent_slot,
+        blockhash: block.blockhash.clone(),
+    };
+    if options.intern_pubkeys {
+        intern::intern_pubkeys(&mut output);
+    }
+    Ok(output)
+}
+
+fn count_event(counts: &mut EventCounts, event: &Event) {
+    let count = match event {
+        Event::CreateAccount(_) => &mut counts.create_account,
+        Event::Assign(_) => &mut counts.assign,
+        Event::Transfer(_) => &mut counts.transfer,
+        Event::CreateAccountWithSeed(_) => &mut counts.create_account_with_seed,
+        Event::AdvanceNonceAccount(_) => &mut counts.advance_nonce_account,
+        Event::WithdrawNonceAccount(_) => &mut counts.withdraw_nonce_account,
+        Event::InitializeNonceAccount(_) => &mut counts.initialize_nonce_account,
+        Event::AuthorizeNonceAccount(_) => &mut counts.authorize_nonce_account,
+        Event::Allocate(_) => &mut counts.allocate,
+        Event::AllocateWithSeed(_) => &mut counts.allocate_with_seed,
+        Event::AssignWithSeed(_) => &mut counts.assign_with_seed,
+        Event::TransferWithSeed(_) => &mut counts.transfer_with_seed,
+        Event::UpgradeNonceAccount(_) => &mut counts.upgrade_nonce_account,
+        Event::Unknown(_) => &mut counts.unknown,
+    };
+    *count += 1;
+}
+
+/// The first `num_required_signatures` static account keys of the message, formatted with `encoding`.
+fn get_signers(message: &Message, encoding: PubkeyEncoding) -> Vec<String> {
+    let num_required_signatures = message.header.as_ref().map_or(0, |header| header.num_required_signatures as usize);
+    message.account_keys.iter()
+        .take(num_required_signatures)
+        .map(|key| format_pubkey(key, encoding))
+        .collect()
+}
+
+pub fn parse_transaction_full(transaction: &ConfirmedTransaction, transaction_index: u32) -> Result<Option<SystemProgramTransactionEvents>, Error> {
+    parse_transaction_full_with_options(transaction, transaction_index, &ParseOptions::default())
+}
+
+/// Parses `transaction` into its complete per-transaction message, as emitted by
+/// `system_program_events`. Returns `None` when the transaction has no events, which includes
+/// failed and skipped transactions.
+pub fn parse_transaction_full_with_options(
+    transaction: &ConfirmedTransaction,
+    transaction_index: u32,
+    options: &ParseOptions,
+) -> Result<Option<SystemProgramTransactionEvents>, Error> {
+    let (events, failed_instruction_indices) = parse_transaction_events(transaction, options)?;
+    if events.is_empty() && failed_instruction_indices.is_empty() {
+        return Ok(None)
+    }
+    let meta = transaction.meta.as_ref();
+    let message = transaction.transaction.as_ref().and_then(|transaction| transaction.message.as_ref());
+    let budget = message.map(compute_budget::compute_budget).unwrap_or_default();
+    Ok(Some(SystemProgramTransactionEvents {
+        signature: utils::transaction::get_signature(transaction),
+        transaction_index,
+        events,
+        compute_units_consumed: meta.and_then(|meta| meta.compute_units_consumed),
+        has_return_data: meta.map_or(false, |meta| meta.return_data.is_some()),
+        recent_blockhash: message.map(|message| bs58::encode(&message.recent_blockhash).into_string()).unwrap_or_default(),
+        version: message.map_or(-1, |message| if message.versioned { 0 } else { -1 }),
+        signers: message.map(|message| get_signers(message, options.pubkey_encoding)).unwrap_or_default(),
+        fee_payer: get_account_from_index(transaction, 0)
+            .map(|fee_payer| format_pubkey(fee_payer, options.pubkey_encoding))
+            .unwrap_or_default(),
+        signer_indexes: Vec::new(),
+        fee_payer_index: None,
+        memo: message.and_then(memo_program::top_level_memo),
+        compute_unit_price_micro_lamports: budget.compute_unit_price_micro_lamports,
+        compute_unit_limit: budget.compute_unit_limit,
+        failed_instruction_indices,
+        log_messages: match meta {
+            Some(meta) if options.include_log_messages => meta.log_messages.clone(),
+            _ => Vec::new(),
+        },
+    }))
 }
 
 pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<SystemProgramEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
-        return Ok(Vec::new())
+    parse_transaction_with_options(transaction, &ParseOptions::default())
+}
+
+pub fn parse_transaction_with_options(transaction: &ConfirmedTransaction, options: &ParseOptions) -> Result<Vec<SystemProgramEvent>, Error> {
+    Ok(parse_transaction_events(transaction, options)?.0)
+}
+
+/// Decoded events of `transaction`, along with the indexes of the System Program instructions
+/// that failed to decode. A failed instruction doesn't prevent the others from being emitted.
+fn parse_transaction_events(transaction: &ConfirmedTransaction, options: &ParseOptions) -> Result<(Vec<SystemProgramEvent>, Vec<u32>), Error> {
+    if exceeds_instruction_limit(transaction, options) {
+        return Ok((Vec::new(), Vec::new()))
     }
+    let parser = SystemProgramParser { options: options.clone() };
+    let (events, failed_instruction_indices) = parse_transaction_multi_isolated::<Event>(transaction, &[&parser])?;
+    let mut events: Vec<SystemProgramEvent> = events.into_iter().map(|(instruction_index, event)| SystemProgramEvent {
+        instruction_index,
+        event: Some(event),
+        ..Default::default()
+    }).collect();
+    annotate_invoking_programs(transaction, &mut events, options.pubkey_encoding);
+    wsol::annotate_wsol_wraps(transaction, &mut events, options.pubkey_encoding)?;
+    nonce::annotate_nonce_rent_payers(&mut events);
+    if options.dedup_identical_events {
+        events = dedup::dedup_identical_events(events);
+    }
+    if options.merge_transfers {
+        events = merge::merge_consecutive_transfers(events);
+    }
+    // After the annotation passes and merging, so dust still counts towards WSOL wraps, nonce
+    // rent payers and merged transfers.
+    events = dust::filter_dust(events, options.min_transfer_lamports, options.filter_create_account_dust);
+    if options.include_account_metas {
+        account_metas::annotate_account_metas(transaction, &mut events, options.pubkey_encoding);
+    }
+    Ok((events, failed_instruction_indices))
+}
 
-    let mut events: Vec<SystemProgramEvent> = Vec::new();
+/// Sets `invoking_program` on each event to the program id of the instruction that invoked its
+/// instruction, leaving it empty for top-level instructions.
+pub fn annotate_invoking_programs(
+    transaction: &ConfirmedTransaction,
+    events: &mut [SystemProgramEvent],
+    pubkey_encoding: PubkeyEncoding,
+) {
+    let invoking_programs = parser::invoking_program_ids(transaction);
+    for event in events.iter_mut() {
+        event.invoking_program = invoking_programs.get(event.instruction_index as usize).copied().flatten()
+            .map(|program_id| format_pubkey(program_id, pubkey_encoding))
+            .unwrap_or_default();
+    }
+}
 
-    let context = get_context(transaction)?;
-    let instructions = get_structured_instructions(transaction)?;
+fn exceeds_instruction_limit(transaction: &ConfirmedTransaction, options: &ParseOptions) -> bool {
+    let count = instruction_count(transaction);
+    if count > options.max_instructions_per_transaction {
+        substreams::log::println(format!(
+            "Skipping transaction {} with {} instructions (limit is {})",
+            utils::transaction::get_signature(transaction),
+            count,
+            options.max_instructions_per_transaction,
+        ));
+        return true;
+    }
+    false
+}
 
-    for (i, instruction) in instructions.flattened().iter().enumerate() {
-        if instruction.program_id() == SYSTEM_PROGRAM_ID {
-            match parse_instruction(instruction, &context) {
-                Ok(event) => {
-                    events.push(SystemProgramEvent { instruction_index: i as u32, event });
-                },
-                Err(e) => return Err(anyhow!("Failed to parse transaction {} with error: {}", context.signature, e))
-            }
-        }
+/// Event of the parsers registered by `default_registry`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegistryEvent {
+    System(Event),
+    SplToken(token::TokenEvent),
+}
+
+impl From<Event> for RegistryEvent {
+    fn from(event: Event) -> Self {
+        RegistryEvent::System(event)
     }
+}
 
-    Ok(events)
+impl From<token::TokenEvent> for RegistryEvent {
+    fn from(event: token::TokenEvent) -> Self {
+        RegistryEvent::SplToken(event)
+    }
+}
+
+/// Registry with the built-in System Program and SPL Token parsers, to which callers can add
+/// their own parsers, or replace the built-in ones. Any event type the built-in events convert
+/// into can be used, `RegistryEvent` being the one holding just those.
+pub fn default_registry<T>(options: &ParseOptions) -> ParserRegistry<T>
+where
+    T: From<Event> + From<token::TokenEvent> + 'static,
+{
+    let mut registry = ParserRegistry::new();
+    registry.register(SystemProgramParser { options: options.clone() });
+    registry.register(token::SplTokenParser);
+    registry
+}
+
+#[derive(Default)]
+pub struct SystemProgramParser {
+    pub options: ParseOptions,
+}
+
+impl<T: From<Event>> InstructionParser<T> for SystemProgramParser {
+    fn program_id(&self) -> Pubkey {
+        SYSTEM_PROGRAM_ID
+    }
+
+    fn parse(
+        &self,
+        instruction: &StructuredInstruction,
+        context: &TransactionContext,
+    ) -> Result<Option<T>, ParseError> {
+        Ok(parse_instruction_with_options(instruction, context, &self.options)?.map(T::from))
+    }
 }
 
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext
+) -> Result<Option<Event>, Error> {
+    parse_instruction_with_options(instruction, context, &ParseOptions::default())
+}
+
+pub fn parse_instruction_with_options<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    options: &ParseOptions,
 ) -> Result<Option<Event>, Error> {
     if instruction.program_id() != SYSTEM_PROGRAM_ID {
         return Err(anyhow!("Not a System Program instruction."));
     }
-    let unpacked = SystemInstruction::unpack(&instruction.data())?;
+    if let Some(discriminant) = unknown_discriminant(&instruction.data()) {
+        return Ok(Some(Event::Unknown(_parse_unknown_instruction(instruction, options, discriminant))));
+    }
+    check_authorize_nonce_account_data(&instruction.data())?;
+    let data = instruction.data();
+    let unpacked = SystemInstruction::unpack(&data)?;
     match unpacked {
-        SystemInstruction::CreateAccount(create_account) => {
-            _parse_create_account_instruction(instruction, context, &create_account).map(|x| Some(Event::CreateAccount(x)))
+        SystemInstruction::CreateAccount { lamports, space, owner } => {
+            _parse_create_account_instruction(instruction, context, options, lamports, space, Pubkey(owner)).map(|x| Some(Event::CreateAccount(x)))
         },
-        SystemInstruction::Assign(assign) => {
-            _parse_assign_instruction(instruction, context, &assign).map(|x| Some(Event::Assign(x)))
+        SystemInstruction::Assign { owner } => {
+            _parse_assign_instruction(instruction, context, options, Pubkey(owner)).map(|x| Some(Event::Assign(x)))
         },
-        SystemInstruction::Transfer(transfer) => {
-            _parse_transfer_instruction(instruction, context, &transfer).map(|x| Some(Event::Transfer(x)))
+        SystemInstruction::Transfer { lamports } => {
+            _parse_transfer_instruction(instruction, context, options, lamports).map(|x| Some(Event::Transfer(x)))
         },
-        SystemInstruction::CreateAccountWithSeed(create_account_with_seed) => {
-            _parse_create_account_with_seed_instruction(instruction, context, &create_account_with_seed).map(|x| Some(Event::CreateAccountWithSeed(x)))
+        SystemInstruction::CreateAccountWithSeed { base, seed, lamports, space, owner } => {
+            _parse_create_account_with_seed_instruction(instruction, context, options, Pubkey(base), seed, lamports, space, Pubkey(owner)).map(|x| Some(Event::CreateAccountWithSeed(x)))
         },
         SystemInstruction::AdvanceNonceAccount => {
-            _parse_advance_nonce_account_instruction(instruction, context).map(|x| Some(Event::AdvanceNonceAccount(x)))
+            _parse_advance_nonce_account_instruction(instruction, context, options).map(|x| Some(Event::AdvanceNonceAccount(x)))
         },
-        SystemInstruction::WithdrawNonceAccount(lamports) => {
-            _parse_withdraw_nonce_account_instruction(instruction, context, lamports).map(|x| Some(Event::WithdrawNonceAccount(x)))
+        SystemInstruction::WithdrawNonceAccount { lamports } => {
+            _parse_withdraw_nonce_account_instruction(instruction, context, options, lamports).map(|x| Some(Event::WithdrawNonceAccount(x)))
         },
-        SystemInstruction::InitializeNonceAccount(pubkey) => {
-            _parse_initialize_nonce_account_instruction(instruction, context, pubkey).map(|x| Some(Event::InitializeNonceAccount(x)))
+        SystemInstruction::InitializeNonceAccount { authority } => {
+            _parse_initialize_nonce_account_instruction(instruction, context, options, Pubkey(authority)).map(|x| Some(Event::InitializeNonceAccount(x)))
         },
-        SystemInstruction::AuthorizeNonceAccount(pubkey) => {
-            _parse_authorize_nonce_account_instruction(instruction, context, pubkey).map(|x| Some(Event::AuthorizeNonceAccount(x)))
+        SystemInstruction::AuthorizeNonceAccount { new_authority } => {
+            _parse_authorize_nonce_account_instruction(instruction, context, options, Pubkey(new_authority)).map(|x| Some(Event::AuthorizeNonceAccount(x)))
         },
-        SystemInstruction::Allocate(allocate) => {
-            _parse_allocate_instruction(instruction, context, &allocate).map(|x| Some(Event::Allocate(x)))
+        SystemInstruction::Allocate { space } => {
+            _parse_allocate_instruction(instruction, context, options, space).map(|x| Some(Event::Allocate(x)))
         },
-        SystemInstruction::AllocateWithSeed(allocate_with_seed) => {
-            _parse_allocate_with_seed_instruction(instruction, context, &allocate_with_seed).map(|x| Some(Event::AllocateWithSeed(x)))
+        SystemInstruction::AllocateWithSeed { base, seed, space, owner } => {
+            _parse_allocate_with_seed_instruction(instruction, context, options, Pubkey(base), seed, space, Pubkey(owner)).map(|x| Some(Event::AllocateWithSeed(x)))
         },
-        SystemInstruction::AssignWithSeed(assign_with_seed) => {
-            _parse_assign_with_seed_instruction(instruction, context, &assign_with_seed).map(|x| Some(Event::AssignWithSeed(x)))
+        SystemInstruction::AssignWithSeed { base, seed, owner } => {
+            _parse_assign_with_seed_instruction(instruction, context, options, Pubkey(base), seed, Pubkey(owner)).map(|x| Some(Event::AssignWithSeed(x)))
         },
-        SystemInstruction::TransferWithSeed(transfer_with_seed) => {
-            _parse_transfer_with_seed_instruction(instruction, context, transfer_with_seed).map(|x| Some(Event::TransferWithSeed(x)))
+        SystemInstruction::TransferWithSeed { lamports, from_seed, from_owner } => {
+            _parse_transfer_with_seed_instruction(instruction, context, options, lamports, from_seed, Pubkey(from_owner)).map(|x| Some(Event::TransferWithSeed(x)))
         },
         SystemInstruction::UpgradeNonceAccount => {
-            _parse_upgrade_nonce_account_instruction(instruction, context).map(|x| Some(Event::UpgradeNonceAccount(x)))
+            _parse_upgrade_nonce_account_instruction(instruction, context, options).map(|x| Some(Event::UpgradeNonceAccount(x)))
         }
     }.context("Failed to parse System instruction")
 }
 
+fn unknown_discriminant(data: &[u8]) -> Option<u32> {
+    decode::discriminant(data).filter(|discriminant| *discriminant > decode::MAX_KNOWN_DISCRIMINANT)
+}
+
+fn _parse_unknown_instruction(
+    instruction: &StructuredInstruction,
+    options: &ParseOptions,
+    discriminant: u32,
+) -> UnknownEvent {
+    UnknownEvent {
+        discriminant,
+        data: instruction.data().to_vec(),
+        accounts: instruction.accounts().iter().map(|account| format_pubkey(&account.0, options.pubkey_encoding)).collect(),
+        account_indexes: Vec::new(),
+    }
+}
+
 fn _parse_create_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    create_account: &system_program::CreateAccount,
+    options: &ParseOptions,
+    lamports: u64,
+    space: u64,
+    owner: Pubkey,
 ) -> Result<CreateAccountEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let new_account = instruction.accounts()[1].to_string();
-    let lamports = create_account.lamports;
-    let owner = create_account.owner.to_string();
-    let space = create_account.space;
+    let funding_account = encode_pubkey(&instruction.accounts()[0], options);
+    let new_account = encode_pubkey(&instruction.accounts()[1], options);
+    let owner_program = known_program(&owner).into();
+    let owner = encode_pubkey(&owner, options);
+    let rent_exempt_minimum = rent::rent_exempt_minimum(space);
+    let is_rent_exempt = rent::is_rent_exempt(lamports, space);
 
     Ok(CreateAccountEvent {
-        funding_account,
-        new_account,
+        funding_account: funding_account.text,
+        funding_account_bytes: funding_account.bytes,
+        funding_account_index: None,
+        new_account: new_account.text,
+        new_account_bytes: new_account.bytes,
+        new_account_index: None,
         lamports,
-        owner,
+        owner: owner.text,
+        owner_bytes: owner.bytes,
+        owner_index: None,
         space,
+        owner_program,
+        rent_exempt_minimum,
+        is_rent_exempt,
     })
 }
 
 fn _parse_assign_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    assign: &system_program::Assign,
+    options: &ParseOptions,
+    owner: Pubkey,
 ) -> Result<AssignEvent, Error> {
-    let assigned_account = instruction.accounts()[0].to_string();
-    let owner = assign.owner.to_string();
+    let assigned_account = encode_pubkey(&instruction.accounts()[0], options);
+    let owner = encode_pubkey(&owner, options);
 
     Ok(AssignEvent {
-        assigned_account,
-        owner,
+        assigned_account: assigned_account.text,
+        assigned_account_bytes: assigned_account.bytes,
+        assigned_account_index: None,
+        owner: owner.text,
+        owner_bytes: owner.bytes,
+        owner_index: None,
     })
 }
 
 fn _parse_transfer_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    transfer: &system_program::Transfer,
+    options: &ParseOptions,
+    lamports: u64,
 ) -> Result<TransferEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let recipient_account = instruction.accounts()[1].to_string();
-    let lamports = transfer.lamports;
+    let funding_account = encode_pubkey(&instruction.accounts()[0], options);
+    let recipient_account = encode_pubkey(&instruction.accounts()[1], options);
+    let is_jito_tip = jito::is_jito_tip_account(&instruction.accounts()[1]);
 
     Ok(TransferEvent {
-        funding_account,
-        recipient_account,
+        funding_account: funding_account.text,
+        funding_account_bytes: funding_account.bytes,
+        funding_account_index: None,
+        recipient_account: recipient_account.text,
+        recipient_account_bytes: recipient_account.bytes,
+        recipient_account_index: None,
         lamports,
+        is_wsol_wrap: false,
+        merged_count: 0,
+        is_jito_tip,
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn _parse_create_account_with_seed_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    create_account_with_seed: &system_program::CreateAccountWithSeed,
+    options: &ParseOptions,
+    base: Pubkey,
+    seed: &[u8],
+    lamports: u64,
+    space: u64,
+    owner: Pubkey,
 ) -> Result<CreateAccountWithSeedEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let created_account = instruction.accounts()[1].to_string();
-    let base_account = create_account_with_seed.base.to_string();
-    let lamports = create_account_with_seed.lamports;
-    let owner = create_account_with_seed.owner.to_string();
-    let seed = create_account_with_seed.seed.0.clone();
-    let space = create_account_with_seed.space;
+    let funding_account = encode_pubkey(&instruction.accounts()[0], options);
+    let created_account = encode_pubkey(&instruction.accounts()[1], options);
+    let base_account = encode_pubkey(&base, options);
+    let owner_program = known_program(&owner).into();
+    let owner = encode_pubkey(&owner, options);
+    let seed = encode_seed(seed);
+    let rent_exempt_minimum = rent::rent_exempt_minimum(space);
+    let is_rent_exempt = rent::is_rent_exempt(lamports, space);
 
     Ok(CreateAccountWithSeedEvent {
-        funding_account,
-        created_account,
-        base_account,
-        seed,
+        funding_account: funding_account.text,
+        funding_account_bytes: funding_account.bytes,
+        funding_account_index: None,
+        created_account: created_account.text,
+        created_account_bytes: created_account.bytes,
+        created_account_index: None,
+        base_account: base_account.text,
+        base_account_bytes: base_account.bytes,
+        base_account_index: None,
+        seed: seed.text,
+        seed_hex: seed.hex,
         lamports,
         space,
-        owner,
+        owner: owner.text,
+        owner_bytes: owner.bytes,
+        owner_index: None,
+        owner_program,
+        rent_exempt_minimum,
+        is_rent_exempt,
     })
 }
 
+pub struct EncodedSeed {
+    pub text: String,
+    pub hex: String,
+}
+
+/// Seeds are arbitrary bytes on-chain, though nearly always ASCII. The text is a lossy UTF-8
+/// conversion; `hex` is only filled when the bytes are not valid UTF-8, so the exact seed is
+/// always recoverable.
+pub fn encode_seed(seed: impl AsRef<[u8]>) -> EncodedSeed {
+    let seed = seed.as_ref();
+    match std::str::from_utf8(seed) {
+        Ok(text) => EncodedSeed { text: text.to_string(), hex: String::new() },
+        Err(_) => EncodedSeed { text: String::from_utf8_lossy(seed).into_owned(), hex: parser::to_hex(seed) },
+    }
+}
+
+/// Classifies `program_id` into one of the programs commonly found as account owners.
+pub fn known_program(program_id: &Pubkey) -> KnownProgram {
+    let known_programs = [
+        (SYSTEM_PROGRAM_ID, KnownProgram::System),
+        (TOKEN_PROGRAM_ID, KnownProgram::Token),
+        (TOKEN_2022_PROGRAM_ID, KnownProgram::Token2022),
+        (ASSOCIATED_TOKEN_PROGRAM_ID, KnownProgram::AssociatedToken),
+        (MPL_TOKEN_METADATA_PROGRAM_ID, KnownProgram::Metadata),
+    ];
+    known_programs.into_iter()
+        .find(|(id, _)| id == program_id)
+        .map_or(KnownProgram::Unknown, |(_, program)| program)
+}
+
 fn _parse_advance_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
+    options: &ParseOptions,
 ) -> Result<AdvanceNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
-    let nonce_authority = instruction.accounts()[2].to_string();
+    let nonce_account = encode_pubkey(&instruction.accounts()[0], options);
+    let nonce_authority = encode_pubkey(&instruction.accounts()[2], options);
 
     Ok(AdvanceNonceAccountEvent {
-        nonce_account,
-        nonce_authority,
+        nonce_account: nonce_account.text,
+        nonce_account_bytes: nonce_account.bytes,
+        nonce_account_index: None,
+        nonce_authority: nonce_authority.text,
+        nonce_authority_bytes: nonce_authority.bytes,
+        nonce_authority_index: None,
     })
 }
 
 fn _parse_withdraw_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
+    options: &ParseOptions,
     lamports: u64,
 ) -> Result<WithdrawNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
-    let recipient_account = instruction.accounts()[1].to_string();
-    let nonce_authority = instruction.accounts()[4].to_string();
+    let nonce_account = encode_pubkey(&instruction.accounts()[0], options);
+    let recipient_account = encode_pubkey(&instruction.accounts()[1], options);
+    let nonce_authority = encode_pubkey(&instruction.accounts()[4], options);
 
     Ok(WithdrawNonceAccountEvent {
-        nonce_account,
-        recipient_account,
-        nonce_authority,
+        nonce_account: nonce_account.text,
+        nonce_account_bytes: nonce_account.bytes,
+        nonce_account_index: None,
+        recipient_account: recipient_account.text,
+        recipient_account_bytes: recipient_account.bytes,
+        recipient_account_index: None,
+        nonce_authority: nonce_authority.text,
+        nonce_authority_bytes: nonce_authority.bytes,
+        nonce_authority_index: None,
         lamports,
     })
 }
@@ -217,43 +645,84 @@ fn _parse_withdraw_nonce_account_instruction(
 fn _parse_initialize_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
+    options: &ParseOptions,
     authority: Pubkey,
 ) -> Result<InitializeNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
-    let nonce_authority = authority.to_string();
+    let accounts = instruction.accounts();
+    let nonce_account = encode_pubkey(&accounts[0], options);
+    let nonce_authority = encode_pubkey(&authority, options);
+    let canonical_layout = accounts.get(1) == Some(&RECENT_BLOCKHASHES_SYSVAR_ID)
+        && accounts.get(2) == Some(&RENT_SYSVAR_ID);
 
     Ok(InitializeNonceAccountEvent {
-        nonce_account,
-        nonce_authority,
+        nonce_account: nonce_account.text,
+        nonce_account_bytes: nonce_account.bytes,
+        nonce_account_index: None,
+        nonce_authority: nonce_authority.text,
+        nonce_authority_bytes: nonce_authority.bytes,
+        nonce_authority_index: None,
+        canonical_layout,
+        rent_payer: String::new(),
+        rent_payer_bytes: Vec::new(),
+        rent_payer_index: None,
     })
 }
 
+const AUTHORIZE_NONCE_ACCOUNT_DISCRIMINANT: u32 = 7;
+
+/// 4-byte discriminant followed by the new authority pubkey.
+const AUTHORIZE_NONCE_ACCOUNT_DATA_LEN: usize = 4 + 32;
+
+/// Rejects AuthorizeNonceAccount data too short to hold the new authority. Runs before
+/// unpacking, which would fail on such data without saying why. Trailing bytes are tolerated,
+/// as the runtime's bincode decoding does.
+fn check_authorize_nonce_account_data(data: &[u8]) -> Result<(), Error> {
+    if decode::discriminant(data) == Some(AUTHORIZE_NONCE_ACCOUNT_DISCRIMINANT) && data.len() < AUTHORIZE_NONCE_ACCOUNT_DATA_LEN {
+        return Err(anyhow!(
+            "AuthorizeNonceAccount data is {} bytes, expected {}.", data.len(), AUTHORIZE_NONCE_ACCOUNT_DATA_LEN,
+        ));
+    }
+    Ok(())
+}
+
+/// Accounts are the nonce account and its current authority, the signer. Unlike
+/// AdvanceNonceAccount there is no RecentBlockhashes sysvar in between, so the authority is at
+/// index 1 rather than 2.
 fn _parse_authorize_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
+    options: &ParseOptions,
     pubkey: Pubkey,
 ) -> Result<AuthorizeNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
-    let nonce_authority = instruction.accounts()[1].to_string();
-    let new_nonce_authority = pubkey.to_string();
+    let nonce_account = encode_pubkey(&instruction.accounts()[0], options);
+    let nonce_authority = encode_pubkey(&instruction.accounts()[1], options);
+    let new_nonce_authority = encode_pubkey(&pubkey, options);
 
     Ok(AuthorizeNonceAccountEvent {
-        nonce_account,
-        nonce_authority,
-        new_nonce_authority,
+        nonce_account: nonce_account.text,
+        nonce_account_bytes: nonce_account.bytes,
+        nonce_account_index: None,
+        nonce_authority: nonce_authority.text,
+        nonce_authority_bytes: nonce_authority.bytes,
+        nonce_authority_index: None,
+        new_nonce_authority: new_nonce_authority.text,
+        new_nonce_authority_bytes: new_nonce_authority.bytes,
+        new_nonce_authority_index: None,
     })
 }
 
 fn _parse_allocate_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    allocate: &system_program::Allocate,
+    options: &ParseOptions,
+    space: u64,
 ) -> Result<AllocateEvent, Error> {
-    let account = instruction.accounts()[0].to_string();
-    let space = allocate.space;
+    let account = encode_pubkey(&instruction.accounts()[0], options);
 
     Ok(AllocateEvent {
-        account,
+        account: account.text,
+        account_bytes: account.bytes,
+        account_index: None,
         space,
     })
 }
@@ -261,19 +730,29 @@ fn _parse_allocate_instruction(
 fn _parse_allocate_with_seed_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    allocate_with_seed: &system_program::AllocateWithSeed,
+    options: &ParseOptions,
+    base: Pubkey,
+    seed: &[u8],
+    space: u64,
+    owner: Pubkey,
 ) -> Result<AllocateWithSeedEvent, Error> {
-    let allocated_account = instruction.accounts()[0].to_string();
-    let space = allocate_with_seed.space;
-    let base_account = allocate_with_seed.base.to_string();
-    let owner = allocate_with_seed.owner.to_string();
-    let seed = allocate_with_seed.seed.0.clone();
+    let allocated_account = encode_pubkey(&instruction.accounts()[0], options);
+    let base_account = encode_pubkey(&base, options);
+    let owner = encode_pubkey(&owner, options);
+    let seed = encode_seed(seed);
 
     Ok(AllocateWithSeedEvent {
-        allocated_account,
-        base_account,
-        seed,
-        owner,
+        allocated_account: allocated_account.text,
+        allocated_account_bytes: allocated_account.bytes,
+        allocated_account_index: None,
+        base_account: base_account.text,
+        base_account_bytes: base_account.bytes,
+        base_account_index: None,
+        seed: seed.text,
+        seed_hex: seed.hex,
+        owner: owner.text,
+        owner_bytes: owner.bytes,
+        owner_index: None,
         space,
     })
 }
@@ -281,39 +760,60 @@ fn _parse_allocate_with_seed_instruction(
 fn _parse_assign_with_seed_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    assign_with_seed: &system_program::AssignWithSeed,
+    options: &ParseOptions,
+    base: Pubkey,
+    seed: &[u8],
+    owner: Pubkey,
 ) -> Result<AssignWithSeedEvent, Error> {
-    let assigned_account = instruction.accounts()[0].to_string();
-    let base_account = assign_with_seed.base.to_string();
-    let owner = assign_with_seed.owner.to_string();
-    let seed = assign_with_seed.seed.0.clone();
+    let assigned_account = encode_pubkey(&instruction.accounts()[0], options);
+    let base_account = encode_pubkey(&base, options);
+    let owner = encode_pubkey(&owner, options);
+    let seed = encode_seed(seed);
 
     Ok(AssignWithSeedEvent {
-        assigned_account,
-        base_account,
-        owner,
-        seed,
+        assigned_account: assigned_account.text,
+        assigned_account_bytes: assigned_account.bytes,
+        assigned_account_index: None,
+        base_account: base_account.text,
+        base_account_bytes: base_account.bytes,
+        base_account_index: None,
+        owner: owner.text,
+        owner_bytes: owner.bytes,
+        owner_index: None,
+        seed: seed.text,
+        seed_hex: seed.hex,
     })
 }
 
 fn _parse_transfer_with_seed_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-    transfer_with_seed: system_program::TransferWithSeed
+    options: &ParseOptions,
+    lamports: u64,
+    from_seed: &[u8],
+    from_owner: Pubkey,
 ) -> Result<TransferWithSeedEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let base_account = instruction.accounts()[1].to_string();
-    let recipient_account = instruction.accounts()[2].to_string();
-    let from_owner = transfer_with_seed.from_owner.to_string();
-    let from_seed = transfer_with_seed.from_seed.0.clone();
-    let lamports = transfer_with_seed.lamports;
+    let funding_account = encode_pubkey(&instruction.accounts()[0], options);
+    let base_account = encode_pubkey(&instruction.accounts()[1], options);
+    let recipient_account = encode_pubkey(&instruction.accounts()[2], options);
+    let from_owner = encode_pubkey(&from_owner, options);
+    let from_seed = encode_seed(from_seed);
 
     Ok(TransferWithSeedEvent {
-        funding_account,
-        base_account,
-        recipient_account,
-        from_owner,
-        from_seed,
+        funding_account: funding_account.text,
+        funding_account_bytes: funding_account.bytes,
+        funding_account_index: None,
+        base_account: base_account.text,
+        base_account_bytes: base_account.bytes,
+        base_account_index: None,
+        recipient_account: recipient_account.text,
+        recipient_account_bytes: recipient_account.bytes,
+        recipient_account_index: None,
+        from_owner: from_owner.text,
+        from_owner_bytes: from_owner.bytes,
+        from_owner_index: None,
+        from_seed: from_seed.text,
+        from_seed_hex: from_seed.hex,
         lamports,
     })
 }
@@ -321,11 +821,14 @@ fn _parse_transfer_with_seed_instruction(
 fn _parse_upgrade_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
+    options: &ParseOptions,
 ) -> Result<UpgradeNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
+    let nonce_account = encode_pubkey(&instruction.accounts()[0], options);
 
     Ok(UpgradeNonceAccountEvent {
-        nonce_account,
+        nonce_account: nonce_account.text,
+        nonce_account_bytes: nonce_account.bytes,
+        nonce_account_index: None,
     })
 }
 
@@ -0,0 +1,84 @@
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction, Message};
+
+use substreams_solana_utils as utils;
+use utils::transaction::TransactionContext;
+use utils::instruction::StructuredInstruction;
+use utils::pubkey::Pubkey;
+
+use crate::pb::system_program::*;
+use crate::constants::{MEMO_V1_PROGRAM_ID, MEMO_PROGRAM_ID};
+use crate::parser::{InstructionParser, ParseError, parse_transaction_multi, skip_missing_meta};
+
+pub fn is_memo_program(program_id: &Pubkey) -> bool {
+    *program_id == MEMO_PROGRAM_ID || *program_id == MEMO_V1_PROGRAM_ID
+}
+
+/// Decodes SPL Memo instructions of both the v1 and v2 programs.
+pub struct MemoParser {
+    pub program_id: Pubkey,
+}
+
+impl InstructionParser<MemoEvent> for MemoParser {
+    fn program_id(&self) -> Pubkey {
+        self.program_id.clone()
+    }
+
+    fn parse(
+        &self,
+        instruction: &StructuredInstruction,
+        _context: &TransactionContext,
+    ) -> Result<Option<MemoEvent>, ParseError> {
+        Ok(Some(parse_memo_instruction(instruction)))
+    }
+}
+
+pub fn parse_block(block: &Block) -> Result<MemoBlockEvents, Error> {
+    let mut transactions: Vec<MemoTransactionEvents> = Vec::new();
+    for (i, transaction) in block.transactions.iter().enumerate() {
+        if skip_missing_meta(transaction) {
+            continue;
+        }
+        let events = parse_transaction(transaction)?;
+        if !events.is_empty() {
+            transactions.push(MemoTransactionEvents {
+                signature: utils::transaction::get_signature(transaction),
+                transaction_index: i as u32,
+                events,
+            });
+        }
+    }
+    Ok(MemoBlockEvents { slot: block.slot, transactions })
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<MemoEvent>, Error> {
+    let v1 = MemoParser { program_id: MEMO_V1_PROGRAM_ID };
+    let v2 = MemoParser { program_id: MEMO_PROGRAM_ID };
+    let events = parse_transaction_multi(transaction, &[&v1, &v2])?;
+    Ok(events.into_iter().map(|(instruction_index, event)| MemoEvent { instruction_index, ..event }).collect())
+}
+
+/// The memo is the whole instruction data; every account passed is a required signer.
+pub fn parse_memo_instruction(instruction: &StructuredInstruction) -> MemoEvent {
+    let (memo, memo_raw) = match String::from_utf8(instruction.data().to_vec()) {
+        Ok(memo) => (memo, Vec::new()),
+        Err(e) => (String::new(), e.into_bytes()),
+    };
+    MemoEvent {
+        instruction_index: 0,
+        memo,
+        memo_raw,
+        signers: instruction.accounts().iter().map(|account| account.to_string()).collect(),
+    }
+}
+
+/// First top-level memo of the message, read from the raw instructions. Non-UTF-8 memos are skipped.
+pub fn top_level_memo(message: &Message) -> Option<String> {
+    message.instructions.iter()
+        .filter(|instruction| {
+            message.account_keys.get(instruction.program_id_index as usize)
+                .and_then(|key| <[u8; 32]>::try_from(key.as_slice()).ok())
+                .map_or(false, |key| is_memo_program(&Pubkey(key)))
+        })
+        .find_map(|instruction| String::from_utf8(instruction.data.clone()).ok())
+}
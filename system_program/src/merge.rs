@@ -0,0 +1,71 @@
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+/// Merges runs of adjacent transfers with the same funding and recipient accounts into the first
+/// transfer of the run, summing lamports and counting the merged transfers in `merged_count`.
+/// Any other event between two transfers ends the run, so the order of events is preserved. A
+/// transfer whose lamports would overflow the sum starts a new run instead.
+pub fn merge_consecutive_transfers(events: Vec<SystemProgramEvent>) -> Vec<SystemProgramEvent> {
+    let mut merged: Vec<SystemProgramEvent> = Vec::with_capacity(events.len());
+    for mut event in events {
+        let Some(Event::Transfer(transfer)) = event.event.as_mut() else {
+            merged.push(event);
+            continue;
+        };
+        if let Some(Event::Transfer(previous)) = merged.last_mut().and_then(|last| last.event.as_mut()) {
+            if same_accounts(previous, transfer) {
+                if let Some(lamports) = previous.lamports.checked_add(transfer.lamports) {
+                    previous.lamports = lamports;
+                    previous.merged_count += 1;
+                    continue;
+                }
+            }
+        }
+        transfer.merged_count = 1;
+        merged.push(event);
+    }
+    merged
+}
+
+fn same_accounts(a: &TransferEvent, b: &TransferEvent) -> bool {
+    a.funding_account == b.funding_account
+        && a.funding_account_bytes == b.funding_account_bytes
+        && a.recipient_account == b.recipient_account
+        && a.recipient_account_bytes == b.recipient_account_bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer(lamports: u64) -> SystemProgramEvent {
+        SystemProgramEvent {
+            event: Some(Event::Transfer(TransferEvent {
+                funding_account: "a".to_string(),
+                recipient_account: "b".to_string(),
+                lamports,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    fn lamports_and_counts(events: &[SystemProgramEvent]) -> Vec<(u64, u32)> {
+        events.iter().map(|event| match event.event.as_ref() {
+            Some(Event::Transfer(transfer)) => (transfer.lamports, transfer.merged_count),
+            _ => panic!("expected a transfer"),
+        }).collect()
+    }
+
+    #[test]
+    fn sums_adjacent_transfers() {
+        let merged = merge_consecutive_transfers(vec![transfer(1), transfer(2), transfer(3)]);
+        assert_eq!(lamports_and_counts(&merged), vec![(6, 3)]);
+    }
+
+    #[test]
+    fn overflowing_transfer_starts_a_new_run() {
+        let merged = merge_consecutive_transfers(vec![transfer(u64::MAX - 1), transfer(2), transfer(3)]);
+        assert_eq!(lamports_and_counts(&merged), vec![(u64::MAX - 1, 1), (5, 2)]);
+    }
+}
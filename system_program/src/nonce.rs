@@ -0,0 +1,28 @@
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+/// Sets `rent_payer` on InitializeNonceAccount events whose nonce account was created by an
+/// earlier event of the same transaction. InitializeNonceAccount has no payer account of its
+/// own: the nonce account is funded when it is created.
+pub fn annotate_nonce_rent_payers(events: &mut [SystemProgramEvent]) {
+    for i in 0..events.len() {
+        let (created, rest) = events.split_at_mut(i);
+        let Some(Event::InitializeNonceAccount(initialize)) = rest[0].event.as_mut() else {
+            continue;
+        };
+        let nonce_account = (&initialize.nonce_account, &initialize.nonce_account_bytes);
+        let funding_account = created.iter().rev().find_map(|event| match event.event.as_ref() {
+            Some(Event::CreateAccount(e)) if (&e.new_account, &e.new_account_bytes) == nonce_account => {
+                Some((&e.funding_account, &e.funding_account_bytes))
+            },
+            Some(Event::CreateAccountWithSeed(e)) if (&e.created_account, &e.created_account_bytes) == nonce_account => {
+                Some((&e.funding_account, &e.funding_account_bytes))
+            },
+            _ => None,
+        });
+        if let Some((text, bytes)) = funding_account {
+            initialize.rent_payer = text.clone();
+            initialize.rent_payer_bytes = bytes.clone();
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::anyhow;
+use base64::Engine;
+use substreams::errors::Error;
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// How account fields are rendered in the output events.
+///
+/// `Base58` fills the `string` fields and is the default. `Bytes` fills the parallel `*_bytes`
+/// fields instead, which is about 30% smaller on the wire and can be written as-is by
+/// byte-oriented sinks (ClickHouse `FixedString(32)`, Postgres `bytea`, parquet `BINARY`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+    #[default]
+    Base58,
+    Bytes,
+}
+
+/// How pubkeys are written into the `string` account fields when `encoding` is `Base58`.
+///
+/// `Base64` always yields 44 characters, is cheaper to produce than base58's big-number
+/// conversion, and is decoded natively by most downstream systems.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PubkeyEncoding {
+    #[default]
+    Base58,
+    Base64,
+}
+
+#[derive(Clone, Debug)]
+pub struct ParseOptions {
+    pub encoding: Encoding,
+    pub pubkey_encoding: PubkeyEncoding,
+    /// Merge consecutive transfers between the same accounts into a single event.
+    pub merge_transfers: bool,
+    /// Transactions with more instructions than this, inner instructions included, are skipped.
+    pub max_instructions_per_transaction: usize,
+    /// Copy the program logs of transactions with events into `log_messages`.
+    pub include_log_messages: bool,
+    /// Fill the accounts of each event with their signer and writable flags.
+    pub include_account_metas: bool,
+    /// Store each pubkey once in the block's `pubkey_table` and reference it by index.
+    pub intern_pubkeys: bool,
+    /// Transfer and TransferWithSeed events moving fewer lamports than this are dropped.
+    pub min_transfer_lamports: u64,
+    /// Apply `min_transfer_lamports` to the funding lamports of CreateAccount and
+    /// CreateAccountWithSeed events too.
+    pub filter_create_account_dust: bool,
+    /// Drop events identical to an earlier event of the same transaction.
+    pub dedup_identical_events: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            encoding: Encoding::default(),
+            pubkey_encoding: PubkeyEncoding::default(),
+            merge_transfers: false,
+            max_instructions_per_transaction: 4096,
+            include_log_messages: false,
+            include_account_metas: false,
+            intern_pubkeys: false,
+            min_transfer_lamports: 0,
+            filter_create_account_dust: false,
+            dedup_identical_events: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Parses module params of the form `key=value&key=value`. Empty params yield the defaults.
+    pub fn from_params(params: &str) -> Result<Self, Error> {
+        let mut options = ParseOptions::default();
+        for param in params.split('&').map(str::trim).filter(|param| !param.is_empty()) {
+            let (key, value) = param.split_once('=').ok_or_else(|| anyhow!("Invalid param {}.", param))?;
+            match key.trim() {
+                "encoding" => {
+                    options.encoding = match value.trim() {
+                        "base58" => Encoding::Base58,
+                        "bytes" => Encoding::Bytes,
+                        _ => return Err(anyhow!("Unknown encoding {}.", value)),
+                    }
+                },
+                "pubkey_encoding" => {
+                    options.pubkey_encoding = match value.trim() {
+                        "base58" => PubkeyEncoding::Base58,
+                        "base64" => PubkeyEncoding::Base64,
+                        _ => return Err(anyhow!("Unknown pubkey_encoding {}.", value)),
+                    }
+                },
+                "merge_transfers" => {
+                    options.merge_transfers = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid merge_transfers {}.", value))?;
+                },
+                "max_instructions_per_transaction" => {
+                    options.max_instructions_per_transaction = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid max_instructions_per_transaction {}.", value))?;
+                },
+                "include_log_messages" => {
+                    options.include_log_messages = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid include_log_messages {}.", value))?;
+                },
+                "include_account_metas" => {
+                    options.include_account_metas = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid include_account_metas {}.", value))?;
+                },
+                "intern_pubkeys" => {
+                    options.intern_pubkeys = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid intern_pubkeys {}.", value))?;
+                },
+                "min_transfer_lamports" => {
+                    options.min_transfer_lamports = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid min_transfer_lamports {}.", value))?;
+                },
+                "filter_create_account_dust" => {
+                    options.filter_create_account_dust = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid filter_create_account_dust {}.", value))?;
+                },
+                "dedup_identical_events" => {
+                    options.dedup_identical_events = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid dedup_identical_events {}.", value))?;
+                },
+                _ => return Err(anyhow!("Unknown param {}.", key)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+pub struct EncodedPubkey {
+    pub text: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Renders `pubkey` according to `options.encoding`, leaving the other representation empty.
+/// The string representation follows `options.pubkey_encoding`.
+pub fn encode_pubkey(pubkey: &Pubkey, options: &ParseOptions) -> EncodedPubkey {
+    match options.encoding {
+        Encoding::Base58 => EncodedPubkey { text: format_pubkey(&pubkey.0, options.pubkey_encoding), bytes: Vec::new() },
+        Encoding::Bytes => EncodedPubkey { text: String::new(), bytes: pubkey.0.to_vec() },
+    }
+}
+
+/// Formats raw pubkey bytes as a string in the given encoding.
+pub fn format_pubkey(bytes: &[u8], encoding: PubkeyEncoding) -> String {
+    match encoding {
+        PubkeyEncoding::Base58 => bs58::encode(bytes).into_string(),
+        PubkeyEncoding::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+    }
+}
+
+/// Inverse of `format_pubkey`. Returns an empty vector when `text` is not valid in `encoding`.
+pub fn decode_pubkey(text: &str, encoding: PubkeyEncoding) -> Vec<u8> {
+    match encoding {
+        PubkeyEncoding::Base58 => bs58::decode(text).into_vec().unwrap_or_default(),
+        PubkeyEncoding::Base64 => base64::engine::general_purpose::STANDARD.decode(text).unwrap_or_default(),
+    }
+}
@@ -0,0 +1,342 @@
+use anyhow::anyhow;
+use base64::Engine;
+use prost::Message;
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use substreams_solana_utils as utils;
+use utils::transaction::{get_context, TransactionContext};
+use utils::instruction::{get_structured_instructions, StructuredInstruction};
+use utils::pubkey::Pubkey;
+
+use crate::constants::VOTE_PROGRAM_ID;
+
+/// Errors of the parsers and of the transaction-level entry points.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The transaction has no meta, as happens with some block sources. Its status and inner
+    /// instructions are unknown, so it can't be parsed.
+    MissingMeta,
+    /// An instruction of the parser's program couldn't be decoded, with the reason.
+    InvalidInstruction(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::MissingMeta => write!(f, "Transaction has no meta."),
+            ParseError::InvalidInstruction(reason) => write!(f, "Invalid instruction: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<Error> for ParseError {
+    fn from(error: Error) -> Self {
+        ParseError::InvalidInstruction(format!("{:#}", error))
+    }
+}
+
+/// A decoder for the instructions of a single program.
+///
+/// Parsers producing a common event type `T` can be passed together to
+/// `parse_transaction_multi`, so a transaction is walked only once regardless
+/// of how many programs are being decoded.
+pub trait InstructionParser<T> {
+    fn program_id(&self) -> Pubkey;
+
+    fn parse(
+        &self,
+        instruction: &StructuredInstruction,
+        context: &TransactionContext,
+    ) -> Result<Option<T>, ParseError>;
+}
+
+/// An owned, growable set of parsers producing `T`, for pipelines assembled at runtime, e.g.
+/// proprietary decoders registered next to the built-in ones.
+pub struct ParserRegistry<T> {
+    parsers: Vec<Box<dyn InstructionParser<T>>>,
+}
+
+impl<T> Default for ParserRegistry<T> {
+    fn default() -> Self {
+        ParserRegistry { parsers: Vec::new() }
+    }
+}
+
+impl<T> ParserRegistry<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `parser`, replacing any parser already registered for its program id.
+    pub fn register(&mut self, parser: impl InstructionParser<T> + 'static) -> &mut Self {
+        let program_id = parser.program_id();
+        self.parsers.retain(|registered| registered.program_id() != program_id);
+        self.parsers.push(Box::new(parser));
+        self
+    }
+
+    pub fn is_registered(&self, program_id: &Pubkey) -> bool {
+        self.parsers.iter().any(|parser| parser.program_id() == *program_id)
+    }
+
+    pub fn parsers(&self) -> Vec<&dyn InstructionParser<T>> {
+        self.parsers.iter().map(|parser| parser.as_ref()).collect()
+    }
+
+    /// `parse_transaction_multi` with the registered parsers.
+    pub fn parse_transaction(&self, transaction: &ConfirmedTransaction) -> Result<Vec<(u32, T)>, Error> {
+        parse_transaction_multi(transaction, &self.parsers())
+    }
+
+    /// `parse_transaction_multi_isolated` with the registered parsers.
+    pub fn parse_transaction_isolated(&self, transaction: &ConfirmedTransaction) -> Result<(Vec<(u32, T)>, Vec<u32>), Error> {
+        parse_transaction_multi_isolated(transaction, &self.parsers())
+    }
+}
+
+/// Decodes every instruction of `transaction` with the parser matching its program id.
+///
+/// Returns the index of each instruction within `instructions.flattened()` alongside its event.
+/// The instruction tree is walked depth first, so indexes follow execution order: a
+/// top-level instruction, then the instructions it invoked in invocation order (recursively),
+/// then the next top-level instruction. Failed transactions yield no events, and transactions
+/// without meta fail with `ParseError::MissingMeta`.
+pub fn parse_transaction_multi<T>(
+    transaction: &ConfirmedTransaction,
+    parsers: &[&dyn InstructionParser<T>],
+) -> Result<Vec<(u32, T)>, Error> {
+    if transaction.meta.as_ref().ok_or(ParseError::MissingMeta)?.err.is_some() {
+        return Ok(Vec::new())
+    }
+    if skip_unresolvable(transaction) {
+        return Ok(Vec::new())
+    }
+
+    let mut events: Vec<(u32, T)> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened_iter().enumerate() {
+        let program_id = instruction.program_id();
+        let Some(parser) = parsers.iter().find(|parser| parser.program_id() == program_id) else {
+            continue;
+        };
+        match parser.parse(instruction, &context) {
+            Ok(Some(event)) => events.push((i as u32, event)),
+            Ok(None) => (),
+            Err(e) => return Err(anyhow!(
+                "Failed to parse transaction {} with error: {} (program {}, data {})",
+                context.signature, e, program_id, to_hex(&instruction.data()),
+            ))
+        }
+    }
+
+    Ok(events)
+}
+
+/// Like `parse_transaction_multi`, but an instruction that fails to decode doesn't fail the
+/// transaction: the failure is logged and its index returned alongside the decoded events.
+pub fn parse_transaction_multi_isolated<T>(
+    transaction: &ConfirmedTransaction,
+    parsers: &[&dyn InstructionParser<T>],
+) -> Result<(Vec<(u32, T)>, Vec<u32>), Error> {
+    if transaction.meta.as_ref().ok_or(ParseError::MissingMeta)?.err.is_some() {
+        return Ok((Vec::new(), Vec::new()))
+    }
+    if skip_unresolvable(transaction) {
+        return Ok((Vec::new(), Vec::new()))
+    }
+
+    let mut events: Vec<(u32, T)> = Vec::new();
+    let mut failed_instruction_indices: Vec<u32> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened_iter().enumerate() {
+        let program_id = instruction.program_id();
+        let Some(parser) = parsers.iter().find(|parser| parser.program_id() == program_id) else {
+            continue;
+        };
+        match parser.parse(instruction, &context) {
+            Ok(Some(event)) => events.push((i as u32, event)),
+            Ok(None) => (),
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Failed to parse instruction {} of transaction {} with error: {} (program {}, data {})",
+                    i, context.signature, e, program_id, to_hex(&instruction.data()),
+                ));
+                failed_instruction_indices.push(i as u32);
+            }
+        }
+    }
+
+    Ok((events, failed_instruction_indices))
+}
+
+/// Lazy depth-first walk over an instruction tree, in the same order as `flattened()`.
+///
+/// Only a stack of per-level iterators is kept, bounded by the CPI depth, instead of a `Vec`
+/// holding every instruction of the transaction.
+pub struct FlattenedIter<'i, 'a> {
+    stack: Vec<std::slice::Iter<'i, StructuredInstruction<'a>>>,
+}
+
+impl<'i, 'a> Iterator for FlattenedIter<'i, 'a> {
+    type Item = &'i StructuredInstruction<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let level = self.stack.last_mut()?;
+            match level.next() {
+                Some(instruction) => {
+                    self.stack.push(instruction.inner_instructions().iter());
+                    return Some(instruction);
+                },
+                None => {
+                    self.stack.pop();
+                },
+            }
+        }
+    }
+}
+
+pub trait FlattenedInstructions<'a> {
+    fn flattened_iter(&self) -> FlattenedIter<'_, 'a>;
+}
+
+impl<'a> FlattenedInstructions<'a> for [StructuredInstruction<'a>] {
+    fn flattened_iter(&self) -> FlattenedIter<'_, 'a> {
+        FlattenedIter { stack: vec![self.iter()] }
+    }
+}
+
+/// Number of instructions in `transaction`, inner instructions included, read from the raw
+/// message and meta without building the structured instructions.
+pub fn instruction_count(transaction: &ConfirmedTransaction) -> usize {
+    let top_level = transaction.transaction.as_ref()
+        .and_then(|transaction| transaction.message.as_ref())
+        .map_or(0, |message| message.instructions.len());
+    let inner = transaction.meta.as_ref()
+        .map_or(0, |meta| meta.inner_instructions.iter().map(|inner| inner.instructions.len()).sum());
+    top_level + inner
+}
+
+/// Whether every top-level instruction of `transaction` invokes the Vote program. The Vote
+/// program makes no CPIs, so such transactions can't contain System Program instructions and
+/// are skipped before building their structured instructions. Program ids are always static
+/// keys, so only the message is read.
+pub fn is_vote_transaction(transaction: &ConfirmedTransaction) -> bool {
+    let Some(message) = transaction.transaction.as_ref().and_then(|transaction| transaction.message.as_ref()) else {
+        return false;
+    };
+    !message.instructions.is_empty() && message.instructions.iter().all(|instruction| {
+        message.account_keys.get(instruction.program_id_index as usize)
+            .map_or(false, |program_id| program_id.as_slice() == VOTE_PROGRAM_ID.0.as_slice())
+    })
+}
+
+/// Account key at `index` in the resolved account list of `transaction`: the static keys of the
+/// message, then the writable and readonly addresses loaded from lookup tables. `None` when the
+/// index is out of range.
+pub fn get_account_from_index(transaction: &ConfirmedTransaction, index: usize) -> Option<&[u8]> {
+    let message = transaction.transaction.as_ref()?.message.as_ref()?;
+    let meta = transaction.meta.as_ref();
+    let loaded_writable = meta.map_or(&[][..], |meta| meta.loaded_writable_addresses.as_slice());
+    let loaded_readonly = meta.map_or(&[][..], |meta| meta.loaded_readonly_addresses.as_slice());
+    message.account_keys.iter()
+        .chain(loaded_writable)
+        .chain(loaded_readonly)
+        .nth(index)
+        .map(Vec::as_slice)
+}
+
+/// Program id of the instruction that invoked each instruction of `transaction`, in the order of
+/// `instructions.flattened()`, `None` for top-level instructions. Inner instructions are placed
+/// in the call tree by their stack height; blocks predating stack heights attribute them to
+/// their top-level instruction.
+pub fn invoking_program_ids(transaction: &ConfirmedTransaction) -> Vec<Option<&[u8]>> {
+    let Some(message) = transaction.transaction.as_ref().and_then(|transaction| transaction.message.as_ref()) else {
+        return Vec::new();
+    };
+    let inner_instructions = transaction.meta.as_ref().map_or(&[][..], |meta| meta.inner_instructions.as_slice());
+
+    let mut invoking_programs: Vec<Option<&[u8]>> = Vec::new();
+    for (i, instruction) in message.instructions.iter().enumerate() {
+        invoking_programs.push(None);
+        // Program ids of the current call stack, the top-level instruction at height 1.
+        let mut stack = vec![get_account_from_index(transaction, instruction.program_id_index as usize)];
+        let inner = inner_instructions.iter()
+            .filter(|inner| inner.index as usize == i)
+            .flat_map(|inner| inner.instructions.iter());
+        for instruction in inner {
+            let height = instruction.stack_height.map_or(2, |height| (height as usize).max(2));
+            stack.truncate(height - 1);
+            invoking_programs.push(stack.last().copied().flatten());
+            stack.push(get_account_from_index(transaction, instruction.program_id_index as usize));
+        }
+    }
+    invoking_programs
+}
+
+/// Program id indexes of the instructions of `transaction`, inner instructions included, that
+/// don't resolve to an account key. Structured instructions can't be built for such a
+/// transaction, so callers skip it.
+pub fn unresolvable_program_id_indexes(transaction: &ConfirmedTransaction) -> Vec<u32> {
+    let top_level = transaction.transaction.as_ref()
+        .and_then(|transaction| transaction.message.as_ref())
+        .map(|message| message.instructions.iter().map(|instruction| instruction.program_id_index).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let inner = transaction.meta.as_ref()
+        .map(|meta| meta.inner_instructions.iter()
+            .flat_map(|inner| inner.instructions.iter().map(|instruction| instruction.program_id_index))
+            .collect::<Vec<_>>())
+        .unwrap_or_default();
+    top_level.into_iter()
+        .chain(inner)
+        .filter(|index| get_account_from_index(transaction, *index as usize).is_none())
+        .collect()
+}
+
+/// Logs and returns `true` when `transaction` has instructions with an unresolvable program id.
+pub(crate) fn skip_unresolvable(transaction: &ConfirmedTransaction) -> bool {
+    let indexes = unresolvable_program_id_indexes(transaction);
+    if indexes.is_empty() {
+        return false;
+    }
+    substreams::log::println(format!(
+        "Skipping transaction {} with unresolvable program id indexes {:?}",
+        utils::transaction::get_signature(transaction),
+        indexes,
+    ));
+    true
+}
+
+/// Logs and returns `true` when `transaction` has no meta. The block parsers skip such
+/// transactions rather than failing the whole block on `ParseError::MissingMeta`.
+pub(crate) fn skip_missing_meta(transaction: &ConfirmedTransaction) -> bool {
+    if transaction.meta.is_some() {
+        return false;
+    }
+    substreams::log::println(format!(
+        "Skipping transaction {} without meta",
+        utils::transaction::get_signature(transaction),
+    ));
+    true
+}
+
+/// Decodes a base64 protobuf-encoded `ConfirmedTransaction`, the format of transaction
+/// fixtures. See the Fixtures section of the README for how to capture one.
+pub fn decode_transaction_base64(encoded: &str) -> Result<ConfirmedTransaction, Error> {
+    let bytes = base64::engine::general_purpose::STANDARD.decode(encoded.trim())
+        .map_err(|e| anyhow!("Invalid base64 transaction: {}", e))?;
+    ConfirmedTransaction::decode(bytes.as_slice()).map_err(|e| anyhow!("Invalid transaction: {}", e))
+}
+
+pub fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -6,6 +6,62 @@ pub struct SystemProgramBlockEvents {
     pub slot: u64,
     #[prost(message, repeated, tag="2")]
     pub transactions: ::prost::alloc::vec::Vec<SystemProgramTransactionEvents>,
+    /// Transactions skipped for exceeding max_instructions_per_transaction.
+    #[prost(uint32, tag="3")]
+    pub truncated_transactions: u32,
+    #[prost(uint32, tag="4")]
+    pub transactions_scanned: u32,
+    #[prost(uint32, tag="5")]
+    pub transactions_with_events: u32,
+    #[prost(message, optional, tag="6")]
+    pub event_counts: ::core::option::Option<EventCounts>,
+    /// Transactions only invoking the Vote program, skipped without being parsed.
+    #[prost(uint32, tag="7")]
+    pub skipped_vote_transactions: u32,
+    /// Distinct pubkeys of the block, only filled with intern_pubkeys=true. The pubkey fields of
+    /// the transactions are then empty, and the *_index fields next to them hold the index of their
+    /// pubkey in this table.
+    #[prost(string, repeated, tag="8")]
+    pub pubkey_table: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Slot of the parent block and base58 hash of this block, so that consumers can check the
+    /// continuity of the stream.
+    #[prost(uint64, tag="9")]
+    pub parent_slot: u64,
+    #[prost(string, tag="10")]
+    pub blockhash: ::prost::alloc::string::String,
+}
+/// Number of emitted events of each type in the block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventCounts {
+    #[prost(uint32, tag="1")]
+    pub create_account: u32,
+    #[prost(uint32, tag="2")]
+    pub assign: u32,
+    #[prost(uint32, tag="3")]
+    pub transfer: u32,
+    #[prost(uint32, tag="4")]
+    pub create_account_with_seed: u32,
+    #[prost(uint32, tag="5")]
+    pub advance_nonce_account: u32,
+    #[prost(uint32, tag="6")]
+    pub withdraw_nonce_account: u32,
+    #[prost(uint32, tag="7")]
+    pub initialize_nonce_account: u32,
+    #[prost(uint32, tag="8")]
+    pub authorize_nonce_account: u32,
+    #[prost(uint32, tag="9")]
+    pub allocate: u32,
+    #[prost(uint32, tag="10")]
+    pub allocate_with_seed: u32,
+    #[prost(uint32, tag="11")]
+    pub assign_with_seed: u32,
+    #[prost(uint32, tag="12")]
+    pub transfer_with_seed: u32,
+    #[prost(uint32, tag="13")]
+    pub upgrade_nonce_account: u32,
+    #[prost(uint32, tag="14")]
+    pub unknown: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -16,14 +72,267 @@ pub struct SystemProgramTransactionEvents {
     pub transaction_index: u32,
     #[prost(message, repeated, tag="3")]
     pub events: ::prost::alloc::vec::Vec<SystemProgramEvent>,
+    #[prost(uint64, optional, tag="4")]
+    pub compute_units_consumed: ::core::option::Option<u64>,
+    #[prost(bool, tag="5")]
+    pub has_return_data: bool,
+    #[prost(string, tag="6")]
+    pub recent_blockhash: ::prost::alloc::string::String,
+    /// -1 for legacy transactions.
+    #[prost(int32, tag="7")]
+    pub version: i32,
+    #[prost(string, repeated, tag="8")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// First top-level SPL Memo of the transaction, when it is valid UTF-8.
+    #[prost(string, optional, tag="9")]
+    pub memo: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag="10")]
+    pub compute_unit_price_micro_lamports: ::core::option::Option<u64>,
+    #[prost(uint32, optional, tag="11")]
+    pub compute_unit_limit: ::core::option::Option<u32>,
+    /// System Program instructions that failed to decode; the other events are still emitted.
+    #[prost(uint32, repeated, tag="12")]
+    pub failed_instruction_indices: ::prost::alloc::vec::Vec<u32>,
+    /// Program logs of the transaction, only filled with include_log_messages=true.
+    #[prost(string, repeated, tag="13")]
+    pub log_messages: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// First account key, which pays the transaction fee.
+    #[prost(string, tag="14")]
+    pub fee_payer: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag="15")]
+    pub signer_indexes: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, optional, tag="16")]
+    pub fee_payer_index: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SystemProgramEventRows {
+    #[prost(message, repeated, tag="1")]
+    pub rows: ::prost::alloc::vec::Vec<SystemProgramEventRow>,
+}
+/// One System Program event, denormalized for table sinks. Fields that don't apply to the event
+/// type are unset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SystemProgramEventRow {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(string, tag="2")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub transaction_index: u32,
+    #[prost(uint32, tag="4")]
+    pub instruction_index: u32,
+    /// Name of the event in SystemProgramEvent, e.g. "transfer" or "create_account".
+    #[prost(string, tag="5")]
+    pub event_type: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="6")]
+    pub lamports: ::core::option::Option<u64>,
+    /// Account the lamports come from.
+    #[prost(string, optional, tag="7")]
+    pub from: ::core::option::Option<::prost::alloc::string::String>,
+    /// Account receiving the lamports, or the account acted upon when no lamports move.
+    #[prost(string, optional, tag="8")]
+    pub to: ::core::option::Option<::prost::alloc::string::String>,
+    /// Program assigned as the owner of the account.
+    #[prost(string, optional, tag="9")]
+    pub owner: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemoBlockEvents {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub transactions: ::prost::alloc::vec::Vec<MemoTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemoTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub transaction_index: u32,
+    #[prost(message, repeated, tag="3")]
+    pub events: ::prost::alloc::vec::Vec<MemoEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemoEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    /// Empty when the payload is not valid UTF-8, in which case memo_raw holds it.
+    #[prost(string, tag="2")]
+    pub memo: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub memo_raw: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrecompileBlockEvents {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub transactions: ::prost::alloc::vec::Vec<PrecompileTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrecompileTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub transaction_index: u32,
+    #[prost(message, repeated, tag="3")]
+    pub events: ::prost::alloc::vec::Vec<PrecompileEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrecompileEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(enumeration="PrecompileProgram", tag="2")]
+    pub program: i32,
+    #[prost(message, repeated, tag="3")]
+    pub signatures: ::prost::alloc::vec::Vec<PrecompileSignature>,
+    /// Set when the instruction data is malformed; signatures then holds the entries decoded before the failure.
+    #[prost(string, optional, tag="4")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrecompileSignature {
+    /// Base58 public key for Ed25519, 0x-prefixed hex Ethereum address for Secp256k1.
+    #[prost(string, tag="1")]
+    pub signer: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="3")]
+    pub message_offset: u32,
+    #[prost(uint32, tag="4")]
+    pub message_size: u32,
+    #[prost(bytes="vec", tag="5")]
+    pub message: ::prost::alloc::vec::Vec<u8>,
+    /// Top-level instruction holding the message, unset when it is the precompile instruction itself.
+    #[prost(uint32, optional, tag="6")]
+    pub message_instruction_index: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeBudgetBlockEvents {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub transactions: ::prost::alloc::vec::Vec<ComputeBudgetTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeBudgetTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub transaction_index: u32,
+    #[prost(message, repeated, tag="3")]
+    pub events: ::prost::alloc::vec::Vec<ComputeBudgetEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ComputeBudgetEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(oneof="compute_budget_event::Event", tags="2, 3, 4, 5")]
+    pub event: ::core::option::Option<compute_budget_event::Event>,
+}
+/// Nested message and enum types in `ComputeBudgetEvent`.
+pub mod compute_budget_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="2")]
+        RequestHeapFrame(super::RequestHeapFrameEvent),
+        #[prost(message, tag="3")]
+        SetComputeUnitLimit(super::SetComputeUnitLimitEvent),
+        #[prost(message, tag="4")]
+        SetComputeUnitPrice(super::SetComputeUnitPriceEvent),
+        #[prost(message, tag="5")]
+        SetLoadedAccountsDataSizeLimit(super::SetLoadedAccountsDataSizeLimitEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestHeapFrameEvent {
+    #[prost(uint32, tag="1")]
+    pub bytes: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetComputeUnitLimitEvent {
+    #[prost(uint32, tag="1")]
+    pub units: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetComputeUnitPriceEvent {
+    #[prost(uint64, tag="1")]
+    pub micro_lamports: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLoadedAccountsDataSizeLimitEvent {
+    #[prost(uint32, tag="1")]
+    pub bytes: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SystemProgramBlockEventTrees {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub transactions: ::prost::alloc::vec::Vec<SystemProgramTransactionEventTree>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SystemProgramTransactionEventTree {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub transaction_index: u32,
+    #[prost(message, repeated, tag="3")]
+    pub instructions: ::prost::alloc::vec::Vec<InstructionNode>,
+}
+/// An instruction with the instructions it invoked nested under it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InstructionNode {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(string, tag="2")]
+    pub program_id: ::prost::alloc::string::String,
+    /// Only set for System Program instructions.
+    #[prost(message, optional, tag="3")]
+    pub event: ::core::option::Option<SystemProgramEvent>,
+    #[prost(message, repeated, tag="4")]
+    pub children: ::prost::alloc::vec::Vec<InstructionNode>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SystemProgramEvent {
+    /// Position in execution order: each top-level instruction is followed by the instructions it
+    /// invoked, depth first, before the next top-level instruction.
     #[prost(uint32, tag="1")]
     pub instruction_index: u32,
-    #[prost(oneof="system_program_event::Event", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
+    #[prost(oneof="system_program_event::Event", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15")]
     pub event: ::core::option::Option<system_program_event::Event>,
+    /// Accounts of the instruction, only filled with include_account_metas=true.
+    #[prost(message, repeated, tag="16")]
+    pub accounts: ::prost::alloc::vec::Vec<AccountMeta>,
+    /// Program id of the instruction that invoked this one through a CPI, empty for top-level
+    /// instructions.
+    #[prost(string, tag="17")]
+    pub invoking_program: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag="18")]
+    pub invoking_program_index: ::core::option::Option<u32>,
 }
 /// Nested message and enum types in `SystemProgramEvent`.
 pub mod system_program_event {
@@ -56,8 +365,23 @@ pub mod system_program_event {
         TransferWithSeed(super::TransferWithSeedEvent),
         #[prost(message, tag="14")]
         UpgradeNonceAccount(super::UpgradeNonceAccountEvent),
+        #[prost(message, tag="15")]
+        Unknown(super::UnknownEvent),
     }
 }
+/// An instruction account with its flags as declared by the transaction message.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountMeta {
+    #[prost(string, tag="1")]
+    pub pubkey: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub is_signer: bool,
+    #[prost(bool, tag="3")]
+    pub is_writable: bool,
+    #[prost(uint32, optional, tag="4")]
+    pub pubkey_index: ::core::option::Option<u32>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateAccountEvent {
@@ -71,6 +395,24 @@ pub struct CreateAccountEvent {
     pub space: u64,
     #[prost(string, tag="5")]
     pub owner: ::prost::alloc::string::String,
+    #[prost(enumeration="KnownProgram", tag="6")]
+    pub owner_program: i32,
+    #[prost(bytes="vec", tag="7")]
+    pub funding_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub new_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub owner_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="10")]
+    pub rent_exempt_minimum: u64,
+    #[prost(bool, tag="11")]
+    pub is_rent_exempt: bool,
+    #[prost(uint32, optional, tag="12")]
+    pub funding_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="13")]
+    pub new_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="14")]
+    pub owner_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -79,6 +421,14 @@ pub struct AssignEvent {
     pub assigned_account: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub owner: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub assigned_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub owner_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="5")]
+    pub assigned_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="6")]
+    pub owner_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -89,6 +439,23 @@ pub struct TransferEvent {
     pub recipient_account: ::prost::alloc::string::String,
     #[prost(uint64, tag="3")]
     pub lamports: u64,
+    #[prost(bytes="vec", tag="4")]
+    pub funding_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub recipient_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    /// The recipient is a token account synced with SyncNative later in the transaction.
+    #[prost(bool, tag="6")]
+    pub is_wsol_wrap: bool,
+    /// Number of consecutive transfers summed into this event with merge_transfers, 0 otherwise.
+    #[prost(uint32, tag="7")]
+    pub merged_count: u32,
+    /// The recipient is a Jito tip payment account, so lamports is the tip of a bundle.
+    #[prost(bool, tag="8")]
+    pub is_jito_tip: bool,
+    #[prost(uint32, optional, tag="9")]
+    pub funding_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="10")]
+    pub recipient_account_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -99,6 +466,7 @@ pub struct CreateAccountWithSeedEvent {
     pub created_account: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub base_account: ::prost::alloc::string::String,
+    /// UTF-8 seed. When the bytes are not valid UTF-8, this is a lossy conversion and the *_hex field holds the exact bytes.
     #[prost(string, tag="4")]
     pub seed: ::prost::alloc::string::String,
     #[prost(uint64, tag="5")]
@@ -107,6 +475,30 @@ pub struct CreateAccountWithSeedEvent {
     pub space: u64,
     #[prost(string, tag="7")]
     pub owner: ::prost::alloc::string::String,
+    #[prost(enumeration="KnownProgram", tag="8")]
+    pub owner_program: i32,
+    #[prost(bytes="vec", tag="9")]
+    pub funding_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub created_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub base_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="12")]
+    pub owner_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="13")]
+    pub rent_exempt_minimum: u64,
+    #[prost(bool, tag="14")]
+    pub is_rent_exempt: bool,
+    #[prost(string, tag="15")]
+    pub seed_hex: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag="16")]
+    pub funding_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="17")]
+    pub created_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="18")]
+    pub base_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="19")]
+    pub owner_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -115,6 +507,14 @@ pub struct AdvanceNonceAccountEvent {
     pub nonce_account: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub nonce_authority: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub nonce_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub nonce_authority_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="5")]
+    pub nonce_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="6")]
+    pub nonce_authority_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -127,6 +527,18 @@ pub struct WithdrawNonceAccountEvent {
     pub nonce_authority: ::prost::alloc::string::String,
     #[prost(uint64, tag="4")]
     pub lamports: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub nonce_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub recipient_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub nonce_authority_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="8")]
+    pub nonce_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="9")]
+    pub recipient_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="10")]
+    pub nonce_authority_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -135,6 +547,26 @@ pub struct InitializeNonceAccountEvent {
     pub nonce_account: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub nonce_authority: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub nonce_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub nonce_authority_bytes: ::prost::alloc::vec::Vec<u8>,
+    /// The RecentBlockhashes and Rent sysvars are the second and third accounts, as the System
+    /// Program client builds the instruction.
+    #[prost(bool, tag="5")]
+    pub canonical_layout: bool,
+    /// Funding account of the CreateAccount or CreateAccountWithSeed that created the nonce
+    /// account earlier in the transaction, empty when it was created elsewhere.
+    #[prost(string, tag="6")]
+    pub rent_payer: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub rent_payer_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="8")]
+    pub nonce_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="9")]
+    pub nonce_authority_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="10")]
+    pub rent_payer_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -145,6 +577,18 @@ pub struct AuthorizeNonceAccountEvent {
     pub nonce_authority: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub new_nonce_authority: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="4")]
+    pub nonce_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub nonce_authority_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub new_nonce_authority_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="7")]
+    pub nonce_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="8")]
+    pub nonce_authority_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="9")]
+    pub new_nonce_authority_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -153,6 +597,10 @@ pub struct AllocateEvent {
     pub account: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
     pub space: u64,
+    #[prost(bytes="vec", tag="3")]
+    pub account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="4")]
+    pub account_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -167,6 +615,20 @@ pub struct AllocateWithSeedEvent {
     pub space: u64,
     #[prost(string, tag="5")]
     pub owner: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub allocated_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub base_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub owner_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="9")]
+    pub seed_hex: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag="10")]
+    pub allocated_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="11")]
+    pub base_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="12")]
+    pub owner_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -179,6 +641,20 @@ pub struct AssignWithSeedEvent {
     pub seed: ::prost::alloc::string::String,
     #[prost(string, tag="4")]
     pub owner: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="5")]
+    pub assigned_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub base_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub owner_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub seed_hex: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag="9")]
+    pub assigned_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="10")]
+    pub base_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="11")]
+    pub owner_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -195,11 +671,110 @@ pub struct TransferWithSeedEvent {
     pub from_seed: ::prost::alloc::string::String,
     #[prost(string, tag="6")]
     pub from_owner: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub funding_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub base_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub recipient_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub from_owner_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="11")]
+    pub from_seed_hex: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag="12")]
+    pub funding_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="13")]
+    pub base_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="14")]
+    pub recipient_account_index: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="15")]
+    pub from_owner_index: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpgradeNonceAccountEvent {
     #[prost(string, tag="1")]
     pub nonce_account: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub nonce_account_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="3")]
+    pub nonce_account_index: ::core::option::Option<u32>,
+}
+/// Emitted for instructions with a discriminant this crate doesn't know about yet.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnknownEvent {
+    #[prost(uint32, tag="1")]
+    pub discriminant: u32,
+    #[prost(bytes="vec", tag="2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="3")]
+    pub accounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint32, repeated, tag="4")]
+    pub account_indexes: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum KnownProgram {
+    Unknown = 0,
+    System = 1,
+    Token = 2,
+    Token2022 = 3,
+    AssociatedToken = 4,
+    Metadata = 5,
+}
+impl KnownProgram {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            KnownProgram::Unknown => "UNKNOWN",
+            KnownProgram::System => "SYSTEM",
+            KnownProgram::Token => "TOKEN",
+            KnownProgram::Token2022 => "TOKEN_2022",
+            KnownProgram::AssociatedToken => "ASSOCIATED_TOKEN",
+            KnownProgram::Metadata => "METADATA",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNKNOWN" => Some(Self::Unknown),
+            "SYSTEM" => Some(Self::System),
+            "TOKEN" => Some(Self::Token),
+            "TOKEN_2022" => Some(Self::Token2022),
+            "ASSOCIATED_TOKEN" => Some(Self::AssociatedToken),
+            "METADATA" => Some(Self::Metadata),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PrecompileProgram {
+    Ed25519 = 0,
+    Secp256k1 = 1,
+}
+impl PrecompileProgram {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PrecompileProgram::Ed25519 => "ED25519",
+            PrecompileProgram::Secp256k1 => "SECP256K1",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ED25519" => Some(Self::Ed25519),
+            "SECP256K1" => Some(Self::Secp256k1),
+            _ => None,
+        }
+    }
 }
 // @@protoc_insertion_point(module)
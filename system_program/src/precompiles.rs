@@ -0,0 +1,188 @@
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+use substreams_solana_utils as utils;
+use utils::transaction::TransactionContext;
+use utils::instruction::StructuredInstruction;
+use utils::pubkey::Pubkey;
+
+use crate::pb::system_program::*;
+use crate::constants::{ED25519_PROGRAM_ID, SECP256K1_PROGRAM_ID};
+use crate::parser::{InstructionParser, ParseError, parse_transaction_multi, skip_missing_meta, to_hex};
+
+const ED25519_PUBKEY_SIZE: usize = 32;
+const ED25519_SIGNATURE_SIZE: usize = 64;
+/// The signature count is followed by a padding byte.
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+const SECP256K1_ETH_ADDRESS_SIZE: usize = 20;
+/// 64-byte signature followed by the recovery id.
+const SECP256K1_SIGNATURE_SIZE: usize = 65;
+const SECP256K1_OFFSETS_START: usize = 1;
+const SECP256K1_OFFSETS_SIZE: usize = 11;
+
+/// Location of the signature, signer and message of one signature entry. An instruction index
+/// of `None` refers to the precompile instruction itself, any other to a top-level instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureOffsets {
+    pub signature_offset: u16,
+    pub signature_instruction_index: Option<u16>,
+    pub signer_offset: u16,
+    pub signer_instruction_index: Option<u16>,
+    pub message_offset: u16,
+    pub message_size: u16,
+    pub message_instruction_index: Option<u16>,
+}
+
+impl SignatureOffsets {
+    /// Ed25519 offsets are all `u16`, with `u16::MAX` standing for the current instruction.
+    pub fn unpack_ed25519(data: &[u8], index: usize) -> Result<Self, String> {
+        let start = ED25519_OFFSETS_START + index * ED25519_OFFSETS_SIZE;
+        let offsets = data.get(start..start + ED25519_OFFSETS_SIZE)
+            .ok_or_else(|| format!("Offsets of signature {} are out of bounds.", index))?;
+        let u16_at = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+        let instruction_index_at = |i: usize| Some(u16_at(i)).filter(|index| *index != u16::MAX);
+        Ok(SignatureOffsets {
+            signature_offset: u16_at(0),
+            signature_instruction_index: instruction_index_at(2),
+            signer_offset: u16_at(4),
+            signer_instruction_index: instruction_index_at(6),
+            message_offset: u16_at(8),
+            message_size: u16_at(10),
+            message_instruction_index: instruction_index_at(12),
+        })
+    }
+
+    /// Secp256k1 instruction indexes are `u8` and always refer to a top-level instruction.
+    pub fn unpack_secp256k1(data: &[u8], index: usize) -> Result<Self, String> {
+        let start = SECP256K1_OFFSETS_START + index * SECP256K1_OFFSETS_SIZE;
+        let offsets = data.get(start..start + SECP256K1_OFFSETS_SIZE)
+            .ok_or_else(|| format!("Offsets of signature {} are out of bounds.", index))?;
+        let u16_at = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+        Ok(SignatureOffsets {
+            signature_offset: u16_at(0),
+            signature_instruction_index: Some(offsets[2].into()),
+            signer_offset: u16_at(3),
+            signer_instruction_index: Some(offsets[5].into()),
+            message_offset: u16_at(6),
+            message_size: u16_at(8),
+            message_instruction_index: Some(offsets[10].into()),
+        })
+    }
+}
+
+/// Decodes Ed25519 or Secp256k1 signature verification instructions.
+pub struct PrecompileParser<'a> {
+    pub program: PrecompileProgram,
+    /// Data of the top-level instructions of the transaction, which offsets may point into.
+    pub top_level_data: &'a [Vec<u8>],
+}
+
+impl InstructionParser<PrecompileEvent> for PrecompileParser<'_> {
+    fn program_id(&self) -> Pubkey {
+        match self.program {
+            PrecompileProgram::Ed25519 => ED25519_PROGRAM_ID,
+            PrecompileProgram::Secp256k1 => SECP256K1_PROGRAM_ID,
+        }
+    }
+
+    fn parse(
+        &self,
+        instruction: &StructuredInstruction,
+        _context: &TransactionContext,
+    ) -> Result<Option<PrecompileEvent>, ParseError> {
+        Ok(Some(parse_precompile_instruction(self.program, &instruction.data(), self.top_level_data)))
+    }
+}
+
+pub fn parse_block(block: &Block) -> Result<PrecompileBlockEvents, Error> {
+    let mut transactions: Vec<PrecompileTransactionEvents> = Vec::new();
+    for (i, transaction) in block.transactions.iter().enumerate() {
+        if skip_missing_meta(transaction) {
+            continue;
+        }
+        let events = parse_transaction(transaction)?;
+        if !events.is_empty() {
+            transactions.push(PrecompileTransactionEvents {
+                signature: utils::transaction::get_signature(transaction),
+                transaction_index: i as u32,
+                events,
+            });
+        }
+    }
+    Ok(PrecompileBlockEvents { slot: block.slot, transactions })
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<PrecompileEvent>, Error> {
+    let top_level_data: Vec<Vec<u8>> = transaction.transaction.as_ref()
+        .and_then(|transaction| transaction.message.as_ref())
+        .map(|message| message.instructions.iter().map(|instruction| instruction.data.clone()).collect())
+        .unwrap_or_default();
+    let ed25519 = PrecompileParser { program: PrecompileProgram::Ed25519, top_level_data: &top_level_data };
+    let secp256k1 = PrecompileParser { program: PrecompileProgram::Secp256k1, top_level_data: &top_level_data };
+    let events = parse_transaction_multi(transaction, &[&ed25519, &secp256k1])?;
+    Ok(events.into_iter().map(|(instruction_index, event)| PrecompileEvent { instruction_index, ..event }).collect())
+}
+
+/// Resolves every signature entry of a precompile instruction. Malformed data never fails: the
+/// entries resolved so far are returned along with the reason in `error`.
+pub fn parse_precompile_instruction(program: PrecompileProgram, data: &[u8], top_level_data: &[Vec<u8>]) -> PrecompileEvent {
+    let mut signatures: Vec<PrecompileSignature> = Vec::new();
+    let error = _parse_signatures(program, data, top_level_data, &mut signatures).err();
+    PrecompileEvent {
+        instruction_index: 0,
+        program: program.into(),
+        signatures,
+        error,
+    }
+}
+
+fn _parse_signatures(
+    program: PrecompileProgram,
+    data: &[u8],
+    top_level_data: &[Vec<u8>],
+    signatures: &mut Vec<PrecompileSignature>,
+) -> Result<(), String> {
+    let num_signatures = *data.first().ok_or("Instruction data is empty.")? as usize;
+    for i in 0..num_signatures {
+        let (offsets, signer_size, signature_size) = match program {
+            PrecompileProgram::Ed25519 => (SignatureOffsets::unpack_ed25519(data, i)?, ED25519_PUBKEY_SIZE, ED25519_SIGNATURE_SIZE),
+            PrecompileProgram::Secp256k1 => (SignatureOffsets::unpack_secp256k1(data, i)?, SECP256K1_ETH_ADDRESS_SIZE, SECP256K1_SIGNATURE_SIZE),
+        };
+        let signer = _resolve(data, top_level_data, offsets.signer_instruction_index, offsets.signer_offset, signer_size)?;
+        let signature = _resolve(data, top_level_data, offsets.signature_instruction_index, offsets.signature_offset, signature_size)?;
+        let message = _resolve(data, top_level_data, offsets.message_instruction_index, offsets.message_offset, offsets.message_size.into())?;
+        let signer = match program {
+            PrecompileProgram::Ed25519 => bs58::encode(signer).into_string(),
+            PrecompileProgram::Secp256k1 => format!("0x{}", to_hex(signer)),
+        };
+        signatures.push(PrecompileSignature {
+            signer,
+            signature: signature.to_vec(),
+            message_offset: offsets.message_offset.into(),
+            message_size: offsets.message_size.into(),
+            message: message.to_vec(),
+            message_instruction_index: offsets.message_instruction_index.map(u32::from),
+        });
+    }
+    Ok(())
+}
+
+fn _resolve<'a>(
+    data: &'a [u8],
+    top_level_data: &'a [Vec<u8>],
+    instruction_index: Option<u16>,
+    offset: u16,
+    size: usize,
+) -> Result<&'a [u8], String> {
+    let source = match instruction_index {
+        None => data,
+        Some(index) => top_level_data.get(index as usize)
+            .ok_or_else(|| format!("Instruction index {} is out of bounds.", index))?,
+    };
+    let start = offset as usize;
+    source.get(start..start + size).ok_or_else(|| format!(
+        "Range {}..{} is out of bounds of instruction data of length {}.", start, start + size, source.len(),
+    ))
+}
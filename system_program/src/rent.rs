@@ -0,0 +1,40 @@
+//! Rent exemption as computed by the runtime with the mainnet rent parameters.
+
+/// Bytes charged on top of the account data for the account metadata.
+pub const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+pub const LAMPORTS_PER_BYTE_YEAR: u64 = 3480;
+/// The runtime defines the threshold as `2.0` years; it is kept integral to avoid float rounding.
+pub const EXEMPTION_THRESHOLD_YEARS: u64 = 2;
+
+/// Minimum balance for an account with `space` bytes of data to be rent exempt.
+pub fn rent_exempt_minimum(space: u64) -> u64 {
+    ACCOUNT_STORAGE_OVERHEAD
+        .saturating_add(space)
+        .saturating_mul(LAMPORTS_PER_BYTE_YEAR)
+        .saturating_mul(EXEMPTION_THRESHOLD_YEARS)
+}
+
+pub fn is_rent_exempt(lamports: u64, space: u64) -> bool {
+    lamports >= rent_exempt_minimum(space)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rent_exempt_minimum_table() {
+        let table = [
+            (0, 890_880),
+            // Token account.
+            (165, 2_039_280),
+            // Largest account, 10 MiB.
+            (10 * 1024 * 1024, 72_981_780_480),
+        ];
+        for (space, minimum) in table {
+            assert_eq!(rent_exempt_minimum(space), minimum, "space {}", space);
+            assert!(is_rent_exempt(minimum, space));
+            assert!(!is_rent_exempt(minimum - 1, space));
+        }
+    }
+}
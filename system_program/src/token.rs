@@ -0,0 +1,26 @@
+use substreams_solana_utils as utils;
+use utils::transaction::TransactionContext;
+use utils::instruction::StructuredInstruction;
+use utils::spl_token::TOKEN_PROGRAM_ID;
+use utils::pubkey::Pubkey;
+
+use crate::parser::{InstructionParser, ParseError};
+
+pub use spl_token_substream::pb::spl_token::spl_token_event::Event as TokenEvent;
+
+/// The SPL Token decoder of `spl_token_substream`, registered by `default_registry`.
+pub struct SplTokenParser;
+
+impl<T: From<TokenEvent>> InstructionParser<T> for SplTokenParser {
+    fn program_id(&self) -> Pubkey {
+        TOKEN_PROGRAM_ID
+    }
+
+    fn parse(
+        &self,
+        instruction: &StructuredInstruction,
+        context: &TransactionContext,
+    ) -> Result<Option<T>, ParseError> {
+        Ok(spl_token_substream::parse_instruction(instruction, context)?.map(T::from))
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::anyhow;
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::transaction::{get_context, TransactionContext};
+use utils::instruction::{get_structured_instructions, StructuredInstruction};
+use utils::system_program::SYSTEM_PROGRAM_ID;
+
+use crate::pb::system_program::*;
+use crate::{parse_instruction_with_options, instruction_count, format_pubkey, ParseOptions};
+use crate::parser::{is_vote_transaction, skip_missing_meta, skip_unresolvable, to_hex, ParseError};
+
+/// Solana caps CPI depth well below this; anything deeper is treated as malformed, and the
+/// transaction is skipped.
+pub const MAX_INSTRUCTION_TREE_DEPTH: usize = 16;
+
+/// Instruction trees of the transactions of `block` with a System Program event. Vote
+/// transactions are skipped, as are transactions whose tree can't be built, which are logged
+/// without failing the block.
+pub fn parse_block_tree(block: &Block, options: &ParseOptions) -> Result<SystemProgramBlockEventTrees, Error> {
+    let mut transactions: Vec<SystemProgramTransactionEventTree> = Vec::new();
+    for (i, transaction) in block.transactions.iter().enumerate() {
+        if is_vote_transaction(transaction) || skip_missing_meta(transaction) {
+            continue;
+        }
+        let instructions = match parse_transaction_tree(transaction, options) {
+            Ok(instructions) => instructions,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        if instructions.iter().any(contains_event) {
+            transactions.push(SystemProgramTransactionEventTree {
+                signature: utils::transaction::get_signature(transaction),
+                transaction_index: i as u32,
+                instructions,
+            });
+        }
+    }
+    Ok(SystemProgramBlockEventTrees { slot: block.slot, transactions })
+}
+
+/// Builds the instruction call tree of `transaction`, decoding System Program nodes.
+///
+/// Node indexes match the positions in `instructions.flattened()`, so they line up with the
+/// `instruction_index` of the flat events. A System Program instruction that fails to decode is
+/// logged and kept as a node without an event, as the flat events leave it out without failing
+/// the others.
+pub fn parse_transaction_tree(transaction: &ConfirmedTransaction, options: &ParseOptions) -> Result<Vec<InstructionNode>, Error> {
+    if transaction.meta.as_ref().ok_or(ParseError::MissingMeta)?.err.is_some() {
+        return Ok(Vec::new())
+    }
+    if instruction_count(transaction) > options.max_instructions_per_transaction {
+        return Ok(Vec::new())
+    }
+    if skip_unresolvable(transaction) {
+        return Ok(Vec::new())
+    }
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    let mut index: u32 = 0;
+    instructions.iter()
+        .map(|instruction| build_node(instruction, &context, options, "", 0, &mut index))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| anyhow!("Failed to parse transaction {} with error: {}", context.signature, e))
+}
+
+fn build_node(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    options: &ParseOptions,
+    invoking_program: &str,
+    depth: usize,
+    index: &mut u32,
+) -> Result<InstructionNode, Error> {
+    if depth >= MAX_INSTRUCTION_TREE_DEPTH {
+        return Err(anyhow!("Instruction nesting exceeds {} levels.", MAX_INSTRUCTION_TREE_DEPTH));
+    }
+    let instruction_index = *index;
+    *index += 1;
+
+    let program_id = format_pubkey(&instruction.program_id().0, options.pubkey_encoding);
+    let event = if instruction.program_id() == SYSTEM_PROGRAM_ID {
+        match parse_instruction_with_options(instruction, context, options) {
+            Ok(event) => event,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Failed to parse instruction {} of transaction {} with error: {} (program {}, data {})",
+                    instruction_index, context.signature, e, program_id, to_hex(&instruction.data()),
+                ));
+                None
+            }
+        }
+        .map(|event| SystemProgramEvent {
+            instruction_index,
+            event: Some(event),
+            accounts: Vec::new(),
+            invoking_program: invoking_program.to_string(),
+            invoking_program_index: None,
+        })
+    } else {
+        None
+    };
+    let children = instruction.inner_instructions().iter()
+        .map(|inner_instruction| build_node(inner_instruction, context, options, &program_id, depth + 1, index))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(InstructionNode {
+        instruction_index,
+        program_id,
+        event,
+        children,
+    })
+}
+
+fn contains_event(node: &InstructionNode) -> bool {
+    node.event.is_some() || node.children.iter().any(contains_event)
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use substreams::errors::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use substreams_solana_utils as utils;
+use utils::instruction::get_structured_instructions;
+use utils::spl_token::TOKEN_PROGRAM_ID;
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+use crate::constants::TOKEN_2022_PROGRAM_ID;
+use crate::parser::FlattenedInstructions;
+use crate::options::{decode_pubkey, PubkeyEncoding};
+
+const SYNC_NATIVE_DISCRIMINANT: u8 = 17;
+
+/// Sets `is_wsol_wrap` on transfers whose recipient is a token account synced with SyncNative
+/// later in the same transaction, which is how SOL gets wrapped into WSOL.
+pub fn annotate_wsol_wraps(
+    transaction: &ConfirmedTransaction,
+    events: &mut [SystemProgramEvent],
+    pubkey_encoding: PubkeyEncoding,
+) -> Result<(), Error> {
+    if !events.iter().any(|event| matches!(event.event, Some(Event::Transfer(_)))) {
+        return Ok(())
+    }
+
+    let last_sync_native = last_sync_native_indexes(transaction)?;
+    for event in events.iter_mut() {
+        let instruction_index = event.instruction_index;
+        if let Some(Event::Transfer(transfer)) = event.event.as_mut() {
+            let recipient = if transfer.recipient_account_bytes.is_empty() {
+                decode_pubkey(&transfer.recipient_account, pubkey_encoding)
+            } else {
+                transfer.recipient_account_bytes.clone()
+            };
+            transfer.is_wsol_wrap = last_sync_native.get(&recipient)
+                .map_or(false, |sync_index| *sync_index > instruction_index);
+        }
+    }
+    Ok(())
+}
+
+/// Index of the last SyncNative instruction applied to each token account, keyed by raw pubkey.
+fn last_sync_native_indexes(transaction: &ConfirmedTransaction) -> Result<HashMap<Vec<u8>, u32>, Error> {
+    let instructions = get_structured_instructions(transaction)?;
+    let mut indexes: HashMap<Vec<u8>, u32> = HashMap::new();
+    for (i, instruction) in instructions.flattened_iter().enumerate() {
+        let program_id = instruction.program_id();
+        if program_id != TOKEN_PROGRAM_ID && program_id != TOKEN_2022_PROGRAM_ID {
+            continue;
+        }
+        if instruction.data().first() != Some(&SYNC_NATIVE_DISCRIMINANT) {
+            continue;
+        }
+        if let Some(account) = instruction.accounts().first() {
+            indexes.insert(account.0.to_vec(), i as u32);
+        }
+    }
+    Ok(indexes)
+}
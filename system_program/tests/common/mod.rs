@@ -0,0 +1,37 @@
+//! Loader for the transaction fixtures of `tests/fixtures`, shared by the integration tests.
+#![allow(dead_code)]
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use system_program_substream::decode_transaction_base64;
+use system_program_substream::pb::system_program::SystemProgramEvent;
+use system_program_substream::pb::system_program::system_program_event::Event;
+
+/// Decodes `tests/fixtures/<name>.b64`. See `tests/fixtures/generate.py` for how each fixture
+/// is built.
+pub fn load_fixture(name: &str) -> ConfirmedTransaction {
+    let path = format!("{}/tests/fixtures/{}.b64", env!("CARGO_MANIFEST_DIR"), name);
+    let encoded = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+    decode_transaction_base64(&encoded).unwrap_or_else(|e| panic!("Failed to decode {}: {}", path, e))
+}
+
+/// Events of the fixture `name`, parsed with the default options.
+pub fn fixture_events(name: &str) -> Vec<SystemProgramEvent> {
+    system_program_substream::parse_transaction(&load_fixture(name)).unwrap()
+}
+
+/// The single event of the fixture `name`.
+pub fn single_event(name: &str) -> Event {
+    let events = fixture_events(name);
+    assert_eq!(events.len(), 1, "{} should have a single event", name);
+    events[0].event.clone().unwrap()
+}
+
+/// Base58 of the `[n; 32]` fixture key.
+pub fn key(n: u8) -> String {
+    bs58::encode([n; 32]).into_string()
+}
+
+/// Base58 of the System Program id, the all-zero key.
+pub fn system_program() -> String {
+    key(0)
+}
@@ -0,0 +1,26 @@
+mod common;
+
+use common::load_fixture;
+use system_program_substream::{parse_transaction_with_options, ParseOptions};
+
+fn event_count(fixture: &str, min_transfer_lamports: u64, filter_create_account_dust: bool) -> usize {
+    let options = ParseOptions { min_transfer_lamports, filter_create_account_dust, ..ParseOptions::default() };
+    parse_transaction_with_options(&load_fixture(fixture), &options).unwrap().len()
+}
+
+#[test]
+fn transfers_below_the_minimum_are_dropped() {
+    for (fixture, lamports) in [("transfer", 1_000_000), ("transfer_with_seed", 5_000)] {
+        assert_eq!(event_count(fixture, lamports, false), 1, "{}", fixture);
+        assert_eq!(event_count(fixture, lamports + 1, false), 0, "{}", fixture);
+    }
+}
+
+#[test]
+fn account_creations_below_the_minimum_are_dropped_when_enabled() {
+    for (fixture, lamports) in [("create_account", 2_039_280), ("create_account_with_seed", 2_282_880)] {
+        assert_eq!(event_count(fixture, lamports + 1, false), 1, "{}", fixture);
+        assert_eq!(event_count(fixture, lamports, true), 1, "{}", fixture);
+        assert_eq!(event_count(fixture, lamports + 1, true), 0, "{}", fixture);
+    }
+}
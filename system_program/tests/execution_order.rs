@@ -0,0 +1,57 @@
+mod common;
+
+use common::{fixture_events, key, load_fixture, system_program};
+use system_program_substream::ParseOptions;
+use system_program_substream::pb::system_program::InstructionNode;
+use system_program_substream::pb::system_program::system_program_event::Event;
+use system_program_substream::tree::parse_transaction_tree;
+
+// In `nested_cpi`, program X (key 20) invokes program Y (key 21), which transfers 1 lamport,
+// then X transfers 2. The second top-level instruction transfers 3, and the third is X again,
+// creating an account. Flattened in execution order:
+//   0 X, 1 Y, 2 Transfer(1), 3 Transfer(2), 4 Transfer(3), 5 X, 6 CreateAccount
+
+#[test]
+fn instruction_indexes_follow_execution_order() {
+    let events = fixture_events("nested_cpi");
+    let indexes: Vec<u32> = events.iter().map(|event| event.instruction_index).collect();
+    assert_eq!(indexes, vec![2, 3, 4, 6]);
+
+    let lamports: Vec<u64> = events.iter().filter_map(|event| match &event.event {
+        Some(Event::Transfer(transfer)) => Some(transfer.lamports),
+        _ => None,
+    }).collect();
+    assert_eq!(lamports, vec![1, 2, 3]);
+    assert!(matches!(events[3].event, Some(Event::CreateAccount(_))));
+}
+
+#[test]
+fn invoking_programs_follow_the_call_stack() {
+    let invoking_programs: Vec<String> = fixture_events("nested_cpi").into_iter().map(|event| event.invoking_program).collect();
+    assert_eq!(invoking_programs, vec![key(21), key(20), String::new(), key(20)]);
+}
+
+#[test]
+fn tree_indexes_match_flat_indexes() {
+    fn shape(node: &InstructionNode) -> (u32, String, Vec<(u32, String, usize)>) {
+        let children = node.children.iter().map(|child| (child.instruction_index, child.program_id.clone(), child.children.len())).collect();
+        (node.instruction_index, node.program_id.clone(), children)
+    }
+
+    let tree = parse_transaction_tree(&load_fixture("nested_cpi"), &ParseOptions::default()).unwrap();
+    let shapes: Vec<_> = tree.iter().map(shape).collect();
+    assert_eq!(shapes, vec![
+        (0, key(20), vec![(1, key(21), 1), (3, system_program(), 0)]),
+        (4, system_program(), vec![]),
+        (5, key(20), vec![(6, system_program(), 0)]),
+    ]);
+    assert_eq!(tree[0].children[0].children[0].instruction_index, 2);
+}
+
+#[test]
+fn tree_keeps_undecodable_instructions() {
+    let tree = parse_transaction_tree(&load_fixture("authorize_nonce_account_truncated"), &ParseOptions::default()).unwrap();
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].program_id, system_program());
+    assert!(tree[0].event.is_none());
+}
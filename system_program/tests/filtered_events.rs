@@ -0,0 +1,34 @@
+mod common;
+
+use common::{key, load_fixture};
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+use system_program_substream::filter::parse_filter_params;
+use system_program_substream::{parse_block_filtered, ParseOptions};
+
+fn block() -> Block {
+    Block { transactions: vec![load_fixture("transfer"), load_fixture("assign")], ..Default::default() }
+}
+
+#[test]
+fn keeps_transactions_involving_a_pubkey() {
+    // Only the transfer involves its recipient, key 2.
+    let pubkeys = parse_filter_params(&format!("{}, {}", key(2), key(8))).unwrap();
+    let output = parse_block_filtered(&block(), &ParseOptions::default(), &pubkeys).unwrap();
+    assert_eq!(output.transactions.len(), 1);
+    assert_eq!(output.transactions[0].transaction_index, 0);
+    assert_eq!(output.transactions_scanned, 2);
+}
+
+#[test]
+fn drops_transactions_not_involving_any_pubkey() {
+    let pubkeys = parse_filter_params(&key(8)).unwrap();
+    let output = parse_block_filtered(&block(), &ParseOptions::default(), &pubkeys).unwrap();
+    assert!(output.transactions.is_empty());
+}
+
+#[test]
+fn rejects_invalid_pubkeys() {
+    assert!(parse_filter_params("not-base58!").is_err());
+    // Valid base58, but 3 bytes.
+    assert!(parse_filter_params("abc").is_err());
+}
@@ -0,0 +1,277 @@
+"""Builds the transaction fixtures of this directory.
+
+Each fixture is a base64 protobuf-encoded `sf.solana.type.v1.ConfirmedTransaction`, the format
+loaded by `decode_transaction_base64`. Account keys are `[n; 32]` byte arrays (`key(n)`), except
+for programs and sysvars, so the tests can derive the expected pubkeys from `n`. Run from this
+directory with `python3 generate.py`; only the standard library is used.
+"""
+
+import base64
+import struct
+
+ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+
+
+def b58encode(raw):
+    n = int.from_bytes(raw, "big")
+    text = ""
+    while n:
+        n, r = divmod(n, 58)
+        text = ALPHABET[r] + text
+    return "1" * (len(raw) - len(raw.lstrip(b"\0"))) + text
+
+
+def b58decode(text):
+    n = 0
+    for c in text:
+        n = n * 58 + ALPHABET.index(c)
+    raw = n.to_bytes(32, "big")
+    return raw
+
+
+def key(n):
+    return bytes([n]) * 32
+
+
+SYSTEM_PROGRAM = key(0)
+TOKEN_PROGRAM = b58decode("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+RECENT_BLOCKHASHES = b58decode("SysvarRecentB1ockHashes11111111111111111111")
+RENT = b58decode("SysvarRent111111111111111111111111111111111")
+COMPUTE_BUDGET = b58decode("ComputeBudget111111111111111111111111111111")
+PROGRAM_X = key(20)
+PROGRAM_Y = key(21)
+
+
+# Protobuf encoding
+
+def varint(n):
+    out = b""
+    while True:
+        byte = n & 0x7F
+        n >>= 7
+        if n:
+            out += bytes([byte | 0x80])
+        else:
+            return out + bytes([byte])
+
+
+def field_varint(number, value):
+    return varint(number << 3) + varint(value)
+
+
+def field_bytes(number, value):
+    return varint(number << 3 | 2) + varint(len(value)) + value
+
+
+def message_header(num_required_signatures, num_readonly_signed, num_readonly_unsigned):
+    return field_varint(1, num_required_signatures) + field_varint(2, num_readonly_signed) + field_varint(3, num_readonly_unsigned)
+
+
+def compiled_instruction(program_id_index, accounts, data):
+    """Returns the program id index, for the logs, along with the encoded instruction."""
+    return program_id_index, field_varint(1, program_id_index) + field_bytes(2, bytes(accounts)) + field_bytes(3, data)
+
+
+def inner_instruction(program_id_index, accounts, data, stack_height):
+    _, encoded = compiled_instruction(program_id_index, accounts, data)
+    return program_id_index, encoded + field_varint(4, stack_height), stack_height
+
+
+def inner_instructions(index, instructions):
+    return index, instructions
+
+
+def log_messages(account_keys, instructions, inner):
+    """Invoke and success logs of the instruction tree, as the runtime writes them."""
+    logs = []
+    for index, (program_id_index, _) in enumerate(instructions):
+        stack = [account_keys[program_id_index]]
+        logs.append(f"Program {b58encode(stack[0])} invoke [1]")
+        for inner_index, inner_program_id_index, stack_height in inner:
+            if inner_index != index:
+                continue
+            while len(stack) >= stack_height:
+                logs.append(f"Program {b58encode(stack.pop())} success")
+            stack.append(account_keys[inner_program_id_index])
+            logs.append(f"Program {b58encode(stack[-1])} invoke [{stack_height}]")
+        while stack:
+            logs.append(f"Program {b58encode(stack.pop())} success")
+    return logs
+
+
+def confirmed_transaction(signature, header, account_keys, instructions, inner=(), compute_units_consumed=None, return_data=None):
+    tree = [(index, program_id_index, stack_height) for index, group in inner for program_id_index, _, stack_height in group]
+    logs = log_messages(account_keys, instructions, tree)
+    inner = [field_varint(1, index) + b"".join(field_bytes(2, encoded) for _, encoded, _ in group) for index, group in inner]
+    instructions = [encoded for _, encoded in instructions]
+
+    message = field_bytes(1, header)
+    message += b"".join(field_bytes(2, account_key) for account_key in account_keys)
+    message += field_bytes(3, key(7))
+    message += b"".join(field_bytes(4, instruction) for instruction in instructions)
+    transaction = field_bytes(1, signature) + field_bytes(2, message)
+
+    balances = b"".join(varint(1_000_000_000) for _ in account_keys)
+    meta = field_varint(2, 5000) + field_bytes(3, balances) + field_bytes(4, balances)
+    meta += b"".join(field_bytes(5, group) for group in inner)
+    meta += b"".join(field_bytes(6, log.encode()) for log in logs)
+    if return_data is not None:
+        program_id, data = return_data
+        meta += field_bytes(14, field_bytes(1, program_id) + field_bytes(2, data))
+    if compute_units_consumed is not None:
+        meta += field_varint(16, compute_units_consumed)
+    return field_bytes(1, transaction) + field_bytes(2, meta)
+
+
+# System Program instruction data, bincode encoded
+
+def u32(n):
+    return struct.pack("<I", n)
+
+
+def u64(n):
+    return struct.pack("<Q", n)
+
+
+def string(s):
+    return u64(len(s)) + s
+
+
+def create_account(lamports, space, owner):
+    return u32(0) + u64(lamports) + u64(space) + owner
+
+
+def transfer(lamports):
+    return u32(2) + u64(lamports)
+
+
+def signature(n):
+    return bytes([n]) * 64
+
+
+FIXTURES = {
+    "create_account": confirmed_transaction(
+        signature(1), message_header(2, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [0, 1], create_account(2_039_280, 165, TOKEN_PROGRAM))],
+    ),
+    "assign": confirmed_transaction(
+        signature(2), message_header(1, 0, 1), [key(1), SYSTEM_PROGRAM],
+        [compiled_instruction(1, [0], u32(1) + key(9))],
+    ),
+    # No compute units consumed nor return data in the meta, as in blocks predating them.
+    "transfer": confirmed_transaction(
+        signature(3), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [0, 1], transfer(1_000_000))],
+    ),
+    "create_account_with_seed": confirmed_transaction(
+        signature(4), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [0, 1, 0], u32(3) + key(1) + string(b"stake:0") + u64(2_282_880) + u64(200) + key(9))],
+    ),
+    "advance_nonce_account": confirmed_transaction(
+        signature(5), message_header(1, 0, 2), [key(1), key(2), RECENT_BLOCKHASHES, SYSTEM_PROGRAM],
+        [compiled_instruction(3, [1, 2, 0], u32(4))],
+    ),
+    "withdraw_nonce_account": confirmed_transaction(
+        signature(6), message_header(1, 0, 3), [key(1), key(2), key(3), RECENT_BLOCKHASHES, RENT, SYSTEM_PROGRAM],
+        [compiled_instruction(5, [1, 2, 3, 4, 0], u32(5) + u64(1_000_000))],
+    ),
+    "initialize_nonce_account": confirmed_transaction(
+        signature(7), message_header(2, 0, 3), [key(1), key(2), RECENT_BLOCKHASHES, RENT, SYSTEM_PROGRAM],
+        [
+            compiled_instruction(4, [0, 1], create_account(1_447_680, 80, SYSTEM_PROGRAM)),
+            compiled_instruction(4, [1, 2, 3], u32(6) + key(5)),
+        ],
+    ),
+    "authorize_nonce_account": confirmed_transaction(
+        signature(8), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [1, 0], u32(7) + key(6))],
+    ),
+    "allocate": confirmed_transaction(
+        signature(9), message_header(1, 0, 1), [key(1), SYSTEM_PROGRAM],
+        [compiled_instruction(1, [0], u32(8) + u64(165))],
+    ),
+    "allocate_with_seed": confirmed_transaction(
+        signature(10), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [1, 0], u32(9) + key(1) + string(b"seed") + u64(100) + key(9))],
+    ),
+    "assign_with_seed": confirmed_transaction(
+        signature(11), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [1, 0], u32(10) + key(1) + string(b"\xff\x00seed") + key(9))],
+    ),
+    "transfer_with_seed": confirmed_transaction(
+        signature(12), message_header(1, 0, 1), [key(1), key(2), key(3), SYSTEM_PROGRAM],
+        [compiled_instruction(3, [1, 0, 2], u32(11) + u64(5_000) + string(b"seed") + SYSTEM_PROGRAM)],
+    ),
+    "upgrade_nonce_account": confirmed_transaction(
+        signature(13), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [1], u32(12))],
+    ),
+    "unknown_discriminant": confirmed_transaction(
+        signature(14), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [0, 1], u32(99) + b"\x01\x02\x03")],
+    ),
+    # Same transfer as `transfer`, from a block recent enough to record compute units, with
+    # return data set by the invoked program.
+    "compute_units_consumed": confirmed_transaction(
+        signature(15), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [0, 1], transfer(1_000_000))],
+        compute_units_consumed=150, return_data=(SYSTEM_PROGRAM, b"\x2a"),
+    ),
+    # Program X invokes program Y, which transfers 1 lamport, then X transfers 2 itself. The
+    # second top-level instruction transfers 3, and the third is X creating an account.
+    "nested_cpi": confirmed_transaction(
+        signature(16), message_header(1, 0, 3), [key(1), key(2), key(3), PROGRAM_X, PROGRAM_Y, SYSTEM_PROGRAM],
+        [
+            compiled_instruction(3, [0, 1, 4, 5], b"\x00"),
+            compiled_instruction(5, [0, 2], transfer(3)),
+            compiled_instruction(3, [0, 2, 5], b"\x01"),
+        ],
+        inner=[
+            inner_instructions(0, [
+                inner_instruction(4, [0, 1, 5], b"\x00", 2),
+                inner_instruction(5, [0, 1], transfer(1), 3),
+                inner_instruction(5, [0, 1], transfer(2), 2),
+            ]),
+            inner_instructions(2, [
+                inner_instruction(5, [0, 2], create_account(890_880, 0, PROGRAM_X), 2),
+            ]),
+        ],
+    ),
+    # A priority fee transfer: a 200,000 unit limit priced at 10,000 micro-lamports per unit.
+    "compute_budget": confirmed_transaction(
+        signature(17), message_header(1, 0, 2), [key(1), key(2), COMPUTE_BUDGET, SYSTEM_PROGRAM],
+        [
+            compiled_instruction(2, [], b"\x02" + u32(200_000)),
+            compiled_instruction(2, [], b"\x03" + u64(10_000)),
+            compiled_instruction(3, [0, 1], transfer(1_000_000)),
+        ],
+    ),
+    # `authorize_nonce_account` with the new authority cut to 16 bytes.
+    "authorize_nonce_account_truncated": confirmed_transaction(
+        signature(18), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [1, 0], u32(7) + key(6)[:16])],
+    ),
+    # The with-seed instructions again, with the seed "mySeed", for the seed round-trip tests.
+    "create_account_with_my_seed": confirmed_transaction(
+        signature(19), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [0, 1, 0], u32(3) + key(1) + string(b"mySeed") + u64(2_282_880) + u64(200) + key(9))],
+    ),
+    "allocate_with_my_seed": confirmed_transaction(
+        signature(20), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [1, 0], u32(9) + key(1) + string(b"mySeed") + u64(100) + key(9))],
+    ),
+    "assign_with_my_seed": confirmed_transaction(
+        signature(21), message_header(1, 0, 1), [key(1), key(2), SYSTEM_PROGRAM],
+        [compiled_instruction(2, [1, 0], u32(10) + key(1) + string(b"mySeed") + key(9))],
+    ),
+    "transfer_with_my_seed": confirmed_transaction(
+        signature(22), message_header(1, 0, 1), [key(1), key(2), key(3), SYSTEM_PROGRAM],
+        [compiled_instruction(3, [1, 0, 2], u32(11) + u64(5_000) + string(b"mySeed") + SYSTEM_PROGRAM)],
+    ),
+}
+
+
+if __name__ == "__main__":
+    for name, fixture in FIXTURES.items():
+        with open(f"{name}.b64", "w") as f:
+            f.write(base64.b64encode(fixture).decode() + "\n")
@@ -0,0 +1,42 @@
+mod common;
+
+use common::{key, load_fixture};
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+use system_program_substream::pb::system_program::system_program_event::Event;
+use system_program_substream::{parse_block_with_options, resolve_pubkeys, ParseOptions};
+
+fn block() -> Block {
+    Block { transactions: vec![load_fixture("transfer"), load_fixture("transfer")], ..Default::default() }
+}
+
+#[test]
+fn interned_pubkeys_are_indexes_into_the_table() {
+    let options = ParseOptions { intern_pubkeys: true, ..ParseOptions::default() };
+    let output = parse_block_with_options(&block(), &options).unwrap();
+
+    // Both transactions reference the same pubkeys, stored once.
+    let table = &output.pubkey_table;
+    assert_eq!(table.iter().filter(|pubkey| **pubkey == key(1)).count(), 1);
+    for transaction in output.transactions.iter() {
+        assert!(transaction.fee_payer.is_empty());
+        assert_eq!(table[transaction.fee_payer_index.unwrap() as usize], key(1));
+        assert!(transaction.signers.is_empty());
+        assert_eq!(transaction.signer_indexes.len(), 1);
+        let Some(Event::Transfer(transfer)) = transaction.events[0].event.as_ref() else {
+            panic!("expected a transfer");
+        };
+        assert!(transfer.funding_account.is_empty());
+        assert!(transfer.recipient_account.is_empty());
+        assert_eq!(table[transfer.funding_account_index.unwrap() as usize], key(1));
+        assert_eq!(table[transfer.recipient_account_index.unwrap() as usize], key(2));
+    }
+}
+
+#[test]
+fn resolve_pubkeys_restores_the_inline_output() {
+    let inline = parse_block_with_options(&block(), &ParseOptions::default()).unwrap();
+    let options = ParseOptions { intern_pubkeys: true, ..ParseOptions::default() };
+    let mut interned = parse_block_with_options(&block(), &options).unwrap();
+    resolve_pubkeys(&mut interned).unwrap();
+    assert_eq!(interned, inline);
+}
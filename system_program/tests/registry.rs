@@ -0,0 +1,75 @@
+mod common;
+
+use common::load_fixture;
+use substreams_solana_utils::instruction::StructuredInstruction;
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana_utils::spl_token::TOKEN_PROGRAM_ID;
+use substreams_solana_utils::transaction::TransactionContext;
+use system_program_substream::pb::system_program::system_program_event::Event;
+use system_program_substream::token::TokenEvent;
+use system_program_substream::{default_registry, InstructionParser, ParseError, ParseOptions, RegistryEvent};
+
+/// Events of a pipeline decoding program X of `nested_cpi` (key 20) next to the built-in ones.
+#[derive(Debug, PartialEq)]
+enum MyEvent {
+    System(Event),
+    Token(TokenEvent),
+    XInvoke,
+}
+
+impl From<Event> for MyEvent {
+    fn from(event: Event) -> Self {
+        MyEvent::System(event)
+    }
+}
+
+impl From<TokenEvent> for MyEvent {
+    fn from(event: TokenEvent) -> Self {
+        MyEvent::Token(event)
+    }
+}
+
+/// Decodes tag 0 of X into `XInvoke`, and fails on any other tag.
+struct XParser;
+
+impl InstructionParser<MyEvent> for XParser {
+    fn program_id(&self) -> Pubkey {
+        Pubkey([20; 32])
+    }
+
+    fn parse(&self, instruction: &StructuredInstruction, _context: &TransactionContext) -> Result<Option<MyEvent>, ParseError> {
+        match instruction.data().first() {
+            Some(0) => Ok(Some(MyEvent::XInvoke)),
+            _ => Err(ParseError::InvalidInstruction("Unknown X instruction.".to_string())),
+        }
+    }
+}
+
+#[test]
+fn default_registry_has_system_and_token_parsers() {
+    let registry = default_registry::<RegistryEvent>(&ParseOptions::default());
+    assert!(registry.is_registered(&Pubkey([0; 32])));
+    assert!(registry.is_registered(&TOKEN_PROGRAM_ID));
+    assert!(!registry.is_registered(&Pubkey([20; 32])));
+
+    let events = registry.parse_transaction(&load_fixture("transfer")).unwrap();
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], (0, RegistryEvent::System(Event::Transfer(_)))));
+}
+
+#[test]
+fn custom_parser_next_to_built_in_ones() {
+    let mut registry = default_registry::<MyEvent>(&ParseOptions::default());
+    registry.register(XParser);
+    assert!(registry.is_registered(&TOKEN_PROGRAM_ID));
+
+    // X invokes with tag 0 at index 0 and creates an account with tag 1 at 5, which fails to
+    // decode without failing the other instructions. Y at 1 has no parser.
+    let (events, failed_instruction_indices) = registry.parse_transaction_isolated(&load_fixture("nested_cpi")).unwrap();
+    let indexes: Vec<u32> = events.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indexes, vec![0, 2, 3, 4, 6]);
+    assert_eq!(events[0].1, MyEvent::XInvoke);
+    assert!(matches!(events[1].1, MyEvent::System(Event::Transfer(_))));
+    assert!(matches!(events[4].1, MyEvent::System(Event::CreateAccount(_))));
+    assert_eq!(failed_instruction_indices, vec![5]);
+}
@@ -0,0 +1,206 @@
+mod common;
+
+use common::{fixture_events, key, load_fixture, single_event, system_program};
+use system_program_substream::decode::SystemInstruction;
+use system_program_substream::parse_transaction_full;
+use system_program_substream::pb::system_program::KnownProgram;
+use system_program_substream::pb::system_program::system_program_event::Event;
+
+#[test]
+fn create_account() {
+    let Event::CreateAccount(event) = single_event("create_account") else { panic!("Expected CreateAccount") };
+    assert_eq!(event.funding_account, key(1));
+    assert_eq!(event.new_account, key(2));
+    assert_eq!(event.lamports, 2_039_280);
+    assert_eq!(event.space, 165);
+    assert_eq!(event.owner, "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    assert_eq!(event.owner_program, KnownProgram::Token as i32);
+    assert_eq!(event.rent_exempt_minimum, 2_039_280);
+    assert!(event.is_rent_exempt);
+}
+
+#[test]
+fn assign() {
+    let Event::Assign(event) = single_event("assign") else { panic!("Expected Assign") };
+    assert_eq!(event.assigned_account, key(1));
+    assert_eq!(event.owner, key(9));
+}
+
+#[test]
+fn transfer() {
+    let Event::Transfer(event) = single_event("transfer") else { panic!("Expected Transfer") };
+    assert_eq!(event.funding_account, key(1));
+    assert_eq!(event.recipient_account, key(2));
+    assert_eq!(event.lamports, 1_000_000);
+    assert!(!event.is_wsol_wrap);
+    assert!(!event.is_jito_tip);
+}
+
+#[test]
+fn create_account_with_seed() {
+    let Event::CreateAccountWithSeed(event) = single_event("create_account_with_seed") else { panic!("Expected CreateAccountWithSeed") };
+    assert_eq!(event.funding_account, key(1));
+    assert_eq!(event.created_account, key(2));
+    assert_eq!(event.base_account, key(1));
+    assert_eq!(event.seed, "stake:0");
+    assert_eq!(event.seed_hex, "");
+    assert_eq!(event.lamports, 2_282_880);
+    assert_eq!(event.space, 200);
+    assert_eq!(event.owner, key(9));
+    assert_eq!(event.owner_program, KnownProgram::Unknown as i32);
+    assert!(event.is_rent_exempt);
+}
+
+#[test]
+fn advance_nonce_account() {
+    let Event::AdvanceNonceAccount(event) = single_event("advance_nonce_account") else { panic!("Expected AdvanceNonceAccount") };
+    assert_eq!(event.nonce_account, key(2));
+    assert_eq!(event.nonce_authority, key(1));
+}
+
+#[test]
+fn withdraw_nonce_account() {
+    let Event::WithdrawNonceAccount(event) = single_event("withdraw_nonce_account") else { panic!("Expected WithdrawNonceAccount") };
+    assert_eq!(event.nonce_account, key(2));
+    assert_eq!(event.recipient_account, key(3));
+    assert_eq!(event.nonce_authority, key(1));
+    assert_eq!(event.lamports, 1_000_000);
+}
+
+#[test]
+fn initialize_nonce_account() {
+    let events = fixture_events("initialize_nonce_account");
+    assert_eq!(events.len(), 2);
+    let Some(Event::CreateAccount(create_account)) = &events[0].event else { panic!("Expected CreateAccount") };
+    assert_eq!(create_account.new_account, key(2));
+    assert_eq!(create_account.owner, system_program());
+    let Some(Event::InitializeNonceAccount(event)) = &events[1].event else { panic!("Expected InitializeNonceAccount") };
+    assert_eq!(events[1].instruction_index, 1);
+    assert_eq!(event.nonce_account, key(2));
+    assert_eq!(event.nonce_authority, key(5));
+    assert!(event.canonical_layout);
+    assert_eq!(event.rent_payer, key(1));
+}
+
+#[test]
+fn authorize_nonce_account() {
+    let Event::AuthorizeNonceAccount(event) = single_event("authorize_nonce_account") else { panic!("Expected AuthorizeNonceAccount") };
+    assert_eq!(event.nonce_account, key(2));
+    assert_eq!(event.nonce_authority, key(1));
+    assert_eq!(event.new_nonce_authority, key(6));
+}
+
+#[test]
+fn authorize_nonce_account_truncated() {
+    let transaction = load_fixture("authorize_nonce_account_truncated");
+    let events = parse_transaction_full(&transaction, 0).unwrap().unwrap();
+    assert!(events.events.is_empty());
+    assert_eq!(events.failed_instruction_indices, vec![0]);
+}
+
+#[test]
+fn allocate() {
+    let Event::Allocate(event) = single_event("allocate") else { panic!("Expected Allocate") };
+    assert_eq!(event.account, key(1));
+    assert_eq!(event.space, 165);
+}
+
+#[test]
+fn allocate_with_seed() {
+    let Event::AllocateWithSeed(event) = single_event("allocate_with_seed") else { panic!("Expected AllocateWithSeed") };
+    assert_eq!(event.allocated_account, key(2));
+    assert_eq!(event.base_account, key(1));
+    assert_eq!(event.seed, "seed");
+    assert_eq!(event.space, 100);
+    assert_eq!(event.owner, key(9));
+}
+
+#[test]
+fn assign_with_seed() {
+    let Event::AssignWithSeed(event) = single_event("assign_with_seed") else { panic!("Expected AssignWithSeed") };
+    assert_eq!(event.assigned_account, key(2));
+    assert_eq!(event.base_account, key(1));
+    assert_eq!(event.owner, key(9));
+    // Not valid UTF-8, so the exact bytes are kept in hex.
+    assert_eq!(event.seed, "\u{FFFD}\u{0}seed");
+    assert_eq!(event.seed_hex, "ff0073656564");
+}
+
+#[test]
+fn transfer_with_seed() {
+    let Event::TransferWithSeed(event) = single_event("transfer_with_seed") else { panic!("Expected TransferWithSeed") };
+    assert_eq!(event.funding_account, key(2));
+    assert_eq!(event.base_account, key(1));
+    assert_eq!(event.recipient_account, key(3));
+    assert_eq!(event.from_seed, "seed");
+    assert_eq!(event.from_owner, system_program());
+    assert_eq!(event.lamports, 5_000);
+}
+
+#[test]
+fn upgrade_nonce_account() {
+    let Event::UpgradeNonceAccount(event) = single_event("upgrade_nonce_account") else { panic!("Expected UpgradeNonceAccount") };
+    assert_eq!(event.nonce_account, key(2));
+}
+
+#[test]
+fn unknown_discriminant() {
+    let Event::Unknown(event) = single_event("unknown_discriminant") else { panic!("Expected Unknown") };
+    assert_eq!(event.discriminant, 99);
+    assert_eq!(event.data, vec![99, 0, 0, 0, 1, 2, 3]);
+    assert_eq!(event.accounts, vec![key(1), key(2)]);
+}
+
+/// Data of the single instruction of the fixture `name`.
+fn instruction_data(name: &str) -> Vec<u8> {
+    let transaction = load_fixture(name);
+    transaction.transaction.unwrap().message.unwrap().instructions[0].data.clone()
+}
+
+#[test]
+fn create_account_with_seed_round_trip() {
+    let data = instruction_data("create_account_with_my_seed");
+    let Ok(SystemInstruction::CreateAccountWithSeed { seed, .. }) = SystemInstruction::unpack(&data) else { panic!("Expected CreateAccountWithSeed") };
+    assert_eq!(seed, b"mySeed");
+    let Event::CreateAccountWithSeed(event) = single_event("create_account_with_my_seed") else { panic!("Expected CreateAccountWithSeed") };
+    assert_eq!(event.seed, "mySeed");
+    assert_eq!(event.seed_hex, "");
+    assert_eq!(event.base_account, key(1));
+    assert_eq!(event.lamports, 2_282_880);
+}
+
+#[test]
+fn allocate_with_seed_round_trip() {
+    let data = instruction_data("allocate_with_my_seed");
+    let Ok(SystemInstruction::AllocateWithSeed { seed, .. }) = SystemInstruction::unpack(&data) else { panic!("Expected AllocateWithSeed") };
+    assert_eq!(seed, b"mySeed");
+    let Event::AllocateWithSeed(event) = single_event("allocate_with_my_seed") else { panic!("Expected AllocateWithSeed") };
+    assert_eq!(event.seed, "mySeed");
+    assert_eq!(event.seed_hex, "");
+    assert_eq!(event.base_account, key(1));
+    assert_eq!(event.space, 100);
+}
+
+#[test]
+fn assign_with_seed_round_trip() {
+    let data = instruction_data("assign_with_my_seed");
+    let Ok(SystemInstruction::AssignWithSeed { seed, .. }) = SystemInstruction::unpack(&data) else { panic!("Expected AssignWithSeed") };
+    assert_eq!(seed, b"mySeed");
+    let Event::AssignWithSeed(event) = single_event("assign_with_my_seed") else { panic!("Expected AssignWithSeed") };
+    assert_eq!(event.seed, "mySeed");
+    assert_eq!(event.seed_hex, "");
+    assert_eq!(event.base_account, key(1));
+    assert_eq!(event.owner, key(9));
+}
+
+#[test]
+fn transfer_with_seed_round_trip() {
+    let data = instruction_data("transfer_with_my_seed");
+    let Ok(SystemInstruction::TransferWithSeed { from_seed, .. }) = SystemInstruction::unpack(&data) else { panic!("Expected TransferWithSeed") };
+    assert_eq!(from_seed, b"mySeed");
+    let Event::TransferWithSeed(event) = single_event("transfer_with_my_seed") else { panic!("Expected TransferWithSeed") };
+    assert_eq!(event.from_seed, "mySeed");
+    assert_eq!(event.from_seed_hex, "");
+    assert_eq!(event.from_owner, system_program());
+    assert_eq!(event.lamports, 5_000);
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::load_fixture;
+use system_program_substream::compute_budget::{self, ComputeBudgetInstruction};
+use system_program_substream::parse_transaction_full;
+use system_program_substream::pb::system_program::{SetComputeUnitLimitEvent, SetComputeUnitPriceEvent};
+use system_program_substream::pb::system_program::compute_budget_event::Event;
+
+#[test]
+fn compute_units_missing_before_feature() {
+    let transaction_events = parse_transaction_full(&load_fixture("transfer"), 0).unwrap().unwrap();
+    assert_eq!(transaction_events.compute_units_consumed, None);
+    assert!(!transaction_events.has_return_data);
+}
+
+#[test]
+fn compute_units_after_feature() {
+    let transaction_events = parse_transaction_full(&load_fixture("compute_units_consumed"), 0).unwrap().unwrap();
+    assert_eq!(transaction_events.compute_units_consumed, Some(150));
+    assert!(transaction_events.has_return_data);
+    assert_eq!(transaction_events.events.len(), 1);
+}
+
+#[test]
+fn compute_unit_limit_and_price() {
+    let transaction_events = parse_transaction_full(&load_fixture("compute_budget"), 0).unwrap().unwrap();
+    assert_eq!(transaction_events.compute_unit_limit, Some(200_000));
+    assert_eq!(transaction_events.compute_unit_price_micro_lamports, Some(10_000));
+    assert_eq!(transaction_events.events.len(), 1);
+}
+
+#[test]
+fn compute_budget_events() {
+    let events = compute_budget::parse_transaction(&load_fixture("compute_budget")).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].instruction_index, 0);
+    assert_eq!(events[0].event, Some(Event::SetComputeUnitLimit(SetComputeUnitLimitEvent { units: 200_000 })));
+    assert_eq!(events[1].instruction_index, 1);
+    assert_eq!(events[1].event, Some(Event::SetComputeUnitPrice(SetComputeUnitPriceEvent { micro_lamports: 10_000 })));
+}
+
+#[test]
+fn compute_budget_without_instructions() {
+    let transaction_events = parse_transaction_full(&load_fixture("transfer"), 0).unwrap().unwrap();
+    assert_eq!(transaction_events.compute_unit_limit, None);
+    assert_eq!(transaction_events.compute_unit_price_micro_lamports, None);
+}
+
+#[test]
+fn truncated_compute_unit_price_is_rejected() {
+    assert!(ComputeBudgetInstruction::unpack(&[3, 0x10, 0x27, 0, 0]).is_err());
+}
@@ -0,0 +1,128 @@
+//! Decoding of System Program instruction data, independent of substreams.
+//!
+//! The crate has no dependencies and is `no_std`, and decoded values borrow from the instruction
+//! data, so it can be used on its own, e.g. in a native indexer. Resolving accounts and building
+//! events is left to `system-program-substream`. The `std` feature implements
+//! `std::error::Error` for `DecodeError`.
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+
+/// Discriminant of the last instruction variant known to this module (`UpgradeNonceAccount`).
+pub const MAX_KNOWN_DISCRIMINANT: u32 = 12;
+
+/// A decoded System Program instruction. Seeds are the raw bytes of the bincode string, which
+/// isn't guaranteed to be valid UTF-8.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SystemInstruction<'a> {
+    CreateAccount { lamports: u64, space: u64, owner: [u8; 32] },
+    Assign { owner: [u8; 32] },
+    Transfer { lamports: u64 },
+    CreateAccountWithSeed { base: [u8; 32], seed: &'a [u8], lamports: u64, space: u64, owner: [u8; 32] },
+    AdvanceNonceAccount,
+    WithdrawNonceAccount { lamports: u64 },
+    InitializeNonceAccount { authority: [u8; 32] },
+    AuthorizeNonceAccount { new_authority: [u8; 32] },
+    Allocate { space: u64 },
+    AllocateWithSeed { base: [u8; 32], seed: &'a [u8], space: u64, owner: [u8; 32] },
+    AssignWithSeed { base: [u8; 32], seed: &'a [u8], owner: [u8; 32] },
+    TransferWithSeed { lamports: u64, from_seed: &'a [u8], from_owner: [u8; 32] },
+    UpgradeNonceAccount,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data ends before the field at this offset.
+    UnexpectedEnd { offset: usize },
+    UnknownDiscriminant(u32),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd { offset } => write!(f, "Instruction data ends at offset {}.", offset),
+            DecodeError::UnknownDiscriminant(discriminant) => write!(f, "Unknown instruction discriminant {}.", discriminant),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+impl<'a> SystemInstruction<'a> {
+    /// Decodes the bincode encoding used on-chain: a little-endian `u32` discriminant followed
+    /// by the fields, strings prefixed with a `u64` length. Trailing bytes are ignored, as the
+    /// runtime does.
+    pub fn unpack(data: &'a [u8]) -> Result<SystemInstruction<'a>, DecodeError> {
+        let mut reader = Reader { data, offset: 0 };
+        let instruction = match reader.u32()? {
+            0 => SystemInstruction::CreateAccount { lamports: reader.u64()?, space: reader.u64()?, owner: reader.pubkey()? },
+            1 => SystemInstruction::Assign { owner: reader.pubkey()? },
+            2 => SystemInstruction::Transfer { lamports: reader.u64()? },
+            3 => SystemInstruction::CreateAccountWithSeed {
+                base: reader.pubkey()?,
+                seed: reader.string()?,
+                lamports: reader.u64()?,
+                space: reader.u64()?,
+                owner: reader.pubkey()?,
+            },
+            4 => SystemInstruction::AdvanceNonceAccount,
+            5 => SystemInstruction::WithdrawNonceAccount { lamports: reader.u64()? },
+            6 => SystemInstruction::InitializeNonceAccount { authority: reader.pubkey()? },
+            7 => SystemInstruction::AuthorizeNonceAccount { new_authority: reader.pubkey()? },
+            8 => SystemInstruction::Allocate { space: reader.u64()? },
+            9 => SystemInstruction::AllocateWithSeed {
+                base: reader.pubkey()?,
+                seed: reader.string()?,
+                space: reader.u64()?,
+                owner: reader.pubkey()?,
+            },
+            10 => SystemInstruction::AssignWithSeed { base: reader.pubkey()?, seed: reader.string()?, owner: reader.pubkey()? },
+            11 => SystemInstruction::TransferWithSeed { lamports: reader.u64()?, from_seed: reader.string()?, from_owner: reader.pubkey()? },
+            12 => SystemInstruction::UpgradeNonceAccount,
+            discriminant => return Err(DecodeError::UnknownDiscriminant(discriminant)),
+        };
+        Ok(instruction)
+    }
+}
+
+/// The discriminant of `data`, or `None` when it holds fewer than 4 bytes.
+pub fn discriminant(data: &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(0..4)?.try_into().ok()?))
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self.offset.checked_add(len).filter(|end| *end <= self.data.len())
+            .ok_or(DecodeError::UnexpectedEnd { offset: self.offset })?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn pubkey(&mut self) -> Result<[u8; 32], DecodeError> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    fn string(&mut self) -> Result<&'a [u8], DecodeError> {
+        let offset = self.offset;
+        let len = usize::try_from(self.u64()?).map_err(|_| DecodeError::UnexpectedEnd { offset })?;
+        self.take(len)
+    }
+}
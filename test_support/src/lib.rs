@@ -0,0 +1,129 @@
+//! Builder for the `ConfirmedTransaction` fixtures of the integration tests, shared by the
+//! program crates as a dev-dependency.
+
+use substreams_solana::pb::sf::solana::r#type::v1::{
+    CompiledInstruction, ConfirmedTransaction, InnerInstruction, InnerInstructions, Message, MessageHeader,
+    TokenBalance, Transaction, TransactionStatusMeta, UiTokenAmount,
+};
+
+/// The `[n; 32]` fixture key.
+pub fn key(n: u8) -> Vec<u8> {
+    vec![n; 32]
+}
+
+/// Base58 of the `[n; 32]` fixture key.
+pub fn address(n: u8) -> String {
+    bs58::encode([n; 32]).into_string()
+}
+
+/// A successful transaction signed by its first account key. Inner instructions are attached to
+/// the last top-level instruction, and the invoke/success logs are derived from the call tree.
+#[derive(Default)]
+pub struct TransactionBuilder {
+    account_keys: Vec<Vec<u8>>,
+    instructions: Vec<CompiledInstruction>,
+    inner_instructions: Vec<InnerInstructions>,
+    pre_token_balances: Vec<TokenBalance>,
+    post_token_balances: Vec<TokenBalance>,
+}
+
+impl TransactionBuilder {
+    pub fn new(account_keys: Vec<Vec<u8>>) -> Self {
+        TransactionBuilder { account_keys, ..Default::default() }
+    }
+
+    pub fn instruction(mut self, program_id_index: u32, accounts: &[u8], data: Vec<u8>) -> Self {
+        self.instructions.push(CompiledInstruction { program_id_index, accounts: accounts.to_vec(), data });
+        self
+    }
+
+    pub fn inner_instruction(mut self, program_id_index: u32, accounts: &[u8], data: Vec<u8>, stack_height: u32) -> Self {
+        let index = self.instructions.len() as u32 - 1;
+        if self.inner_instructions.last().map_or(true, |inner| inner.index != index) {
+            self.inner_instructions.push(InnerInstructions { index, instructions: Vec::new() });
+        }
+        self.inner_instructions.last_mut().unwrap().instructions.push(InnerInstruction {
+            program_id_index,
+            accounts: accounts.to_vec(),
+            data,
+            stack_height: Some(stack_height),
+        });
+        self
+    }
+
+    pub fn pre_token_balance(mut self, account_index: u32, mint: u8, owner: u8, amount: u64, decimals: u32) -> Self {
+        self.pre_token_balances.push(token_balance(account_index, mint, owner, amount, decimals));
+        self
+    }
+
+    pub fn post_token_balance(mut self, account_index: u32, mint: u8, owner: u8, amount: u64, decimals: u32) -> Self {
+        self.post_token_balances.push(token_balance(account_index, mint, owner, amount, decimals));
+        self
+    }
+
+    pub fn build(self) -> ConfirmedTransaction {
+        let log_messages = self.log_messages();
+        let balances = vec![1_000_000_000; self.account_keys.len()];
+        ConfirmedTransaction {
+            transaction: Some(Transaction {
+                signatures: vec![vec![1; 64]],
+                message: Some(Message {
+                    header: Some(MessageHeader { num_required_signatures: 1, ..Default::default() }),
+                    account_keys: self.account_keys,
+                    recent_blockhash: vec![7; 32],
+                    instructions: self.instructions,
+                    ..Default::default()
+                }),
+            }),
+            meta: Some(TransactionStatusMeta {
+                fee: 5000,
+                pre_balances: balances.clone(),
+                post_balances: balances,
+                inner_instructions: self.inner_instructions,
+                log_messages,
+                pre_token_balances: self.pre_token_balances,
+                post_token_balances: self.post_token_balances,
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn log_messages(&self) -> Vec<String> {
+        let program_id = |index: u32| bs58::encode(&self.account_keys[index as usize]).into_string();
+        let mut logs = Vec::new();
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            let mut stack = vec![program_id(instruction.program_id_index)];
+            logs.push(format!("Program {} invoke [1]", stack[0]));
+            let inner = self.inner_instructions.iter()
+                .filter(|inner| inner.index as usize == i)
+                .flat_map(|inner| inner.instructions.iter());
+            for instruction in inner {
+                let stack_height = instruction.stack_height.unwrap_or(2) as usize;
+                while stack.len() >= stack_height {
+                    logs.push(format!("Program {} success", stack.pop().unwrap()));
+                }
+                stack.push(program_id(instruction.program_id_index));
+                logs.push(format!("Program {} invoke [{}]", stack.last().unwrap(), stack_height));
+            }
+            while let Some(program_id) = stack.pop() {
+                logs.push(format!("Program {} success", program_id));
+            }
+        }
+        logs
+    }
+}
+
+fn token_balance(account_index: u32, mint: u8, owner: u8, amount: u64, decimals: u32) -> TokenBalance {
+    TokenBalance {
+        account_index,
+        mint: address(mint),
+        owner: address(owner),
+        ui_token_amount: Some(UiTokenAmount {
+            ui_amount: amount as f64 / 10f64.powi(decimals as i32),
+            decimals,
+            amount: amount.to_string(),
+            ui_amount_string: String::new(),
+        }),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,707 @@
+use anyhow::{anyhow, Context, Error};
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::transaction::{get_context, TransactionContext};
+use utils::pubkey::Pubkey;
+
+pub mod token_2022;
+use token_2022::TOKEN_2022_PROGRAM_ID;
+use token_2022::instruction::{
+    Token2022Instruction,
+    TransferFeeInstruction,
+    MetadataPointerInstruction,
+    TokenMetadataInstruction,
+    TokenMetadataField,
+};
+
+pub mod pb;
+use pb::token_2022::*;
+use pb::token_2022::token2022_event::Event;
+
+#[substreams::handlers::map]
+fn token_2022_events(block: Block) -> Result<Token2022BlockEvents, Error> {
+    Ok(Token2022BlockEvents { transactions: parse_block(&block)? })
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block.
+pub fn parse_block(block: &Block) -> Result<Vec<Token2022TransactionEvents>, Error> {
+    let mut transactions_events: Vec<Token2022TransactionEvents> = Vec::new();
+    for transaction in block.transactions() {
+        let events = match parse_transaction(transaction) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        if !events.is_empty() {
+            transactions_events.push(Token2022TransactionEvents {
+                signature: utils::transaction::get_signature(&transaction),
+                events
+            })
+        }
+    }
+    Ok(transactions_events)
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Token2022Event>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new())
+    }
+
+    let mut events: Vec<Token2022Event> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened().iter().enumerate() {
+        if instruction.program_id() != TOKEN_2022_PROGRAM_ID {
+            continue;
+        }
+        let event = parse_instruction(instruction, &context)
+            .with_context(|| format!("Failed to parse transaction {}", context.signature))?;
+        if event.is_some() {
+            events.push(Token2022Event { instruction_index: i as u32, event });
+        }
+    }
+
+    Ok(events)
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Option<Event>, Error> {
+    if instruction.program_id() != TOKEN_2022_PROGRAM_ID {
+        return Err(anyhow!("Not a Token-2022 program instruction"));
+    }
+
+    let unpacked = Token2022Instruction::unpack(&instruction.data())
+        .map_err(|x| anyhow!(x).context("Failed to unpack Token-2022 instruction"))?;
+    match unpacked {
+        Token2022Instruction::InitializeMint { decimals, mint_authority, freeze_authority } |
+        Token2022Instruction::InitializeMint2 { decimals, mint_authority, freeze_authority } => {
+            let event = _parse_initialize_mint_instruction(instruction, context, decimals as u32, mint_authority, freeze_authority);
+            event.map(|x| Some(Event::InitializeMint(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeAccount => {
+            let event = _parse_initialize_account_instruction(instruction, context, None);
+            event.map(|x| Some(Event::InitializeAccount(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::InitializeAccount2 { owner } |
+        Token2022Instruction::InitializeAccount3 { owner } => {
+            let event = _parse_initialize_account_instruction(instruction, context, Some(owner));
+            event.map(|x| Some(Event::InitializeAccount(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeMultisig { m } => {
+            let event = _parse_initialize_multisig_instruction(instruction, context, m, true);
+            event.map(|x| Some(Event::InitializeMultisig(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::InitializeMultisig2 { m } => {
+            let event = _parse_initialize_multisig_instruction(instruction, context, m, false);
+            event.map(|x| Some(Event::InitializeMultisig(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::Transfer { amount } => {
+            let event = _parse_transfer_instruction(instruction, context, amount, None);
+            event.map(|x| Some(Event::Transfer(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TransferChecked { amount, decimals } => {
+            let event = _parse_transfer_instruction(instruction, context, amount, Some(decimals));
+            event.map(|x| Some(Event::Transfer(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::Approve { amount } => {
+            let event = _parse_approve_instruction(instruction, context, amount, false);
+            event.map(|x| Some(Event::Approve(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::ApproveChecked { amount, decimals: _ } => {
+            let event = _parse_approve_instruction(instruction, context, amount, true);
+            event.map(|x| Some(Event::Approve(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::Revoke => {
+            let event = _parse_revoke_instruction(instruction, context);
+            event.map(|x| Some(Event::Revoke(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::SetAuthority { authority_type, new_authority } => {
+            let event = _parse_set_authority_instruction(instruction, context, authority_type, new_authority);
+            event.map(|x| Some(Event::SetAuthority(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::MintTo { amount } |
+        Token2022Instruction::MintToChecked { amount, decimals: _ } => {
+            let event = _parse_mint_to_instruction(instruction, context, amount);
+            event.map(|x| Some(Event::MintTo(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::Burn { amount } |
+        Token2022Instruction::BurnChecked { amount, decimals: _ } => {
+            let event = _parse_burn_instruction(instruction, context, amount);
+            event.map(|x| Some(Event::Burn(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::CloseAccount => {
+            let event = _parse_close_account_instruction(instruction, context);
+            event.map(|x| Some(Event::CloseAccount(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::FreezeAccount => {
+            let event = _parse_freeze_account_instruction(instruction, context);
+            event.map(|x| Some(Event::FreezeAccount(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::ThawAccount => {
+            let event = _parse_thaw_account_instruction(instruction, context);
+            event.map(|x| Some(Event::ThawAccount(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeImmutableOwner => {
+            let event = _parse_initialize_immutable_owner_instruction(instruction, context);
+            event.map(|x| Some(Event::InitializeImmutableOwner(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::SyncNative => {
+            let event = _parse_sync_native_instruction(instruction, context);
+            event.map(|x| Some(Event::SyncNative(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeMintCloseAuthority { close_authority } => {
+            let event = _parse_initialize_mint_close_authority_instruction(instruction, context, close_authority);
+            event.map(|x| Some(Event::InitializeMintCloseAuthority(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::TransferFeeExtension(TransferFeeInstruction::InitializeTransferFeeConfig {
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        }) => {
+            let event = _parse_initialize_transfer_fee_config_instruction(
+                instruction,
+                context,
+                transfer_fee_config_authority,
+                withdraw_withheld_authority,
+                transfer_fee_basis_points,
+                maximum_fee,
+            );
+            event.map(|x| Some(Event::InitializeTransferFeeConfig(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TransferFeeExtension(TransferFeeInstruction::TransferCheckedWithFee { amount, decimals, fee }) => {
+            let event = _parse_transfer_checked_with_fee_instruction(instruction, context, amount, decimals, fee);
+            event.map(|x| Some(Event::TransferCheckedWithFee(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TransferFeeExtension(TransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts }) => {
+            let event = _parse_withdraw_withheld_tokens_from_accounts_instruction(instruction, context, num_token_accounts);
+            event.map(|x| Some(Event::WithdrawWithheldTokensFromAccounts(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TransferFeeExtension(_) => Ok(None),
+
+        Token2022Instruction::MetadataPointerExtension(MetadataPointerInstruction::Initialize { authority, metadata_address }) => {
+            let event = _parse_initialize_metadata_pointer_instruction(instruction, context, authority, metadata_address);
+            event.map(|x| Some(Event::InitializeMetadataPointer(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::MetadataPointerExtension(MetadataPointerInstruction::Update { metadata_address }) => {
+            let event = _parse_update_metadata_pointer_instruction(instruction, context, metadata_address);
+            event.map(|x| Some(Event::UpdateMetadataPointer(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::MetadataPointerExtension(MetadataPointerInstruction::Unsupported(_)) => Ok(None),
+
+        Token2022Instruction::TokenMetadata(TokenMetadataInstruction::Initialize(initialize)) => {
+            let event = _parse_initialize_token_metadata_instruction(instruction, context, initialize);
+            event.map(|x| Some(Event::InitializeTokenMetadata(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TokenMetadata(TokenMetadataInstruction::UpdateField(update_field)) => {
+            let event = _parse_update_token_metadata_field_instruction(instruction, context, update_field);
+            event.map(|x| Some(Event::UpdateTokenMetadataField(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TokenMetadata(TokenMetadataInstruction::RemoveKey(remove_key)) => {
+            let event = _parse_remove_token_metadata_key_instruction(instruction, context, remove_key);
+            event.map(|x| Some(Event::RemoveTokenMetadataKey(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TokenMetadata(TokenMetadataInstruction::UpdateAuthority(update_authority)) => {
+            let event = _parse_update_token_metadata_authority_instruction(instruction, context, update_authority);
+            event.map(|x| Some(Event::UpdateTokenMetadataAuthority(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::TokenMetadata(TokenMetadataInstruction::Emit(emit)) => {
+            let event = _parse_emit_token_metadata_instruction(instruction, context, emit);
+            event.map(|x| Some(Event::EmitTokenMetadata(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::GetAccountDataSize |
+        Token2022Instruction::AmountToUiAmount { amount: _ } |
+        Token2022Instruction::UiAmountToAmount |
+        Token2022Instruction::UnsupportedExtension(_) => Ok(None),
+    }.context("Failed to parse Token-2022 instruction")
+}
+
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, &'static str> {
+    instruction.accounts().get(index).cloned().ok_or("Instruction is missing accounts.")
+}
+
+fn _parse_initialize_mint_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    decimals: u32,
+    mint_authority: Pubkey,
+    freeze_authority: Option<Pubkey>,
+) -> Result<InitializeMintEvent, &'static str> {
+    let mint = _get_account(instruction, 0)?.to_string();
+    let mint_authority = mint_authority.to_string();
+    let freeze_authority = freeze_authority.map(|x| x.to_string());
+
+    Ok(InitializeMintEvent {
+        mint,
+        decimals,
+        mint_authority,
+        freeze_authority,
+    })
+}
+
+fn _parse_initialize_account_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    owner: Option<Pubkey>,
+) -> Result<InitializeAccountEvent, &'static str> {
+    let account = _get_account(instruction, 0)?.to_string();
+    let mint = _get_account(instruction, 1)?.to_string();
+    let owner = match owner {
+        Some(owner) => owner.to_string(),
+        None => _get_account(instruction, 2)?.to_string(),
+    };
+
+    Ok(InitializeAccountEvent {
+        account,
+        mint,
+        owner,
+    })
+}
+
+fn _parse_initialize_multisig_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    m: u8,
+    rent_sysvar_account: bool,
+) -> Result<InitializeMultisigEvent, &'static str> {
+    let multisig = _get_account(instruction, 0)?.to_string();
+    let delta = if rent_sysvar_account { 2 } else { 1 };
+    let signers = instruction.accounts().get(delta..).ok_or("Instruction is missing accounts.")?
+        .iter()
+        .map(|account| account.to_string())
+        .collect();
+
+    Ok(InitializeMultisigEvent {
+        multisig,
+        signers,
+        m: m.into(),
+    })
+}
+
+fn _parse_transfer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    amount: u64,
+    expected_decimals: Option<u8>,
+) -> Result<TransferEvent, &'static str> {
+    let delta: usize = if expected_decimals.is_none() { 0 } else { 1 };
+    let source = _get_account(instruction, 0)?.to_string();
+    let mint = if expected_decimals.is_some() { Some(_get_account(instruction, 1)?.to_string()) } else { None };
+    let destination = _get_account(instruction, 1 + delta)?.to_string();
+    let authority = _get_account(instruction, 2 + delta)?.to_string();
+
+    Ok(TransferEvent {
+        source,
+        destination,
+        authority,
+        amount,
+        mint,
+        decimals: expected_decimals.map(u32::from),
+    })
+}
+
+fn _parse_approve_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    amount: u64,
+    checked: bool,
+) -> Result<ApproveEvent, &'static str> {
+    let delta: usize = if checked { 1 } else { 0 };
+    let source = _get_account(instruction, 0)?.to_string();
+    let delegate = _get_account(instruction, 1 + delta)?.to_string();
+    let owner = _get_account(instruction, 2 + delta)?.to_string();
+
+    Ok(ApproveEvent {
+        source,
+        delegate,
+        owner,
+        amount,
+    })
+}
+
+fn _parse_revoke_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<RevokeEvent, &'static str> {
+    let source = _get_account(instruction, 0)?.to_string();
+    let owner = _get_account(instruction, 1)?.to_string();
+
+    Ok(RevokeEvent {
+        source,
+        owner,
+    })
+}
+
+fn _parse_set_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority_type: token_2022::instruction::AuthorityType,
+    new_authority: Option<Pubkey>,
+) -> Result<SetAuthorityEvent, &'static str> {
+    use token_2022::instruction::AuthorityType as Instruction;
+
+    let account = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+    let authority_type: i32 = match authority_type {
+        Instruction::MintTokens => AuthorityType::MintTokens.into(),
+        Instruction::FreezeAccount => AuthorityType::FreezeAccount.into(),
+        Instruction::AccountOwner => AuthorityType::AccountOwner.into(),
+        Instruction::CloseAccount => AuthorityType::CloseAccount.into(),
+        Instruction::TransferFeeConfig => AuthorityType::TransferFeeConfig.into(),
+        Instruction::WithheldWithdraw => AuthorityType::WithheldWithdraw.into(),
+        Instruction::CloseMint => AuthorityType::CloseMint.into(),
+        Instruction::InterestRate => AuthorityType::InterestRate.into(),
+        Instruction::PermanentDelegate => AuthorityType::PermanentDelegate.into(),
+        Instruction::ConfidentialTransferMint => AuthorityType::ConfidentialTransferMint.into(),
+        Instruction::TransferHookProgramId => AuthorityType::TransferHookProgramId.into(),
+        Instruction::ConfidentialTransferFeeConfig => AuthorityType::ConfidentialTransferFeeConfig.into(),
+        Instruction::MetadataPointer => AuthorityType::MetadataPointer.into(),
+        Instruction::GroupPointer => AuthorityType::GroupPointer.into(),
+        Instruction::GroupMemberPointer => AuthorityType::GroupMemberPointer.into(),
+        Instruction::ScaledUiAmount => AuthorityType::ScaledUiAmount.into(),
+        Instruction::Pause => AuthorityType::Pause.into(),
+        Instruction::Unknown(value) => value.into(),
+    };
+    let new_authority = new_authority.map(|x| x.to_string());
+
+    Ok(SetAuthorityEvent {
+        account,
+        authority,
+        authority_type,
+        new_authority,
+    })
+}
+
+fn _parse_mint_to_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    amount: u64,
+) -> Result<MintToEvent, &'static str> {
+    let mint = _get_account(instruction, 0)?.to_string();
+    let destination = _get_account(instruction, 1)?.to_string();
+    let mint_authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(MintToEvent {
+        mint,
+        mint_authority,
+        destination,
+        amount,
+    })
+}
+
+fn _parse_burn_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    amount: u64,
+) -> Result<BurnEvent, &'static str> {
+    let source = _get_account(instruction, 0)?.to_string();
+    let mint = _get_account(instruction, 1)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(BurnEvent {
+        source,
+        mint,
+        authority,
+        amount,
+    })
+}
+
+fn _parse_close_account_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<CloseAccountEvent, &'static str> {
+    let source = _get_account(instruction, 0)?.to_string();
+    let destination = _get_account(instruction, 1)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(CloseAccountEvent {
+        source,
+        destination,
+        authority,
+    })
+}
+
+fn _parse_freeze_account_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<FreezeAccountEvent, &'static str> {
+    let source = _get_account(instruction, 0)?.to_string();
+    let mint = _get_account(instruction, 1)?.to_string();
+    let freeze_authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(FreezeAccountEvent {
+        source,
+        mint,
+        freeze_authority,
+    })
+}
+
+fn _parse_thaw_account_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<ThawAccountEvent, &'static str> {
+    let source = _get_account(instruction, 0)?.to_string();
+    let mint = _get_account(instruction, 1)?.to_string();
+    let freeze_authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(ThawAccountEvent {
+        source,
+        mint,
+        freeze_authority,
+    })
+}
+
+fn _parse_initialize_immutable_owner_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<InitializeImmutableOwnerEvent, &'static str> {
+    let account = _get_account(instruction, 0)?.to_string();
+
+    Ok(InitializeImmutableOwnerEvent {
+        account,
+    })
+}
+
+fn _parse_sync_native_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<SyncNativeEvent, &'static str> {
+    let account = _get_account(instruction, 0)?.to_string();
+
+    Ok(SyncNativeEvent {
+        account,
+    })
+}
+
+fn _parse_initialize_mint_close_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    close_authority: Option<Pubkey>,
+) -> Result<InitializeMintCloseAuthorityEvent, &'static str> {
+    let mint = _get_account(instruction, 0)?.to_string();
+    let close_authority = close_authority.map(|x| x.to_string());
+
+    Ok(InitializeMintCloseAuthorityEvent {
+        mint,
+        close_authority,
+    })
+}
+
+fn _parse_initialize_transfer_fee_config_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    transfer_fee_config_authority: Option<Pubkey>,
+    withdraw_withheld_authority: Option<Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<InitializeTransferFeeConfigEvent, &'static str> {
+    let mint = _get_account(instruction, 0)?.to_string();
+
+    Ok(InitializeTransferFeeConfigEvent {
+        mint,
+        transfer_fee_config_authority: transfer_fee_config_authority.map(|x| x.to_string()),
+        withdraw_withheld_authority: withdraw_withheld_authority.map(|x| x.to_string()),
+        transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        maximum_fee,
+    })
+}
+
+fn _parse_transfer_checked_with_fee_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+) -> Result<TransferCheckedWithFeeEvent, &'static str> {
+    let source = _get_account(instruction, 0)?.to_string();
+    let mint = _get_account(instruction, 1)?.to_string();
+    let destination = _get_account(instruction, 2)?.to_string();
+    let authority = _get_account(instruction, 3)?.to_string();
+
+    Ok(TransferCheckedWithFeeEvent {
+        source,
+        mint,
+        destination,
+        authority,
+        amount,
+        decimals: decimals.into(),
+        fee,
+    })
+}
+
+fn _parse_withdraw_withheld_tokens_from_accounts_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    num_token_accounts: u8,
+) -> Result<WithdrawWithheldTokensFromAccountsEvent, &'static str> {
+    let mint = _get_account(instruction, 0)?.to_string();
+    let destination = _get_account(instruction, 1)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
+    // Multisig signers, if any, sit between the authority and the source accounts.
+    let accounts = instruction.accounts();
+    let sources_start = accounts.len().checked_sub(num_token_accounts as usize)
+        .filter(|start| *start >= 3)
+        .ok_or("Instruction is missing accounts.")?;
+    let sources = accounts[sources_start..].iter().map(|account| account.to_string()).collect();
+
+    Ok(WithdrawWithheldTokensFromAccountsEvent {
+        mint,
+        destination,
+        authority,
+        sources,
+    })
+}
+
+fn _parse_initialize_metadata_pointer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Option<Pubkey>,
+    metadata_address: Option<Pubkey>,
+) -> Result<InitializeMetadataPointerEvent, &'static str> {
+    let mint = _get_account(instruction, 0)?.to_string();
+
+    Ok(InitializeMetadataPointerEvent {
+        mint,
+        authority: authority.map(|x| x.to_string()),
+        metadata_address: metadata_address.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_update_metadata_pointer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    metadata_address: Option<Pubkey>,
+) -> Result<UpdateMetadataPointerEvent, &'static str> {
+    let mint = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 1)?.to_string();
+
+    Ok(UpdateMetadataPointerEvent {
+        mint,
+        authority,
+        metadata_address: metadata_address.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_initialize_token_metadata_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    initialize: token_2022::instruction::InitializeTokenMetadata,
+) -> Result<InitializeTokenMetadataEvent, &'static str> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let update_authority = _get_account(instruction, 1)?.to_string();
+    let mint = _get_account(instruction, 2)?.to_string();
+    let mint_authority = _get_account(instruction, 3)?.to_string();
+
+    Ok(InitializeTokenMetadataEvent {
+        metadata,
+        update_authority,
+        mint,
+        mint_authority,
+        name: initialize.name,
+        symbol: initialize.symbol,
+        uri: initialize.uri,
+    })
+}
+
+fn _parse_update_token_metadata_field_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    update_field: token_2022::instruction::UpdateTokenMetadataField,
+) -> Result<UpdateTokenMetadataFieldEvent, &'static str> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let update_authority = _get_account(instruction, 1)?.to_string();
+    let field = match update_field.field {
+        TokenMetadataField::Name => "name".to_string(),
+        TokenMetadataField::Symbol => "symbol".to_string(),
+        TokenMetadataField::Uri => "uri".to_string(),
+        TokenMetadataField::Key(key) => key,
+    };
+
+    Ok(UpdateTokenMetadataFieldEvent {
+        metadata,
+        update_authority,
+        field,
+        value: update_field.value,
+    })
+}
+
+fn _parse_remove_token_metadata_key_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    remove_key: token_2022::instruction::RemoveTokenMetadataKey,
+) -> Result<RemoveTokenMetadataKeyEvent, &'static str> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let update_authority = _get_account(instruction, 1)?.to_string();
+
+    Ok(RemoveTokenMetadataKeyEvent {
+        metadata,
+        update_authority,
+        key: remove_key.key,
+        idempotent: remove_key.idempotent,
+    })
+}
+
+fn _parse_update_token_metadata_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    update_authority: token_2022::instruction::UpdateTokenMetadataAuthority,
+) -> Result<UpdateTokenMetadataAuthorityEvent, &'static str> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+    let current_update_authority = _get_account(instruction, 1)?.to_string();
+    let new_update_authority = Some(update_authority.new_authority)
+        .filter(|new_authority| *new_authority != [0; 32])
+        .map(|new_authority| Pubkey(new_authority).to_string());
+
+    Ok(UpdateTokenMetadataAuthorityEvent {
+        metadata,
+        update_authority: current_update_authority,
+        new_update_authority,
+    })
+}
+
+fn _parse_emit_token_metadata_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    emit: token_2022::instruction::EmitTokenMetadata,
+) -> Result<EmitTokenMetadataEvent, &'static str> {
+    let metadata = _get_account(instruction, 0)?.to_string();
+
+    Ok(EmitTokenMetadataEvent {
+        metadata,
+        start: emit.start,
+        end: emit.end,
+    })
+}
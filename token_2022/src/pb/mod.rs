@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:token_2022)
+pub mod token_2022 {
+    include!("token_2022.rs");
+    // @@protoc_insertion_point(token_2022)
+}
@@ -0,0 +1,437 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Token2022BlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<Token2022TransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Token2022TransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<Token2022Event>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Token2022Event {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(oneof="token2022_event::Event", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26")]
+    pub event: ::core::option::Option<token2022_event::Event>,
+}
+/// Nested message and enum types in `Token2022Event`.
+pub mod token2022_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="2")]
+        Transfer(super::TransferEvent),
+        #[prost(message, tag="3")]
+        InitializeMint(super::InitializeMintEvent),
+        #[prost(message, tag="4")]
+        InitializeImmutableOwner(super::InitializeImmutableOwnerEvent),
+        #[prost(message, tag="5")]
+        InitializeAccount(super::InitializeAccountEvent),
+        #[prost(message, tag="6")]
+        InitializeMultisig(super::InitializeMultisigEvent),
+        #[prost(message, tag="7")]
+        Approve(super::ApproveEvent),
+        #[prost(message, tag="8")]
+        MintTo(super::MintToEvent),
+        #[prost(message, tag="9")]
+        Revoke(super::RevokeEvent),
+        #[prost(message, tag="10")]
+        SetAuthority(super::SetAuthorityEvent),
+        #[prost(message, tag="11")]
+        Burn(super::BurnEvent),
+        #[prost(message, tag="12")]
+        CloseAccount(super::CloseAccountEvent),
+        #[prost(message, tag="13")]
+        FreezeAccount(super::FreezeAccountEvent),
+        #[prost(message, tag="14")]
+        ThawAccount(super::ThawAccountEvent),
+        #[prost(message, tag="15")]
+        SyncNative(super::SyncNativeEvent),
+        #[prost(message, tag="16")]
+        InitializeMintCloseAuthority(super::InitializeMintCloseAuthorityEvent),
+        #[prost(message, tag="17")]
+        InitializeTransferFeeConfig(super::InitializeTransferFeeConfigEvent),
+        #[prost(message, tag="18")]
+        TransferCheckedWithFee(super::TransferCheckedWithFeeEvent),
+        #[prost(message, tag="19")]
+        WithdrawWithheldTokensFromAccounts(super::WithdrawWithheldTokensFromAccountsEvent),
+        #[prost(message, tag="20")]
+        InitializeMetadataPointer(super::InitializeMetadataPointerEvent),
+        #[prost(message, tag="21")]
+        UpdateMetadataPointer(super::UpdateMetadataPointerEvent),
+        #[prost(message, tag="22")]
+        InitializeTokenMetadata(super::InitializeTokenMetadataEvent),
+        #[prost(message, tag="23")]
+        UpdateTokenMetadataField(super::UpdateTokenMetadataFieldEvent),
+        #[prost(message, tag="24")]
+        RemoveTokenMetadataKey(super::RemoveTokenMetadataKeyEvent),
+        #[prost(message, tag="25")]
+        UpdateTokenMetadataAuthority(super::UpdateTokenMetadataAuthorityEvent),
+        #[prost(message, tag="26")]
+        EmitTokenMetadata(super::EmitTokenMetadataEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeMintEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub decimals: u32,
+    #[prost(string, tag="3")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub freeze_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeAccountEvent {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub owner: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeMultisigEvent {
+    #[prost(string, tag="1")]
+    pub multisig: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="2")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint32, tag="3")]
+    pub m: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub amount: u64,
+    /// Only known for TransferChecked.
+    #[prost(string, optional, tag="5")]
+    pub mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag="6")]
+    pub decimals: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApproveEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub delegate: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    /// Authority types added after this crate are passed through as their raw value.
+    #[prost(enumeration="AuthorityType", tag="3")]
+    pub authority_type: i32,
+    #[prost(string, optional, tag="4")]
+    pub new_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintToEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BurnEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloseAccountEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FreezeAccountEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub freeze_authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ThawAccountEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub freeze_authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeImmutableOwnerEvent {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncNativeEvent {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeMintCloseAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub close_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeTransferFeeConfigEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub transfer_fee_config_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub withdraw_withheld_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="4")]
+    pub transfer_fee_basis_points: u32,
+    #[prost(uint64, tag="5")]
+    pub maximum_fee: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferCheckedWithFeeEvent {
+    #[prost(string, tag="1")]
+    pub source: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub amount: u64,
+    #[prost(uint32, tag="6")]
+    pub decimals: u32,
+    #[prost(uint64, tag="7")]
+    pub fee: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawWithheldTokensFromAccountsEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="4")]
+    pub sources: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeMetadataPointerEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub metadata_address: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMetadataPointerEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub metadata_address: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeTokenMetadataEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub uri: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateTokenMetadataFieldEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    /// "name", "symbol", "uri" or the custom key.
+    #[prost(string, tag="3")]
+    pub field: ::prost::alloc::string::String,
+    /// Full value as passed to the instruction, never truncated.
+    #[prost(string, tag="4")]
+    pub value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveTokenMetadataKeyEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(bool, tag="4")]
+    pub idempotent: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateTokenMetadataAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    /// Unset when the metadata is made immutable.
+    #[prost(string, optional, tag="3")]
+    pub new_update_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmitTokenMetadataEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="2")]
+    pub start: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="3")]
+    pub end: ::core::option::Option<u64>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AuthorityType {
+    MintTokens = 0,
+    FreezeAccount = 1,
+    AccountOwner = 2,
+    CloseAccount = 3,
+    TransferFeeConfig = 4,
+    WithheldWithdraw = 5,
+    CloseMint = 6,
+    InterestRate = 7,
+    PermanentDelegate = 8,
+    ConfidentialTransferMint = 9,
+    TransferHookProgramId = 10,
+    ConfidentialTransferFeeConfig = 11,
+    MetadataPointer = 12,
+    GroupPointer = 13,
+    GroupMemberPointer = 14,
+    ScaledUiAmount = 15,
+    Pause = 16,
+}
+impl AuthorityType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AuthorityType::MintTokens => "MintTokens",
+            AuthorityType::FreezeAccount => "FreezeAccount",
+            AuthorityType::AccountOwner => "AccountOwner",
+            AuthorityType::CloseAccount => "CloseAccount",
+            AuthorityType::TransferFeeConfig => "TransferFeeConfig",
+            AuthorityType::WithheldWithdraw => "WithheldWithdraw",
+            AuthorityType::CloseMint => "CloseMint",
+            AuthorityType::InterestRate => "InterestRate",
+            AuthorityType::PermanentDelegate => "PermanentDelegate",
+            AuthorityType::ConfidentialTransferMint => "ConfidentialTransferMint",
+            AuthorityType::TransferHookProgramId => "TransferHookProgramId",
+            AuthorityType::ConfidentialTransferFeeConfig => "ConfidentialTransferFeeConfig",
+            AuthorityType::MetadataPointer => "MetadataPointer",
+            AuthorityType::GroupPointer => "GroupPointer",
+            AuthorityType::GroupMemberPointer => "GroupMemberPointer",
+            AuthorityType::ScaledUiAmount => "ScaledUiAmount",
+            AuthorityType::Pause => "Pause",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MintTokens" => Some(Self::MintTokens),
+            "FreezeAccount" => Some(Self::FreezeAccount),
+            "AccountOwner" => Some(Self::AccountOwner),
+            "CloseAccount" => Some(Self::CloseAccount),
+            "TransferFeeConfig" => Some(Self::TransferFeeConfig),
+            "WithheldWithdraw" => Some(Self::WithheldWithdraw),
+            "CloseMint" => Some(Self::CloseMint),
+            "InterestRate" => Some(Self::InterestRate),
+            "PermanentDelegate" => Some(Self::PermanentDelegate),
+            "ConfidentialTransferMint" => Some(Self::ConfidentialTransferMint),
+            "TransferHookProgramId" => Some(Self::TransferHookProgramId),
+            "ConfidentialTransferFeeConfig" => Some(Self::ConfidentialTransferFeeConfig),
+            "MetadataPointer" => Some(Self::MetadataPointer),
+            "GroupPointer" => Some(Self::GroupPointer),
+            "GroupMemberPointer" => Some(Self::GroupMemberPointer),
+            "ScaledUiAmount" => Some(Self::ScaledUiAmount),
+            "Pause" => Some(Self::Pause),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,402 @@
+use borsh::BorshDeserialize;
+use substreams_solana_utils::pubkey::Pubkey;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuthorityType {
+    MintTokens,
+    FreezeAccount,
+    AccountOwner,
+    CloseAccount,
+    TransferFeeConfig,
+    WithheldWithdraw,
+    CloseMint,
+    InterestRate,
+    PermanentDelegate,
+    ConfidentialTransferMint,
+    TransferHookProgramId,
+    ConfidentialTransferFeeConfig,
+    MetadataPointer,
+    GroupPointer,
+    GroupMemberPointer,
+    ScaledUiAmount,
+    Pause,
+    /// An authority type added after this crate, kept as its raw value.
+    Unknown(u8),
+}
+
+impl AuthorityType {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::MintTokens,
+            1 => Self::FreezeAccount,
+            2 => Self::AccountOwner,
+            3 => Self::CloseAccount,
+            4 => Self::TransferFeeConfig,
+            5 => Self::WithheldWithdraw,
+            6 => Self::CloseMint,
+            7 => Self::InterestRate,
+            8 => Self::PermanentDelegate,
+            9 => Self::ConfidentialTransferMint,
+            10 => Self::TransferHookProgramId,
+            11 => Self::ConfidentialTransferFeeConfig,
+            12 => Self::MetadataPointer,
+            13 => Self::GroupPointer,
+            14 => Self::GroupMemberPointer,
+            15 => Self::ScaledUiAmount,
+            16 => Self::Pause,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+/// Token-2022 instructions.
+///
+/// The base set shares its layout and discriminants with the legacy Token program. Extensions
+/// are grouped under a single discriminant followed by a sub-discriminant, e.g.
+/// `[26, 1, ...]` for `TransferFeeExtension::TransferCheckedWithFee`.
+#[derive(Debug)]
+pub enum Token2022Instruction {
+    InitializeMint { decimals: u8, mint_authority: Pubkey, freeze_authority: Option<Pubkey> },
+    InitializeAccount,
+    InitializeMultisig { m: u8 },
+    Transfer { amount: u64 },
+    Approve { amount: u64 },
+    Revoke,
+    SetAuthority { authority_type: AuthorityType, new_authority: Option<Pubkey> },
+    MintTo { amount: u64 },
+    Burn { amount: u64 },
+    CloseAccount,
+    FreezeAccount,
+    ThawAccount,
+    TransferChecked { amount: u64, decimals: u8 },
+    ApproveChecked { amount: u64, decimals: u8 },
+    MintToChecked { amount: u64, decimals: u8 },
+    BurnChecked { amount: u64, decimals: u8 },
+    InitializeAccount2 { owner: Pubkey },
+    SyncNative,
+    InitializeAccount3 { owner: Pubkey },
+    InitializeMultisig2 { m: u8 },
+    InitializeMint2 { decimals: u8, mint_authority: Pubkey, freeze_authority: Option<Pubkey> },
+    GetAccountDataSize,
+    InitializeImmutableOwner,
+    AmountToUiAmount { amount: u64 },
+    UiAmountToAmount,
+    InitializeMintCloseAuthority { close_authority: Option<Pubkey> },
+    TransferFeeExtension(TransferFeeInstruction),
+    MetadataPointerExtension(MetadataPointerInstruction),
+    TokenMetadata(TokenMetadataInstruction),
+    /// An extension this crate doesn't decode, identified by its discriminant.
+    UnsupportedExtension(u8),
+}
+
+#[derive(Debug)]
+pub enum TransferFeeInstruction {
+    InitializeTransferFeeConfig {
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    TransferCheckedWithFee { amount: u64, decimals: u8, fee: u64 },
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts { num_token_accounts: u8 },
+    HarvestWithheldTokensToMint,
+    SetTransferFee { transfer_fee_basis_points: u16, maximum_fee: u64 },
+    /// A transfer fee instruction added after this crate, identified by its sub-discriminant.
+    Unsupported(u8),
+}
+
+#[derive(Debug)]
+pub enum MetadataPointerInstruction {
+    Initialize { authority: Option<Pubkey>, metadata_address: Option<Pubkey> },
+    Update { metadata_address: Option<Pubkey> },
+    /// A metadata pointer instruction added after this crate, identified by its sub-discriminant.
+    Unsupported(u8),
+}
+
+/// Instructions of the token-metadata interface, which Token-2022 implements for mints with
+/// the metadata extension. They use 8-byte discriminators instead of the token layout.
+#[derive(Debug)]
+pub enum TokenMetadataInstruction {
+    Initialize(InitializeTokenMetadata),
+    UpdateField(UpdateTokenMetadataField),
+    RemoveKey(RemoveTokenMetadataKey),
+    UpdateAuthority(UpdateTokenMetadataAuthority),
+    Emit(EmitTokenMetadata),
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct InitializeTokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub enum TokenMetadataField {
+    Name,
+    Symbol,
+    Uri,
+    Key(String),
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct UpdateTokenMetadataField {
+    pub field: TokenMetadataField,
+    pub value: String,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct RemoveTokenMetadataKey {
+    /// When set, removing a key that doesn't exist is not an error.
+    pub idempotent: bool,
+    pub key: String,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct UpdateTokenMetadataAuthority {
+    /// All zeros when the metadata is made immutable.
+    pub new_authority: [u8; 32],
+}
+
+/// Asks the program to return the serialized metadata, or the `start..end` byte range of it.
+#[derive(Debug, BorshDeserialize)]
+pub struct EmitTokenMetadata {
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+impl Token2022Instruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        if input.len() >= 8 {
+            let (tag, rest) = input.split_at(8);
+            match tag {
+                [210, 225, 30, 162, 88, 184, 77, 141] => {
+                    let args = InitializeTokenMetadata::deserialize(&mut &rest[..])
+                        .map_err(|_| "Failed to deserialize InitializeTokenMetadata.")?;
+                    return Ok(Self::TokenMetadata(TokenMetadataInstruction::Initialize(args)));
+                },
+                [221, 233, 49, 45, 181, 202, 220, 200] => {
+                    let args = UpdateTokenMetadataField::deserialize(&mut &rest[..])
+                        .map_err(|_| "Failed to deserialize UpdateTokenMetadataField.")?;
+                    return Ok(Self::TokenMetadata(TokenMetadataInstruction::UpdateField(args)));
+                },
+                [234, 18, 32, 56, 89, 141, 37, 181] => {
+                    let args = RemoveTokenMetadataKey::deserialize(&mut &rest[..])
+                        .map_err(|_| "Failed to deserialize RemoveTokenMetadataKey.")?;
+                    return Ok(Self::TokenMetadata(TokenMetadataInstruction::RemoveKey(args)));
+                },
+                [215, 228, 166, 228, 84, 100, 86, 123] => {
+                    let args = UpdateTokenMetadataAuthority::deserialize(&mut &rest[..])
+                        .map_err(|_| "Failed to deserialize UpdateTokenMetadataAuthority.")?;
+                    return Ok(Self::TokenMetadata(TokenMetadataInstruction::UpdateAuthority(args)));
+                },
+                [250, 166, 180, 250, 13, 12, 184, 70] => {
+                    let args = EmitTokenMetadata::deserialize(&mut &rest[..])
+                        .map_err(|_| "Failed to deserialize EmitTokenMetadata.")?;
+                    return Ok(Self::TokenMetadata(TokenMetadataInstruction::Emit(args)));
+                },
+                _ => (),
+            }
+        }
+
+        let (&tag, rest) = input.split_first().ok_or("Invalid instruction data")?;
+        Ok(match tag {
+            0 | 20 => {
+                let (decimals, rest) = Self::unpack_u8(rest)?;
+                let (mint_authority, rest) = Self::unpack_pubkey(rest)?;
+                let (freeze_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                if tag == 0 {
+                    Self::InitializeMint { decimals, mint_authority, freeze_authority }
+                } else {
+                    Self::InitializeMint2 { decimals, mint_authority, freeze_authority }
+                }
+            }
+            1 => Self::InitializeAccount,
+            2 => {
+                let (m, _rest) = Self::unpack_u8(rest)?;
+                Self::InitializeMultisig { m }
+            }
+            3 => Self::Transfer { amount: Self::unpack_u64(rest)?.0 },
+            4 => Self::Approve { amount: Self::unpack_u64(rest)?.0 },
+            5 => Self::Revoke,
+            6 => {
+                let (authority_type, rest) = Self::unpack_u8(rest)?;
+                let (new_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::SetAuthority { authority_type: AuthorityType::from_u8(authority_type), new_authority }
+            }
+            7 => Self::MintTo { amount: Self::unpack_u64(rest)?.0 },
+            8 => Self::Burn { amount: Self::unpack_u64(rest)?.0 },
+            9 => Self::CloseAccount,
+            10 => Self::FreezeAccount,
+            11 => Self::ThawAccount,
+            12..=15 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (decimals, _rest) = Self::unpack_u8(rest)?;
+                match tag {
+                    12 => Self::TransferChecked { amount, decimals },
+                    13 => Self::ApproveChecked { amount, decimals },
+                    14 => Self::MintToChecked { amount, decimals },
+                    _ => Self::BurnChecked { amount, decimals },
+                }
+            }
+            16 => Self::InitializeAccount2 { owner: Self::unpack_pubkey(rest)?.0 },
+            17 => Self::SyncNative,
+            18 => Self::InitializeAccount3 { owner: Self::unpack_pubkey(rest)?.0 },
+            19 => {
+                let (m, _rest) = Self::unpack_u8(rest)?;
+                Self::InitializeMultisig2 { m }
+            }
+            21 => Self::GetAccountDataSize,
+            22 => Self::InitializeImmutableOwner,
+            23 => Self::AmountToUiAmount { amount: Self::unpack_u64(rest)?.0 },
+            24 => Self::UiAmountToAmount,
+            25 => {
+                let (close_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::InitializeMintCloseAuthority { close_authority }
+            }
+            26 => Self::TransferFeeExtension(Self::unpack_transfer_fee(rest)?),
+            39 => Self::MetadataPointerExtension(Self::unpack_metadata_pointer(rest)?),
+            _ => Self::UnsupportedExtension(tag),
+        })
+    }
+
+    fn unpack_transfer_fee(input: &[u8]) -> Result<TransferFeeInstruction, &'static str> {
+        let (tag, rest) = Self::unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (transfer_fee_config_authority, rest) = Self::unpack_pubkey_option(rest)?;
+                let (withdraw_withheld_authority, rest) = Self::unpack_pubkey_option(rest)?;
+                let (transfer_fee_basis_points, rest) = Self::unpack_u16(rest)?;
+                let (maximum_fee, _rest) = Self::unpack_u64(rest)?;
+                TransferFeeInstruction::InitializeTransferFeeConfig {
+                    transfer_fee_config_authority,
+                    withdraw_withheld_authority,
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                }
+            }
+            1 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (decimals, rest) = Self::unpack_u8(rest)?;
+                let (fee, _rest) = Self::unpack_u64(rest)?;
+                TransferFeeInstruction::TransferCheckedWithFee { amount, decimals, fee }
+            }
+            2 => TransferFeeInstruction::WithdrawWithheldTokensFromMint,
+            3 => {
+                let (num_token_accounts, _rest) = Self::unpack_u8(rest)?;
+                TransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts }
+            }
+            4 => TransferFeeInstruction::HarvestWithheldTokensToMint,
+            5 => {
+                let (transfer_fee_basis_points, rest) = Self::unpack_u16(rest)?;
+                let (maximum_fee, _rest) = Self::unpack_u64(rest)?;
+                TransferFeeInstruction::SetTransferFee { transfer_fee_basis_points, maximum_fee }
+            }
+            _ => TransferFeeInstruction::Unsupported(tag),
+        })
+    }
+
+    fn unpack_metadata_pointer(input: &[u8]) -> Result<MetadataPointerInstruction, &'static str> {
+        let (tag, rest) = Self::unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (authority, rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                let (metadata_address, _rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                MetadataPointerInstruction::Initialize { authority, metadata_address }
+            }
+            1 => {
+                let (metadata_address, _rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                MetadataPointerInstruction::Update { metadata_address }
+            }
+            _ => MetadataPointerInstruction::Unsupported(tag),
+        })
+    }
+
+    fn unpack_u8(input: &[u8]) -> Result<(u8, &[u8]), &'static str> {
+        let (&value, rest) = input.split_first().ok_or("Invalid instruction data")?;
+        Ok((value, rest))
+    }
+
+    fn unpack_u16(input: &[u8]) -> Result<(u16, &[u8]), &'static str> {
+        if input.len() < 2 {
+            return Err("Invalid instruction data");
+        }
+        let (value, rest) = input.split_at(2);
+        Ok((u16::from_le_bytes(value.try_into().unwrap()), rest))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        if input.len() < 8 {
+            return Err("Invalid instruction data");
+        }
+        let (value, rest) = input.split_at(8);
+        Ok((u64::from_le_bytes(value.try_into().unwrap()), rest))
+    }
+
+    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), &'static str> {
+        if input.len() < 32 {
+            return Err("Invalid instruction data");
+        }
+        let (key, rest) = input.split_at(32);
+        Ok((Pubkey(key.try_into().unwrap()), rest))
+    }
+
+    /// `COption<Pubkey>`: a one byte tag followed by the key when the tag is set.
+    fn unpack_pubkey_option(input: &[u8]) -> Result<(Option<Pubkey>, &[u8]), &'static str> {
+        match input.split_first() {
+            Some((&0, rest)) => Ok((None, rest)),
+            Some((&1, rest)) => {
+                let (key, rest) = Self::unpack_pubkey(rest)?;
+                Ok((Some(key), rest))
+            }
+            _ => Err("Invalid instruction data"),
+        }
+    }
+
+    /// `OptionalNonZeroPubkey`: always 32 bytes, all zeroes meaning `None`.
+    fn unpack_optional_nonzero_pubkey(input: &[u8]) -> Result<(Option<Pubkey>, &[u8]), &'static str> {
+        let (key, rest) = Self::unpack_pubkey(input)?;
+        if key.0 == [0u8; 32] {
+            Ok((None, rest))
+        } else {
+            Ok((Some(key), rest))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_authority_type(authority_type: u8) -> AuthorityType {
+        match Token2022Instruction::unpack(&[6, authority_type, 0]) {
+            Ok(Token2022Instruction::SetAuthority { authority_type, new_authority: None }) => authority_type,
+            other => panic!("Expected SetAuthority, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn set_authority_types() {
+        assert_eq!(set_authority_type(14), AuthorityType::GroupMemberPointer);
+        assert_eq!(set_authority_type(15), AuthorityType::ScaledUiAmount);
+        assert_eq!(set_authority_type(16), AuthorityType::Pause);
+        assert_eq!(set_authority_type(17), AuthorityType::Unknown(17));
+    }
+
+    #[test]
+    fn unknown_transfer_fee_instruction() {
+        assert!(matches!(
+            Token2022Instruction::unpack(&[26, 9]),
+            Ok(Token2022Instruction::TransferFeeExtension(TransferFeeInstruction::Unsupported(9))),
+        ));
+    }
+
+    #[test]
+    fn unknown_metadata_pointer_instruction() {
+        assert!(matches!(
+            Token2022Instruction::unpack(&[39, 2]),
+            Ok(Token2022Instruction::MetadataPointerExtension(MetadataPointerInstruction::Unsupported(2))),
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod constants;
+pub use constants::TOKEN_2022_PROGRAM_ID;
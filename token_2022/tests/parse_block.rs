@@ -0,0 +1,32 @@
+use test_support::{address, key, TransactionBuilder};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+use token_2022_substream::{parse_block, parse_transaction};
+use token_2022_substream::pb::token_2022::token2022_event::Event;
+use token_2022_substream::token_2022::TOKEN_2022_PROGRAM_ID;
+
+fn transfer(accounts: &[u8]) -> ConfirmedTransaction {
+    let data = [vec![3], 500u64.to_le_bytes().to_vec()].concat();
+    TransactionBuilder::new(vec![key(1), key(2), key(3), TOKEN_2022_PROGRAM_ID.0.to_vec()])
+        .instruction(3, accounts, data)
+        .build()
+}
+
+#[test]
+fn skips_failing_transactions() {
+    // The first transfer is missing its authority account.
+    let block = Block { transactions: vec![transfer(&[1, 2]), transfer(&[1, 2, 0])], ..Default::default() };
+    let transactions = parse_block(&block).unwrap();
+    assert_eq!(transactions.len(), 1);
+    let Some(Event::Transfer(event)) = &transactions[0].events[0].event else { panic!("Expected Transfer") };
+    assert_eq!(event.source, address(2));
+    assert_eq!(event.destination, address(3));
+    assert_eq!(event.authority, address(1));
+    assert_eq!(event.amount, 500);
+}
+
+#[test]
+fn transaction_without_meta_is_skipped() {
+    let mut transaction = transfer(&[1, 2, 0]);
+    transaction.meta = None;
+    assert!(parse_transaction(&transaction).unwrap().is_empty());
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Context, Error};
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::transaction::{get_context, TransactionContext};
+use utils::pubkey::Pubkey;
+
+pub mod vote_program;
+use vote_program::VOTE_PROGRAM_ID;
+use vote_program::instruction::VoteInstruction;
+
+pub mod options;
+pub use options::ParseOptions;
+
+pub mod pb;
+use pb::vote_program::*;
+use pb::vote_program::vote_program_event::Event;
+
+#[substreams::handlers::map]
+fn vote_program_events(params: String, block: Block) -> Result<VoteProgramBlockEvents, Error> {
+    let options = ParseOptions::from_params(&params)?;
+    parse_block_with_options(&block, &options)
+}
+
+pub fn parse_block(block: &Block) -> Result<Vec<VoteProgramTransactionEvents>, Error> {
+    Ok(parse_block_with_options(block, &ParseOptions::default())?.transactions)
+}
+
+/// Transactions that fail to parse are logged and skipped, so a single malformed instruction
+/// doesn't fail the whole block. Their votes aren't counted either.
+pub fn parse_block_with_options(block: &Block, options: &ParseOptions) -> Result<VoteProgramBlockEvents, Error> {
+    let mut transactions_events: Vec<VoteProgramTransactionEvents> = Vec::new();
+    let mut vote_counts: BTreeMap<String, u32> = BTreeMap::new();
+    for transaction in block.transactions() {
+        let mut transaction_counts: BTreeMap<String, u32> = BTreeMap::new();
+        let counts = if options.aggregate_votes { Some(&mut transaction_counts) } else { None };
+        let events = match parse_transaction_with_counts(transaction, counts) {
+            Ok(events) => events,
+            Err(e) => {
+                substreams::log::println(format!(
+                    "Skipping transaction {}: {:?}", utils::transaction::get_signature(transaction), e,
+                ));
+                continue;
+            }
+        };
+        for (vote_account, vote_count) in transaction_counts {
+            *vote_counts.entry(vote_account).or_default() += vote_count;
+        }
+        if !events.is_empty() {
+            transactions_events.push(VoteProgramTransactionEvents {
+                signature: utils::transaction::get_signature(&transaction),
+                events
+            })
+        }
+    }
+
+    let stats = options.aggregate_votes.then(|| VoteStats {
+        vote_counts: vote_counts.into_iter()
+            .map(|(vote_account, vote_count)| VoteCount { vote_account, vote_count })
+            .collect(),
+    });
+    Ok(VoteProgramBlockEvents { transactions: transactions_events, stats })
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<VoteProgramEvent>, Error> {
+    parse_transaction_with_counts(transaction, None)
+}
+
+/// Parses the administrative instructions of `transaction`. Vote instructions produce no event;
+/// when `vote_counts` is given they are tallied into it by vote account instead.
+pub fn parse_transaction_with_counts(
+    transaction: &ConfirmedTransaction,
+    mut vote_counts: Option<&mut BTreeMap<String, u32>>,
+) -> Result<Vec<VoteProgramEvent>, Error> {
+    let Some(meta) = transaction.meta.as_ref() else {
+        substreams::log::println(format!("Skipping transaction {} without meta", utils::transaction::get_signature(transaction)));
+        return Ok(Vec::new())
+    };
+    if meta.err.is_some() {
+        return Ok(Vec::new())
+    }
+
+    let mut events: Vec<VoteProgramEvent> = Vec::new();
+
+    let context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+
+    for (i, instruction) in instructions.flattened().iter().enumerate() {
+        if instruction.program_id() != VOTE_PROGRAM_ID {
+            continue;
+        }
+        if let Some(vote_account) = vote_account(instruction) {
+            if let Some(vote_counts) = vote_counts.as_deref_mut() {
+                *vote_counts.entry(vote_account.to_string()).or_default() += 1;
+            }
+            continue;
+        }
+        let event = parse_instruction(instruction, &context)
+            .with_context(|| format!("Failed to parse transaction {}", context.signature))?;
+        if event.is_some() {
+            events.push(VoteProgramEvent { instruction_index: i as u32, event });
+        }
+    }
+
+    Ok(events)
+}
+
+/// The vote account of a vote instruction, `None` for any other instruction.
+pub fn vote_account(instruction: &StructuredInstruction) -> Option<Pubkey> {
+    match VoteInstruction::unpack(&instruction.data()) {
+        Ok(unpacked) if unpacked.is_vote() => instruction.accounts().first().cloned(),
+        _ => None,
+    }
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Option<Event>, Error> {
+    if instruction.program_id() != VOTE_PROGRAM_ID {
+        return Err(anyhow!("Not a Vote program instruction"));
+    }
+
+    let unpacked = VoteInstruction::unpack(&instruction.data()).map_err(|x| anyhow!(x))?;
+    match unpacked {
+        VoteInstruction::InitializeAccount { node, authorized_voter, authorized_withdrawer, commission } => {
+            _parse_initialize_account_instruction(instruction, context, node, authorized_voter, authorized_withdrawer, commission)
+                .map(|x| Some(Event::InitializeAccount(x)))
+        },
+        VoteInstruction::Authorize { new_authority, vote_authorize } => {
+            _parse_authorize_instruction(instruction, context, Some(new_authority), vote_authorize)
+                .map(|x| Some(Event::Authorize(x)))
+        },
+        VoteInstruction::AuthorizeChecked { vote_authorize } => {
+            _parse_authorize_instruction(instruction, context, None, vote_authorize)
+                .map(|x| Some(Event::Authorize(x)))
+        },
+        VoteInstruction::Withdraw { lamports } => {
+            _parse_withdraw_instruction(instruction, context, lamports).map(|x| Some(Event::Withdraw(x)))
+        },
+        VoteInstruction::UpdateValidatorIdentity => {
+            _parse_update_validator_identity_instruction(instruction, context).map(|x| Some(Event::UpdateValidatorIdentity(x)))
+        },
+        VoteInstruction::UpdateCommission { commission } => {
+            _parse_update_commission_instruction(instruction, context, commission).map(|x| Some(Event::UpdateCommission(x)))
+        },
+        VoteInstruction::Vote | VoteInstruction::Unsupported(_) => Ok(None),
+    }.map_err(|x| anyhow!(x)).context("Failed to parse Vote instruction")
+}
+
+fn _get_account(instruction: &StructuredInstruction, index: usize) -> Result<Pubkey, &'static str> {
+    instruction.accounts().get(index).cloned().ok_or("Instruction is missing accounts.")
+}
+
+fn _parse_initialize_account_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    node: Pubkey,
+    authorized_voter: Pubkey,
+    authorized_withdrawer: Pubkey,
+    commission: u8,
+) -> Result<InitializeAccountEvent, &'static str> {
+    let vote_account = _get_account(instruction, 0)?.to_string();
+
+    Ok(InitializeAccountEvent {
+        vote_account,
+        node: node.to_string(),
+        authorized_voter: authorized_voter.to_string(),
+        authorized_withdrawer: authorized_withdrawer.to_string(),
+        commission: commission.into(),
+    })
+}
+
+/// `new_authority` is `None` for AuthorizeChecked, where the new authority signs as the fourth account.
+fn _parse_authorize_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    new_authority: Option<Pubkey>,
+    vote_authorize: vote_program::instruction::VoteAuthorize,
+) -> Result<AuthorizeEvent, &'static str> {
+    use vote_program::instruction::VoteAuthorize as Instruction;
+
+    let vote_account = _get_account(instruction, 0)?.to_string();
+    let authority = _get_account(instruction, 2)?.to_string();
+    let checked = new_authority.is_none();
+    let new_authority = match new_authority {
+        Some(new_authority) => new_authority,
+        None => _get_account(instruction, 3)?,
+    }.to_string();
+    let vote_authorize: i32 = match vote_authorize {
+        Instruction::Voter => VoteAuthorize::Voter.into(),
+        Instruction::Withdrawer => VoteAuthorize::Withdrawer.into(),
+    };
+
+    Ok(AuthorizeEvent {
+        vote_account,
+        authority,
+        new_authority,
+        vote_authorize,
+        checked,
+    })
+}
+
+fn _parse_withdraw_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    lamports: u64,
+) -> Result<WithdrawEvent, &'static str> {
+    let vote_account = _get_account(instruction, 0)?.to_string();
+    let recipient = _get_account(instruction, 1)?.to_string();
+    let withdraw_authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(WithdrawEvent {
+        vote_account,
+        recipient,
+        withdraw_authority,
+        lamports,
+    })
+}
+
+fn _parse_update_validator_identity_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<UpdateValidatorIdentityEvent, &'static str> {
+    let vote_account = _get_account(instruction, 0)?.to_string();
+    let new_identity = _get_account(instruction, 1)?.to_string();
+    let withdraw_authority = _get_account(instruction, 2)?.to_string();
+
+    Ok(UpdateValidatorIdentityEvent {
+        vote_account,
+        new_identity,
+        withdraw_authority,
+    })
+}
+
+fn _parse_update_commission_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    commission: u8,
+) -> Result<UpdateCommissionEvent, &'static str> {
+    let vote_account = _get_account(instruction, 0)?.to_string();
+    let withdraw_authority = _get_account(instruction, 1)?.to_string();
+
+    Ok(UpdateCommissionEvent {
+        vote_account,
+        withdraw_authority,
+        commission: commission.into(),
+    })
+}
@@ -0,0 +1,33 @@
+use anyhow::{anyhow, Error};
+
+#[derive(Clone, Debug)]
+pub struct ParseOptions {
+    /// Count vote instructions per vote account into the block `stats`.
+    pub aggregate_votes: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            aggregate_votes: true,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Parses module params of the form `key=value&key=value`. Empty params yield the defaults.
+    pub fn from_params(params: &str) -> Result<Self, Error> {
+        let mut options = ParseOptions::default();
+        for param in params.split('&').map(str::trim).filter(|param| !param.is_empty()) {
+            let (key, value) = param.split_once('=').ok_or_else(|| anyhow!("Invalid param {}.", param))?;
+            match key.trim() {
+                "aggregate_votes" => {
+                    options.aggregate_votes = value.trim().parse()
+                        .map_err(|_| anyhow!("Invalid aggregate_votes {}.", value))?;
+                },
+                _ => return Err(anyhow!("Unknown param {}.", key)),
+            }
+        }
+        Ok(options)
+    }
+}
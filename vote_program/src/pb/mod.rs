@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:vote_program)
+pub mod vote_program {
+    include!("vote_program.rs");
+    // @@protoc_insertion_point(vote_program)
+}
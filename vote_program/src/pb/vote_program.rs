@@ -0,0 +1,144 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteProgramBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<VoteProgramTransactionEvents>,
+    /// Unset when vote aggregation is disabled.
+    #[prost(message, optional, tag="2")]
+    pub stats: ::core::option::Option<VoteStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteProgramTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<VoteProgramEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteStats {
+    #[prost(message, repeated, tag="1")]
+    pub vote_counts: ::prost::alloc::vec::Vec<VoteCount>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteCount {
+    #[prost(string, tag="1")]
+    pub vote_account: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub vote_count: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteProgramEvent {
+    #[prost(uint32, tag="1")]
+    pub instruction_index: u32,
+    #[prost(oneof="vote_program_event::Event", tags="2, 3, 4, 5, 6")]
+    pub event: ::core::option::Option<vote_program_event::Event>,
+}
+/// Nested message and enum types in `VoteProgramEvent`.
+pub mod vote_program_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="2")]
+        InitializeAccount(super::InitializeAccountEvent),
+        #[prost(message, tag="3")]
+        Authorize(super::AuthorizeEvent),
+        #[prost(message, tag="4")]
+        Withdraw(super::WithdrawEvent),
+        #[prost(message, tag="5")]
+        UpdateValidatorIdentity(super::UpdateValidatorIdentityEvent),
+        #[prost(message, tag="6")]
+        UpdateCommission(super::UpdateCommissionEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeAccountEvent {
+    #[prost(string, tag="1")]
+    pub vote_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub node: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub authorized_voter: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub authorized_withdrawer: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub commission: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeEvent {
+    #[prost(string, tag="1")]
+    pub vote_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub new_authority: ::prost::alloc::string::String,
+    #[prost(enumeration="VoteAuthorize", tag="4")]
+    pub vote_authorize: i32,
+    #[prost(bool, tag="5")]
+    pub checked: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawEvent {
+    #[prost(string, tag="1")]
+    pub vote_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub withdraw_authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub lamports: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateValidatorIdentityEvent {
+    #[prost(string, tag="1")]
+    pub vote_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub new_identity: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub withdraw_authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCommissionEvent {
+    #[prost(string, tag="1")]
+    pub vote_account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub withdraw_authority: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub commission: u32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VoteAuthorize {
+    Voter = 0,
+    Withdrawer = 1,
+}
+impl VoteAuthorize {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VoteAuthorize::Voter => "VOTER",
+            VoteAuthorize::Withdrawer => "WITHDRAWER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VOTER" => Some(Self::Voter),
+            "WITHDRAWER" => Some(Self::Withdrawer),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,4 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const VOTE_PROGRAM_ID: Pubkey = Pubkey(b58!("Vote111111111111111111111111111111111111111"));
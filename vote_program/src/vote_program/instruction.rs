@@ -0,0 +1,79 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteAuthorize {
+    Voter,
+    Withdrawer,
+}
+
+/// Vote program instructions, bincode encoded with a little-endian `u32` tag.
+///
+/// Only the administrative instructions are decoded; the vote payloads themselves are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VoteInstruction {
+    InitializeAccount { node: Pubkey, authorized_voter: Pubkey, authorized_withdrawer: Pubkey, commission: u8 },
+    Authorize { new_authority: Pubkey, vote_authorize: VoteAuthorize },
+    Withdraw { lamports: u64 },
+    UpdateValidatorIdentity,
+    UpdateCommission { commission: u8 },
+    AuthorizeChecked { vote_authorize: VoteAuthorize },
+    /// Vote, VoteSwitch, UpdateVoteState(Switch), CompactUpdateVoteState(Switch) and TowerSync(Switch).
+    Vote,
+    /// AuthorizeWithSeed and AuthorizeCheckedWithSeed, not decoded.
+    Unsupported(u32),
+}
+
+impl VoteInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = Self::unpack_u32(data)?;
+        Ok(match tag {
+            0 => {
+                let (node, rest) = Self::unpack_pubkey(rest)?;
+                let (authorized_voter, rest) = Self::unpack_pubkey(rest)?;
+                let (authorized_withdrawer, rest) = Self::unpack_pubkey(rest)?;
+                let commission = *rest.first().ok_or("Vote instruction is truncated.")?;
+                Self::InitializeAccount { node, authorized_voter, authorized_withdrawer, commission }
+            },
+            1 => {
+                let (new_authority, rest) = Self::unpack_pubkey(rest)?;
+                let (vote_authorize, _rest) = Self::unpack_vote_authorize(rest)?;
+                Self::Authorize { new_authority, vote_authorize }
+            },
+            2 | 6 | 8 | 9 | 12 | 13 | 14 | 15 => Self::Vote,
+            3 => Self::Withdraw { lamports: Self::unpack_u64(rest)?.0 },
+            4 => Self::UpdateValidatorIdentity,
+            5 => Self::UpdateCommission { commission: *rest.first().ok_or("Vote instruction is truncated.")? },
+            7 => Self::AuthorizeChecked { vote_authorize: Self::unpack_vote_authorize(rest)?.0 },
+            10 | 11 => Self::Unsupported(tag),
+            _ => return Err("Invalid Vote instruction."),
+        })
+    }
+
+    pub fn is_vote(&self) -> bool {
+        matches!(self, Self::Vote)
+    }
+
+    fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), &'static str> {
+        let value = input.get(..4).ok_or("Vote instruction is truncated.")?;
+        Ok((u32::from_le_bytes(value.try_into().unwrap()), &input[4..]))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        let value = input.get(..8).ok_or("Vote instruction is truncated.")?;
+        Ok((u64::from_le_bytes(value.try_into().unwrap()), &input[8..]))
+    }
+
+    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), &'static str> {
+        let key = input.get(..32).ok_or("Vote instruction is truncated.")?;
+        Ok((Pubkey(key.try_into().unwrap()), &input[32..]))
+    }
+
+    fn unpack_vote_authorize(input: &[u8]) -> Result<(VoteAuthorize, &[u8]), &'static str> {
+        let (value, rest) = Self::unpack_u32(input)?;
+        match value {
+            0 => Ok((VoteAuthorize::Voter, rest)),
+            1 => Ok((VoteAuthorize::Withdrawer, rest)),
+            _ => Err("Invalid vote authorize."),
+        }
+    }
+}
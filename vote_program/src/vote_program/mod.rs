@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod constants;
+pub use constants::VOTE_PROGRAM_ID;
@@ -0,0 +1,61 @@
+use test_support::{address, key, TransactionBuilder};
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+use vote_program_substream::{parse_block_with_options, ParseOptions};
+use vote_program_substream::pb::vote_program::{VoteCount, WithdrawEvent};
+use vote_program_substream::pb::vote_program::vote_program_event::Event;
+use vote_program_substream::vote_program::VOTE_PROGRAM_ID;
+
+// Account indexes: the authority (fee payer), the vote account, a recipient and the Vote program.
+const AUTHORITY: u8 = 0;
+const VOTE_ACCOUNT: u8 = 1;
+const RECIPIENT: u8 = 2;
+const VOTE_PROGRAM: u32 = 3;
+
+fn builder() -> TransactionBuilder {
+    TransactionBuilder::new(vec![key(1), key(2), key(3), VOTE_PROGRAM_ID.0.to_vec()])
+}
+
+/// A transaction voting twice with the vote account, and one withdrawing from it.
+fn block() -> Block {
+    let vote = builder()
+        .instruction(VOTE_PROGRAM, &[VOTE_ACCOUNT, AUTHORITY], 2u32.to_le_bytes().to_vec())
+        .instruction(VOTE_PROGRAM, &[VOTE_ACCOUNT, AUTHORITY], 14u32.to_le_bytes().to_vec());
+    let withdraw_data = [3u32.to_le_bytes().to_vec(), 500u64.to_le_bytes().to_vec()].concat();
+    let withdraw = builder().instruction(VOTE_PROGRAM, &[VOTE_ACCOUNT, RECIPIENT, AUTHORITY], withdraw_data);
+    Block { transactions: vec![vote.build(), withdraw.build()], ..Default::default() }
+}
+
+fn withdraw_event() -> Event {
+    Event::Withdraw(WithdrawEvent {
+        vote_account: address(2),
+        recipient: address(3),
+        withdraw_authority: address(1),
+        lamports: 500,
+    })
+}
+
+#[test]
+fn aggregate_votes() {
+    let output = parse_block_with_options(&block(), &ParseOptions::default()).unwrap();
+    assert_eq!(output.stats.unwrap().vote_counts, vec![VoteCount { vote_account: address(2), vote_count: 2 }]);
+    assert_eq!(output.transactions.len(), 1);
+    assert_eq!(output.transactions[0].events[0].event, Some(withdraw_event()));
+}
+
+#[test]
+fn aggregate_votes_disabled() {
+    let options = ParseOptions { aggregate_votes: false };
+    let output = parse_block_with_options(&block(), &options).unwrap();
+    assert_eq!(output.stats, None);
+    // Votes still produce no event, and the administrative instructions are unaffected.
+    assert_eq!(output.transactions.len(), 1);
+    assert_eq!(output.transactions[0].events.len(), 1);
+    assert_eq!(output.transactions[0].events[0].event, Some(withdraw_event()));
+}
+
+#[test]
+fn aggregate_votes_param() {
+    assert!(!ParseOptions::from_params("aggregate_votes=false").unwrap().aggregate_votes);
+    assert!(ParseOptions::from_params("").unwrap().aggregate_votes);
+    assert!(ParseOptions::from_params("aggregate_votes=maybe").is_err());
+}